@@ -39,11 +39,15 @@ pub enum AddressAttribute {
     Label(String),
     /// IPv4 only
     Broadcast(Ipv4Addr),
-    /// IPv6 only
+    /// IPv6 only, found in `RTM_GETANYCAST` reply
     Anycast(Ipv6Addr),
     CacheInfo(CacheInfo),
-    /// IPv6 only
+    /// IPv6 joined multicast group, found in `RTM_GETMULTICAST` reply and
+    /// `RTM_NEWMULTICAST` notification
     Multicast(Ipv6Addr),
+    /// IPv4 joined multicast group, found in `RTM_GETMULTICAST` reply and
+    /// `RTM_NEWMULTICAST` notification
+    MulticastV4(Ipv4Addr),
     Flags(AddressFlags),
     Other(DefaultNla),
 }
@@ -51,7 +55,7 @@ pub enum AddressAttribute {
 impl Nla for AddressAttribute {
     fn value_len(&self) -> usize {
         match *self {
-            Self::Broadcast(_) => IPV4_ADDR_LEN,
+            Self::Broadcast(_) | Self::MulticastV4(_) => IPV4_ADDR_LEN,
             Self::Anycast(_) | Self::Multicast(_) => IPV6_ADDR_LEN,
             Self::Address(ref addr) | Self::Local(ref addr) => {
                 if addr.is_ipv6() {
@@ -72,7 +76,9 @@ impl Nla for AddressAttribute {
 
     fn emit_value(&self, buffer: &mut [u8]) {
         match *self {
-            Self::Broadcast(ref addr) => buffer.copy_from_slice(&addr.octets()),
+            Self::Broadcast(ref addr) | Self::MulticastV4(ref addr) => {
+                buffer.copy_from_slice(&addr.octets())
+            }
             Self::Anycast(ref addr) | Self::Multicast(ref addr) => {
                 buffer.copy_from_slice(&addr.octets())
             }
@@ -100,7 +106,7 @@ impl Nla for AddressAttribute {
             Self::Broadcast(_) => IFA_BROADCAST,
             Self::Anycast(_) => IFA_ANYCAST,
             Self::CacheInfo(_) => IFA_CACHEINFO,
-            Self::Multicast(_) => IFA_MULTICAST,
+            Self::Multicast(_) | Self::MulticastV4(_) => IFA_MULTICAST,
             Self::Flags(_) => IFA_FLAGS,
             Self::Other(ref nla) => nla.kind(),
         }
@@ -181,14 +187,18 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
                     .context(format!("Invalid IFA_CACHEINFO {:?}", payload))?,
             ),
             IFA_MULTICAST => {
-                if payload.len() == IPV6_ADDR_LEN {
+                if payload.len() == IPV4_ADDR_LEN {
+                    let mut data = [0u8; IPV4_ADDR_LEN];
+                    data.copy_from_slice(&payload[0..IPV4_ADDR_LEN]);
+                    Self::MulticastV4(Ipv4Addr::from(data))
+                } else if payload.len() == IPV6_ADDR_LEN {
                     let mut data = [0u8; IPV6_ADDR_LEN];
                     data.copy_from_slice(&payload[0..IPV6_ADDR_LEN]);
                     Self::Multicast(Ipv6Addr::from(data))
                 } else {
                     return Err(DecodeError::from(format!(
                         "Invalid IFA_MULTICAST, got unexpected length \
-                        of IPv6 address payload {:?}",
+                        of payload {:?}",
                         payload
                     )));
                 }
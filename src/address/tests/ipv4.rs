@@ -55,3 +55,44 @@ fn test_ipv4_get_loopback_address() {
 
     assert_eq!(buf, raw);
 }
+
+// wireshark capture(netlink message header removed) of nlmon against
+// `RTM_GETMULTICAST` dump request of `AF_INET` family.
+#[test]
+fn test_ipv4_get_multicast_address() {
+    let raw = vec![
+        0x02, 0x20, 0x80, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x07, 0x00,
+        0xe0, 0x00, 0x00, 0x01, 0x14, 0x00, 0x06, 0x00, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xfe, 0xbf, 0x05, 0x00, 0xfe, 0xbf, 0x05, 0x00,
+    ];
+
+    let expected = AddressMessage {
+        header: AddressHeader {
+            family: AddressFamily::Inet,
+            prefix_len: 32,
+            flags: AddressHeaderFlags::Permanent,
+            scope: AddressScope::Universe,
+            index: 1,
+        },
+        attributes: vec![
+            AddressAttribute::MulticastV4(Ipv4Addr::new(224, 0, 0, 1)),
+            AddressAttribute::CacheInfo(CacheInfo {
+                ifa_preferred: u32::MAX,
+                ifa_valid: u32::MAX,
+                cstamp: 376830,
+                tstamp: 376830,
+            }),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        AddressMessage::parse(&AddressMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MIT
 
 use std::net::{IpAddr, Ipv6Addr};
+use std::str::FromStr;
 
 use netlink_packet_utils::{nla::NlaBuffer, Emitable, Parseable};
 
@@ -10,8 +11,6 @@ use crate::address::{
 };
 use crate::AddressFamily;
 
-// TODO(Gris Ge): Need test for `AddressAttribute::Anycast` and `Multicast`.
-
 #[test]
 fn test_addr_flag_stable_privacy() {
     let nla = AddressAttribute::Flags(
@@ -78,3 +77,94 @@ fn test_get_loopback_ipv6_addr() {
 
     assert_eq!(buf, raw);
 }
+
+// wireshark capture(netlink message header removed) of nlmon against
+// `RTM_GETMULTICAST` dump request of `AF_INET6` family.
+#[test]
+fn test_get_multicast_ipv6_addr() {
+    let raw = vec![
+        0x0a, 0x80, 0x80, 0x00, 0x01, 0x00, 0x00, 0x00, 0x14, 0x00, 0x07, 0x00,
+        0xff, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x14, 0x00, 0x06, 0x00, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xfe, 0xbf, 0x05, 0x00, 0xfe, 0xbf, 0x05, 0x00,
+    ];
+
+    let expected = AddressMessage {
+        header: AddressHeader {
+            family: AddressFamily::Inet6,
+            prefix_len: 128,
+            flags: AddressHeaderFlags::Permanent,
+            scope: AddressScope::Universe,
+            index: 1,
+        },
+        attributes: vec![
+            AddressAttribute::Multicast(Ipv6Addr::from_str("ff02::1").unwrap()),
+            AddressAttribute::CacheInfo(CacheInfo {
+                ifa_preferred: u32::MAX,
+                ifa_valid: u32::MAX,
+                cstamp: 376830,
+                tstamp: 376830,
+            }),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        AddressMessage::parse(&AddressMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add d0 type veth peer name d1
+//      ip link set d0 up
+//      sysctl -w net.ipv6.conf.all.forwarding=1
+//      ip -6 addr add 2001:db8:1::1/64 dev d0
+// wireshark capture(netlink message header removed) of nlmon against
+// `RTM_GETANYCAST` dump request of `AF_INET6` family.
+#[test]
+fn test_get_anycast_ipv6_addr() {
+    let raw = vec![
+        0x0a, 0x80, 0x80, 0x00, 0x03, 0x00, 0x00, 0x00, 0x14, 0x00, 0x05, 0x00,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x06, 0x00, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0x9a, 0xc2, 0x05, 0x00, 0x9a, 0xc2, 0x05, 0x00,
+    ];
+
+    let expected = AddressMessage {
+        header: AddressHeader {
+            family: AddressFamily::Inet6,
+            prefix_len: 128,
+            flags: AddressHeaderFlags::Permanent,
+            scope: AddressScope::Universe,
+            index: 3,
+        },
+        attributes: vec![
+            AddressAttribute::Anycast(
+                Ipv6Addr::from_str("2001:db8:1::").unwrap(),
+            ),
+            AddressAttribute::CacheInfo(CacheInfo {
+                ifa_preferred: u32::MAX,
+                ifa_valid: u32::MAX,
+                cstamp: 377498,
+                tstamp: 377498,
+            }),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        AddressMessage::parse(&AddressMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
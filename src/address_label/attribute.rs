@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    traits::Parseable,
+    DecodeError,
+};
+
+use crate::ip::{parse_ipv6_addr, IPV6_ADDR_LEN};
+
+const IFAL_ADDRESS: u16 = 1;
+const IFAL_LABEL: u16 = 2;
+
+/// Netlink attributes of `RTM_NEWADDRLABEL`, `RTM_DELADDRLABEL` and
+/// `RTM_GETADDRLABEL` messages.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum AddressLabelAttribute {
+    /// IPv6 prefix of the label, IPv4 addresses are represented as IPv4
+    /// mapped IPv6 addresses
+    Address(Ipv6Addr),
+    Label(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for AddressLabelAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Address(_) => IPV6_ADDR_LEN,
+            Self::Label(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Address(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Label(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Address(_) => IFAL_ADDRESS,
+            Self::Label(_) => IFAL_LABEL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for AddressLabelAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFAL_ADDRESS => {
+                Self::Address(parse_ipv6_addr(payload).context(format!(
+                    "invalid IFAL_ADDRESS value {payload:?}"
+                ))?)
+            }
+            IFAL_LABEL => Self::Label(
+                parse_u32(payload).context("invalid IFAL_LABEL value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
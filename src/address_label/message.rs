@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::address_label::{
+    AddressLabelAttribute, AddressLabelHeader, AddressLabelMessageBuffer,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct AddressLabelMessage {
+    pub header: AddressLabelHeader,
+    pub attributes: Vec<AddressLabelAttribute>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<AddressLabelMessageBuffer<&'a T>>
+    for AddressLabelMessage
+{
+    fn parse(
+        buf: &AddressLabelMessageBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: AddressLabelHeader::parse(buf)
+                .context("failed to parse address label message header")?,
+            attributes: Vec::<AddressLabelAttribute>::parse(buf)
+                .context("failed to parse address label message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<AddressLabelMessageBuffer<&'a T>>
+    for Vec<AddressLabelAttribute>
+{
+    fn parse(
+        buf: &AddressLabelMessageBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        let mut attributes = vec![];
+        for nla_buf in buf.attributes() {
+            attributes.push(AddressLabelAttribute::parse(&nla_buf?)?);
+        }
+        Ok(attributes)
+    }
+}
+
+impl Emitable for AddressLabelMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::{
+    address_label::{
+        AddressLabelAttribute, AddressLabelHeader, AddressLabelMessage,
+        AddressLabelMessageBuffer,
+    },
+    AddressFamily,
+};
+
+// Setup:
+//      ip link add d0 type veth peer name d1
+//      ip addrlabel add prefix 2001:db8::/32 dev d0 label 100
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      ip addrlabel list
+#[test]
+fn test_get_address_label_of_interface() {
+    let raw = vec![
+        0x0a, 0x00, 0x20, 0x00, 0x03, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x01, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0x64, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = AddressLabelMessage {
+        header: AddressLabelHeader {
+            family: AddressFamily::Inet6,
+            prefix_len: 32,
+            flags: 0,
+            index: 3,
+            seq: 11,
+        },
+        attributes: vec![
+            AddressLabelAttribute::Address(
+                Ipv6Addr::from_str("2001:db8::").unwrap(),
+            ),
+            AddressLabelAttribute::Label(100),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        AddressLabelMessage::parse(&AddressLabelMessageBuffer::new(&raw))
+            .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      ip addrlabel list
+#[test]
+fn test_get_default_address_label_of_ipv4_mapped() {
+    let raw = vec![
+        0x0a, 0x00, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0x04, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = AddressLabelMessage {
+        header: AddressLabelHeader {
+            family: AddressFamily::Inet6,
+            prefix_len: 96,
+            flags: 0,
+            index: 0,
+            seq: 11,
+        },
+        attributes: vec![
+            AddressLabelAttribute::Address(
+                Ipv6Addr::from_str("::ffff:0.0.0.0").unwrap(),
+            ),
+            AddressLabelAttribute::Label(4),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        AddressLabelMessage::parse(&AddressLabelMessageBuffer::new(&raw))
+            .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use super::{BridgeVlanEntry, BridgeVlanGlobalOption};
+use crate::message::RTM_GETVLAN;
+
+const BRIDGE_VLANDB_ENTRY: u16 = 1;
+const BRIDGE_VLANDB_GLOBAL_OPTIONS: u16 = 2;
+
+const BRIDGE_VLANDB_DUMP_FLAGS: u16 = 1;
+
+/// Netlink attributes of `RTM_NEWVLAN`, `RTM_DELVLAN` and `RTM_GETVLAN`
+/// messages.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeVlanAttribute {
+    /// VLAN or range of VLANs on a bridge port or the bridge itself
+    Entry(Vec<BridgeVlanEntry>),
+    /// Global VLAN options of the bridge
+    GlobalOptions(Vec<BridgeVlanGlobalOption>),
+    /// Only valid in `RTM_GETVLAN` dump request
+    DumpFlags(BridgeVlanDumpFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeVlanAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Entry(v) => v.as_slice().buffer_len(),
+            Self::GlobalOptions(v) => v.as_slice().buffer_len(),
+            Self::DumpFlags(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Entry(v) => v.as_slice().emit(buffer),
+            Self::GlobalOptions(v) => v.as_slice().emit(buffer),
+            Self::DumpFlags(v) => NativeEndian::write_u32(buffer, v.bits()),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Entry(_) => BRIDGE_VLANDB_ENTRY | NLA_F_NESTED,
+            Self::GlobalOptions(_) => {
+                BRIDGE_VLANDB_GLOBAL_OPTIONS | NLA_F_NESTED
+            }
+            Self::DumpFlags(_) => BRIDGE_VLANDB_DUMP_FLAGS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+/// The `message_type` is the netlink message type holding this attribute.
+/// The `RTM_GETVLAN` dump request uses its own attribute types overlapping
+/// with the ones of `RTM_NEWVLAN` and `RTM_DELVLAN`.
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16>
+    for BridgeVlanAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        message_type: u16,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_DUMP_FLAGS if message_type == RTM_GETVLAN => {
+                Self::DumpFlags(BridgeVlanDumpFlags::from_bits_retain(
+                    parse_u32(payload)
+                        .context("invalid BRIDGE_VLANDB_DUMP_FLAGS value")?,
+                ))
+            }
+            BRIDGE_VLANDB_ENTRY => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        nla.context("invalid BRIDGE_VLANDB_ENTRY value")?;
+                    nlas.push(BridgeVlanEntry::parse(&nla)?);
+                }
+                Self::Entry(nlas)
+            }
+            BRIDGE_VLANDB_GLOBAL_OPTIONS => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(
+                        "invalid BRIDGE_VLANDB_GLOBAL_OPTIONS value",
+                    )?;
+                    nlas.push(BridgeVlanGlobalOption::parse(&nla)?);
+                }
+                Self::GlobalOptions(nlas)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+const BRIDGE_VLANDB_DUMPF_STATS: u32 = 1 << 0;
+const BRIDGE_VLANDB_DUMPF_GLOBAL: u32 = 1 << 1;
+
+bitflags! {
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[non_exhaustive]
+    pub struct BridgeVlanDumpFlags: u32 {
+        /// Include per-VLAN statistics in the reply
+        const Stats = BRIDGE_VLANDB_DUMPF_STATS;
+        /// Dump global VLAN options of the bridge instead of VLAN entries
+        const Global = BRIDGE_VLANDB_DUMPF_GLOBAL;
+        const _ = !0;
+    }
+}
@@ -0,0 +1,329 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32, parse_u64, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::link::{BridgePortMulticastRouter, BridgePortState, BridgeVlanInfo};
+
+const BRIDGE_VLANDB_ENTRY_INFO: u16 = 1;
+const BRIDGE_VLANDB_ENTRY_RANGE: u16 = 2;
+const BRIDGE_VLANDB_ENTRY_STATE: u16 = 3;
+const BRIDGE_VLANDB_ENTRY_TUNNEL_INFO: u16 = 4;
+const BRIDGE_VLANDB_ENTRY_STATS: u16 = 5;
+const BRIDGE_VLANDB_ENTRY_MCAST_ROUTER: u16 = 6;
+const BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS: u16 = 7;
+const BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS: u16 = 8;
+const BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS: u16 = 9;
+
+/// Nested attributes of `BRIDGE_VLANDB_ENTRY`
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeVlanEntry {
+    Info(BridgeVlanInfo),
+    /// The last VLAN ID of the range starting from [BridgeVlanEntry::Info]
+    Range(u16),
+    /// Per-VLAN STP state
+    State(BridgePortState),
+    TunnelInfo(Vec<BridgeVlanTunnelInfo>),
+    Stats(Vec<BridgeVlanStats>),
+    MulticastRouter(BridgePortMulticastRouter),
+    MulticastNGroups(u32),
+    MulticastMaxGroups(u32),
+    NeighSuppress(bool),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeVlanEntry {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Info(_) => 4,
+            Self::Range(_) => 2,
+            Self::State(_) | Self::MulticastRouter(_) => 1,
+            Self::NeighSuppress(_) => 1,
+            Self::TunnelInfo(v) => v.as_slice().buffer_len(),
+            Self::Stats(v) => v.as_slice().buffer_len(),
+            Self::MulticastNGroups(_) | Self::MulticastMaxGroups(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Info(v) => {
+                buffer[..4].copy_from_slice(<[u8; 4]>::from(v).as_slice())
+            }
+            Self::Range(v) => NativeEndian::write_u16(buffer, *v),
+            Self::State(v) => buffer[0] = (*v).into(),
+            Self::MulticastRouter(v) => buffer[0] = (*v).into(),
+            Self::NeighSuppress(v) => buffer[0] = *v as u8,
+            Self::TunnelInfo(v) => v.as_slice().emit(buffer),
+            Self::Stats(v) => v.as_slice().emit(buffer),
+            Self::MulticastNGroups(v) | Self::MulticastMaxGroups(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Info(_) => BRIDGE_VLANDB_ENTRY_INFO,
+            Self::Range(_) => BRIDGE_VLANDB_ENTRY_RANGE,
+            Self::State(_) => BRIDGE_VLANDB_ENTRY_STATE,
+            Self::TunnelInfo(_) => {
+                BRIDGE_VLANDB_ENTRY_TUNNEL_INFO | NLA_F_NESTED
+            }
+            Self::Stats(_) => BRIDGE_VLANDB_ENTRY_STATS | NLA_F_NESTED,
+            Self::MulticastRouter(_) => BRIDGE_VLANDB_ENTRY_MCAST_ROUTER,
+            Self::MulticastNGroups(_) => BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS,
+            Self::MulticastMaxGroups(_) => BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS,
+            Self::NeighSuppress(_) => BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeVlanEntry
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_ENTRY_INFO => Self::Info(
+                BridgeVlanInfo::try_from(payload)
+                    .context("invalid BRIDGE_VLANDB_ENTRY_INFO value")?,
+            ),
+            BRIDGE_VLANDB_ENTRY_RANGE => Self::Range(
+                parse_u16(payload)
+                    .context("invalid BRIDGE_VLANDB_ENTRY_RANGE value")?,
+            ),
+            BRIDGE_VLANDB_ENTRY_STATE => Self::State(
+                parse_u8(payload)
+                    .context("invalid BRIDGE_VLANDB_ENTRY_STATE value")?
+                    .into(),
+            ),
+            BRIDGE_VLANDB_ENTRY_TUNNEL_INFO => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(
+                        "invalid BRIDGE_VLANDB_ENTRY_TUNNEL_INFO value",
+                    )?;
+                    nlas.push(BridgeVlanTunnelInfo::parse(&nla)?);
+                }
+                Self::TunnelInfo(nlas)
+            }
+            BRIDGE_VLANDB_ENTRY_STATS => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        nla.context("invalid BRIDGE_VLANDB_ENTRY_STATS value")?;
+                    nlas.push(BridgeVlanStats::parse(&nla)?);
+                }
+                Self::Stats(nlas)
+            }
+            BRIDGE_VLANDB_ENTRY_MCAST_ROUTER => Self::MulticastRouter(
+                parse_u8(payload)
+                    .context("invalid BRIDGE_VLANDB_ENTRY_MCAST_ROUTER value")?
+                    .into(),
+            ),
+            BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS => {
+                Self::MulticastNGroups(parse_u32(payload).context(
+                    "invalid BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS value",
+                )?)
+            }
+            BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS => {
+                Self::MulticastMaxGroups(parse_u32(payload).context(
+                    "invalid BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS value",
+                )?)
+            }
+            BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS => Self::NeighSuppress(
+                parse_u8(payload).context(
+                    "invalid BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS value",
+                )? > 0,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+const BRIDGE_VLANDB_TINFO_ID: u16 = 1;
+const BRIDGE_VLANDB_TINFO_CMD: u16 = 2;
+
+/// VLAN to tunnel ID mapping of `BRIDGE_VLANDB_ENTRY_TUNNEL_INFO`
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeVlanTunnelInfo {
+    Id(u32),
+    /// Only valid in request
+    Command(BridgeVlanTunnelCommand),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeVlanTunnelInfo {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Id(_) | Self::Command(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Id(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Command(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => BRIDGE_VLANDB_TINFO_ID,
+            Self::Command(_) => BRIDGE_VLANDB_TINFO_CMD,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeVlanTunnelInfo
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_TINFO_ID => Self::Id(
+                parse_u32(payload)
+                    .context("invalid BRIDGE_VLANDB_TINFO_ID value")?,
+            ),
+            BRIDGE_VLANDB_TINFO_CMD => Self::Command(
+                parse_u32(payload)
+                    .context("invalid BRIDGE_VLANDB_TINFO_CMD value")?
+                    .into(),
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+const RTM_DELLINK: u32 = 17;
+const RTM_SETLINK: u32 = 19;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum BridgeVlanTunnelCommand {
+    /// Add or update the tunnel ID mapping
+    Set,
+    /// Remove the tunnel ID mapping
+    Delete,
+    Other(u32),
+}
+
+impl From<u32> for BridgeVlanTunnelCommand {
+    fn from(d: u32) -> Self {
+        match d {
+            RTM_SETLINK => Self::Set,
+            RTM_DELLINK => Self::Delete,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<BridgeVlanTunnelCommand> for u32 {
+    fn from(v: BridgeVlanTunnelCommand) -> u32 {
+        match v {
+            BridgeVlanTunnelCommand::Set => RTM_SETLINK,
+            BridgeVlanTunnelCommand::Delete => RTM_DELLINK,
+            BridgeVlanTunnelCommand::Other(d) => d,
+        }
+    }
+}
+
+const BRIDGE_VLANDB_STATS_RX_BYTES: u16 = 1;
+const BRIDGE_VLANDB_STATS_RX_PACKETS: u16 = 2;
+const BRIDGE_VLANDB_STATS_TX_BYTES: u16 = 3;
+const BRIDGE_VLANDB_STATS_TX_PACKETS: u16 = 4;
+// const BRIDGE_VLANDB_STATS_PAD: u16 = 5;
+
+/// Per-VLAN statistics of `BRIDGE_VLANDB_ENTRY_STATS`
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeVlanStats {
+    RxBytes(u64),
+    RxPackets(u64),
+    TxBytes(u64),
+    TxPackets(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeVlanStats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RxBytes(_)
+            | Self::RxPackets(_)
+            | Self::TxBytes(_)
+            | Self::TxPackets(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RxBytes(v)
+            | Self::RxPackets(v)
+            | Self::TxBytes(v)
+            | Self::TxPackets(v) => NativeEndian::write_u64(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RxBytes(_) => BRIDGE_VLANDB_STATS_RX_BYTES,
+            Self::RxPackets(_) => BRIDGE_VLANDB_STATS_RX_PACKETS,
+            Self::TxBytes(_) => BRIDGE_VLANDB_STATS_TX_BYTES,
+            Self::TxPackets(_) => BRIDGE_VLANDB_STATS_TX_PACKETS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeVlanStats
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_STATS_RX_BYTES => Self::RxBytes(
+                parse_u64(payload)
+                    .context("invalid BRIDGE_VLANDB_STATS_RX_BYTES value")?,
+            ),
+            BRIDGE_VLANDB_STATS_RX_PACKETS => Self::RxPackets(
+                parse_u64(payload)
+                    .context("invalid BRIDGE_VLANDB_STATS_RX_PACKETS value")?,
+            ),
+            BRIDGE_VLANDB_STATS_TX_BYTES => Self::TxBytes(
+                parse_u64(payload)
+                    .context("invalid BRIDGE_VLANDB_STATS_TX_BYTES value")?,
+            ),
+            BRIDGE_VLANDB_STATS_TX_PACKETS => Self::TxPackets(
+                parse_u64(payload)
+                    .context("invalid BRIDGE_VLANDB_STATS_TX_PACKETS value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,270 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32, parse_u64, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::{
+    link::BridgeQuerierState,
+    mdb::{MdbRouterPort, VecMdbRouterPort},
+};
+
+const BRIDGE_VLANDB_GOPTS_ID: u16 = 1;
+const BRIDGE_VLANDB_GOPTS_RANGE: u16 = 2;
+const BRIDGE_VLANDB_GOPTS_MCAST_SNOOPING: u16 = 3;
+const BRIDGE_VLANDB_GOPTS_MCAST_IGMP_VERSION: u16 = 4;
+const BRIDGE_VLANDB_GOPTS_MCAST_MLD_VERSION: u16 = 5;
+const BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_CNT: u16 = 6;
+const BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_CNT: u16 = 7;
+const BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_INTVL: u16 = 8;
+// const BRIDGE_VLANDB_GOPTS_PAD: u16 = 9;
+const BRIDGE_VLANDB_GOPTS_MCAST_MEMBERSHIP_INTVL: u16 = 10;
+const BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_INTVL: u16 = 11;
+const BRIDGE_VLANDB_GOPTS_MCAST_QUERY_INTVL: u16 = 12;
+const BRIDGE_VLANDB_GOPTS_MCAST_QUERY_RESPONSE_INTVL: u16 = 13;
+const BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_INTVL: u16 = 14;
+const BRIDGE_VLANDB_GOPTS_MCAST_QUERIER: u16 = 15;
+const BRIDGE_VLANDB_GOPTS_MCAST_ROUTER_PORTS: u16 = 16;
+const BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_STATE: u16 = 17;
+const BRIDGE_VLANDB_GOPTS_MSTI: u16 = 18;
+
+/// Nested attributes of `BRIDGE_VLANDB_GLOBAL_OPTIONS`, similar to
+/// `bridge vlan global` command. The time intervals are in centiseconds.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeVlanGlobalOption {
+    Id(u16),
+    /// The last VLAN ID of the range starting from [BridgeVlanGlobalOption::Id]
+    Range(u16),
+    MulticastSnooping(u8),
+    MulticastIgmpVersion(u8),
+    MulticastMldVersion(u8),
+    MulticastLastMemberCount(u32),
+    MulticastStartupQueryCount(u32),
+    MulticastLastMemberInterval(u64),
+    MulticastMembershipInterval(u64),
+    MulticastQuerierInterval(u64),
+    MulticastQueryInterval(u64),
+    MulticastQueryResponseInterval(u64),
+    MulticastStartupQueryInterval(u64),
+    MulticastQuerier(u8),
+    MulticastRouterPorts(Vec<MdbRouterPort>),
+    MulticastQuerierState(Vec<BridgeQuerierState>),
+    /// Multiple Spanning Tree Instance ID the VLAN is mapped to
+    Msti(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeVlanGlobalOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Id(_) | Self::Range(_) | Self::Msti(_) => 2,
+            Self::MulticastSnooping(_)
+            | Self::MulticastIgmpVersion(_)
+            | Self::MulticastMldVersion(_)
+            | Self::MulticastQuerier(_) => 1,
+            Self::MulticastLastMemberCount(_)
+            | Self::MulticastStartupQueryCount(_) => 4,
+            Self::MulticastLastMemberInterval(_)
+            | Self::MulticastMembershipInterval(_)
+            | Self::MulticastQuerierInterval(_)
+            | Self::MulticastQueryInterval(_)
+            | Self::MulticastQueryResponseInterval(_)
+            | Self::MulticastStartupQueryInterval(_) => 8,
+            Self::MulticastRouterPorts(v) => v.as_slice().buffer_len(),
+            Self::MulticastQuerierState(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Id(v) | Self::Range(v) | Self::Msti(v) => {
+                NativeEndian::write_u16(buffer, *v)
+            }
+            Self::MulticastSnooping(v)
+            | Self::MulticastIgmpVersion(v)
+            | Self::MulticastMldVersion(v)
+            | Self::MulticastQuerier(v) => buffer[0] = *v,
+            Self::MulticastLastMemberCount(v)
+            | Self::MulticastStartupQueryCount(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::MulticastLastMemberInterval(v)
+            | Self::MulticastMembershipInterval(v)
+            | Self::MulticastQuerierInterval(v)
+            | Self::MulticastQueryInterval(v)
+            | Self::MulticastQueryResponseInterval(v)
+            | Self::MulticastStartupQueryInterval(v) => {
+                NativeEndian::write_u64(buffer, *v)
+            }
+            Self::MulticastRouterPorts(v) => v.as_slice().emit(buffer),
+            Self::MulticastQuerierState(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => BRIDGE_VLANDB_GOPTS_ID,
+            Self::Range(_) => BRIDGE_VLANDB_GOPTS_RANGE,
+            Self::MulticastSnooping(_) => BRIDGE_VLANDB_GOPTS_MCAST_SNOOPING,
+            Self::MulticastIgmpVersion(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_IGMP_VERSION
+            }
+            Self::MulticastMldVersion(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_MLD_VERSION
+            }
+            Self::MulticastLastMemberCount(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_CNT
+            }
+            Self::MulticastStartupQueryCount(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_CNT
+            }
+            Self::MulticastLastMemberInterval(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_INTVL
+            }
+            Self::MulticastMembershipInterval(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_MEMBERSHIP_INTVL
+            }
+            Self::MulticastQuerierInterval(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_INTVL
+            }
+            Self::MulticastQueryInterval(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_QUERY_INTVL
+            }
+            Self::MulticastQueryResponseInterval(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_QUERY_RESPONSE_INTVL
+            }
+            Self::MulticastStartupQueryInterval(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_INTVL
+            }
+            Self::MulticastQuerier(_) => BRIDGE_VLANDB_GOPTS_MCAST_QUERIER,
+            Self::MulticastRouterPorts(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_ROUTER_PORTS | NLA_F_NESTED
+            }
+            Self::MulticastQuerierState(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_STATE | NLA_F_NESTED
+            }
+            Self::Msti(_) => BRIDGE_VLANDB_GOPTS_MSTI,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeVlanGlobalOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_GOPTS_ID => Self::Id(
+                parse_u16(payload)
+                    .context("invalid BRIDGE_VLANDB_GOPTS_ID value")?,
+            ),
+            BRIDGE_VLANDB_GOPTS_RANGE => Self::Range(
+                parse_u16(payload)
+                    .context("invalid BRIDGE_VLANDB_GOPTS_RANGE value")?,
+            ),
+            BRIDGE_VLANDB_GOPTS_MCAST_SNOOPING => Self::MulticastSnooping(
+                parse_u8(payload).context(
+                    "invalid BRIDGE_VLANDB_GOPTS_MCAST_SNOOPING value",
+                )?,
+            ),
+            BRIDGE_VLANDB_GOPTS_MCAST_IGMP_VERSION => {
+                Self::MulticastIgmpVersion(parse_u8(payload).context(
+                    "invalid BRIDGE_VLANDB_GOPTS_MCAST_IGMP_VERSION value",
+                )?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_MLD_VERSION => {
+                Self::MulticastMldVersion(parse_u8(payload).context(
+                    "invalid BRIDGE_VLANDB_GOPTS_MCAST_MLD_VERSION value",
+                )?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_CNT => {
+                Self::MulticastLastMemberCount(parse_u32(payload).context(
+                    "invalid BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_CNT value",
+                )?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_CNT => {
+                Self::MulticastStartupQueryCount(parse_u32(payload).context(
+                    "invalid BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_CNT value",
+                )?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_INTVL => {
+                Self::MulticastLastMemberInterval(parse_u64(payload).context(
+                    "invalid BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_INTVL value",
+                )?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_MEMBERSHIP_INTVL => {
+                Self::MulticastMembershipInterval(parse_u64(payload).context(
+                    "invalid BRIDGE_VLANDB_GOPTS_MCAST_MEMBERSHIP_INTVL value",
+                )?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_INTVL => {
+                Self::MulticastQuerierInterval(parse_u64(payload).context(
+                    "invalid BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_INTVL value",
+                )?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_QUERY_INTVL => {
+                Self::MulticastQueryInterval(parse_u64(payload).context(
+                    "invalid BRIDGE_VLANDB_GOPTS_MCAST_QUERY_INTVL value",
+                )?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_QUERY_RESPONSE_INTVL => {
+                Self::MulticastQueryResponseInterval(
+                    parse_u64(payload).context(
+                        "invalid BRIDGE_VLANDB_GOPTS_MCAST_QUERY_RESPONSE_INTVL \
+                        value",
+                    )?,
+                )
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_INTVL => {
+                Self::MulticastStartupQueryInterval(
+                    parse_u64(payload).context(
+                        "invalid BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_INTVL \
+                        value",
+                    )?,
+                )
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_QUERIER => Self::MulticastQuerier(
+                parse_u8(payload).context(
+                    "invalid BRIDGE_VLANDB_GOPTS_MCAST_QUERIER value",
+                )?,
+            ),
+            BRIDGE_VLANDB_GOPTS_MCAST_ROUTER_PORTS => {
+                Self::MulticastRouterPorts(
+                    VecMdbRouterPort::parse(buf)
+                        .context(
+                            "invalid BRIDGE_VLANDB_GOPTS_MCAST_ROUTER_PORTS \
+                            value",
+                        )?
+                        .0,
+                )
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_STATE => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(
+                        "invalid BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_STATE value",
+                    )?;
+                    nlas.push(BridgeQuerierState::parse(&nla)?);
+                }
+                Self::MulticastQuerierState(nlas)
+            }
+            BRIDGE_VLANDB_GOPTS_MSTI => Self::Msti(
+                parse_u16(payload)
+                    .context("invalid BRIDGE_VLANDB_GOPTS_MSTI value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
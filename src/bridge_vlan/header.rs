@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::AddressFamily;
+
+const BRIDGE_VLAN_HEADER_LEN: usize = 8;
+
+buffer!(BridgeVlanMessageBuffer(BRIDGE_VLAN_HEADER_LEN) {
+    family: (u8, 0),
+    reserved1: (u8, 1),
+    reserved2: (u16, 2..4),
+    ifindex: (u32, 4..BRIDGE_VLAN_HEADER_LEN),
+    payload: (slice, BRIDGE_VLAN_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> BridgeVlanMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+/// High level representation of `RTM_NEWVLAN`, `RTM_DELVLAN`, `RTM_GETVLAN`
+/// messages headers(`struct br_vlan_msg`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct BridgeVlanHeader {
+    pub family: AddressFamily,
+    /// Interface index of the bridge or bridge port, 0 for dumping all
+    /// devices
+    pub ifindex: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<BridgeVlanMessageBuffer<T>>
+    for BridgeVlanHeader
+{
+    fn parse(buf: &BridgeVlanMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family().into(),
+            ifindex: buf.ifindex(),
+        })
+    }
+}
+
+impl Emitable for BridgeVlanHeader {
+    fn buffer_len(&self) -> usize {
+        BRIDGE_VLAN_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = BridgeVlanMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_reserved1(0);
+        packet.set_reserved2(0);
+        packet.set_ifindex(self.ifindex);
+    }
+}
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::bridge_vlan::{
+    BridgeVlanAttribute, BridgeVlanHeader, BridgeVlanMessageBuffer,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct BridgeVlanMessage {
+    pub header: BridgeVlanHeader,
+    pub attributes: Vec<BridgeVlanAttribute>,
+}
+
+/// The `message_type` is the netlink message type(`RTM_NEWVLAN`,
+/// `RTM_DELVLAN` or `RTM_GETVLAN`) as the attribute types of `RTM_GETVLAN`
+/// dump request overlap with the ones of other messages.
+impl<'a, T: AsRef<[u8]> + 'a>
+    ParseableParametrized<BridgeVlanMessageBuffer<&'a T>, u16>
+    for BridgeVlanMessage
+{
+    fn parse_with_param(
+        buf: &BridgeVlanMessageBuffer<&'a T>,
+        message_type: u16,
+    ) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: BridgeVlanHeader::parse(buf)
+                .context("failed to parse bridge VLAN message header")?,
+            attributes: Vec::<BridgeVlanAttribute>::parse_with_param(
+                buf,
+                message_type,
+            )
+            .context("failed to parse bridge VLAN message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a>
+    ParseableParametrized<BridgeVlanMessageBuffer<&'a T>, u16>
+    for Vec<BridgeVlanAttribute>
+{
+    fn parse_with_param(
+        buf: &BridgeVlanMessageBuffer<&'a T>,
+        message_type: u16,
+    ) -> Result<Self, DecodeError> {
+        let mut attributes = vec![];
+        for nla_buf in buf.attributes() {
+            attributes.push(BridgeVlanAttribute::parse_with_param(
+                &nla_buf?,
+                message_type,
+            )?);
+        }
+        Ok(attributes)
+    }
+}
+
+impl Emitable for BridgeVlanMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
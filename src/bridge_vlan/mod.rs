@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod entry;
+mod global;
+mod header;
+mod message;
+#[cfg(test)]
+mod tests;
+
+pub use self::attribute::{BridgeVlanAttribute, BridgeVlanDumpFlags};
+pub use self::entry::{
+    BridgeVlanEntry, BridgeVlanStats, BridgeVlanTunnelCommand,
+    BridgeVlanTunnelInfo,
+};
+pub use self::global::BridgeVlanGlobalOption;
+pub use self::header::{BridgeVlanHeader, BridgeVlanMessageBuffer};
+pub use self::message::BridgeVlanMessage;
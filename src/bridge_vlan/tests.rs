@@ -0,0 +1,293 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::traits::{Emitable, ParseableParametrized};
+
+use crate::{
+    bridge_vlan::{
+        BridgeVlanAttribute, BridgeVlanDumpFlags, BridgeVlanEntry,
+        BridgeVlanGlobalOption, BridgeVlanHeader, BridgeVlanMessage,
+        BridgeVlanMessageBuffer, BridgeVlanStats,
+    },
+    link::{BridgePortMulticastRouter, BridgePortState, BridgeVlanInfo},
+    message::{RTM_GETVLAN, RTM_NEWVLAN},
+    AddressFamily,
+};
+
+// Raw netlink message(header removed) of `RTM_NEWVLAN` request sent by
+// iproute2 6.1, command:
+//      bridge vlan set dev d0 vid 10 state blocking
+#[test]
+fn test_bridge_vlan_set_state() {
+    let raw = vec![
+        0x07, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x40, 0x00, 0x0a, 0x00, 0x05, 0x00, 0x03, 0x00,
+        0x04, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = BridgeVlanMessage {
+        header: BridgeVlanHeader {
+            family: AddressFamily::Bridge,
+            ifindex: 4,
+        },
+        attributes: vec![BridgeVlanAttribute::Entry(vec![
+            // BRIDGE_VLAN_INFO_ONLY_OPTS
+            BridgeVlanEntry::Info(BridgeVlanInfo {
+                flags: 0x40,
+                vid: 10,
+            }),
+            BridgeVlanEntry::State(BridgePortState::Blocking),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        BridgeVlanMessage::parse_with_param(
+            &BridgeVlanMessageBuffer::new(&raw),
+            RTM_NEWVLAN
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWVLAN` request sent by
+// iproute2 6.1, command:
+//      bridge vlan global set dev br0 vid 10 mcast_snooping 1 \
+//          mcast_igmp_version 3
+#[test]
+fn test_bridge_vlan_global_set() {
+    let raw = vec![
+        0x07, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x02, 0x80,
+        0x06, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x05, 0x00, 0x03, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x04, 0x00, 0x03, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = BridgeVlanMessage {
+        header: BridgeVlanHeader {
+            family: AddressFamily::Bridge,
+            ifindex: 2,
+        },
+        attributes: vec![BridgeVlanAttribute::GlobalOptions(vec![
+            BridgeVlanGlobalOption::Id(10),
+            BridgeVlanGlobalOption::MulticastSnooping(1),
+            BridgeVlanGlobalOption::MulticastIgmpVersion(3),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        BridgeVlanMessage::parse_with_param(
+            &BridgeVlanMessageBuffer::new(&raw),
+            RTM_NEWVLAN
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_GETVLAN` dump request sent by
+// iproute2 6.1, command:
+//      bridge vlan global show
+#[test]
+fn test_bridge_vlan_dump_global() {
+    let raw = vec![
+        0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x02, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = BridgeVlanMessage {
+        header: BridgeVlanHeader {
+            family: AddressFamily::Bridge,
+            ifindex: 0,
+        },
+        attributes: vec![BridgeVlanAttribute::DumpFlags(
+            BridgeVlanDumpFlags::Global,
+        )],
+    };
+
+    assert_eq!(
+        expected,
+        BridgeVlanMessage::parse_with_param(
+            &BridgeVlanMessageBuffer::new(&raw),
+            RTM_GETVLAN
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_GETVLAN` dump request sent by
+// iproute2 6.1, command:
+//      bridge -d -s vlan show
+#[test]
+fn test_bridge_vlan_dump_stats() {
+    let raw = vec![
+        0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = BridgeVlanMessage {
+        header: BridgeVlanHeader {
+            family: AddressFamily::Bridge,
+            ifindex: 0,
+        },
+        attributes: vec![BridgeVlanAttribute::DumpFlags(
+            BridgeVlanDumpFlags::Stats,
+        )],
+    };
+
+    assert_eq!(
+        expected,
+        BridgeVlanMessage::parse_with_param(
+            &BridgeVlanMessageBuffer::new(&raw),
+            RTM_GETVLAN
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add br0 type bridge vlan_filtering 1
+//      ip link add d0 type veth peer name d1
+//      ip link set d0 master br0
+//      bridge vlan add dev d0 vid 10-20
+//      bridge vlan set dev d0 vid 10-20 state blocking
+// Raw netlink message(header removed) of `RTM_NEWVLAN` reply for d0 to
+// command below, following the layout of `br_vlan_fill_vids()` in Linux 6.18:
+//      bridge -d vlan show
+#[test]
+fn test_bridge_vlan_port_vlans() {
+    let raw = vec![
+        0x07, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x24, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x06, 0x00, 0x01, 0x00, 0x05, 0x00, 0x03, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x14, 0x00, 0x00, 0x00, 0x05, 0x00, 0x03, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x06, 0x00,
+        0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = BridgeVlanMessage {
+        header: BridgeVlanHeader {
+            family: AddressFamily::Bridge,
+            ifindex: 4,
+        },
+        attributes: vec![
+            BridgeVlanAttribute::Entry(vec![
+                // BRIDGE_VLAN_INFO_PVID | BRIDGE_VLAN_INFO_UNTAGGED
+                BridgeVlanEntry::Info(BridgeVlanInfo { flags: 6, vid: 1 }),
+                BridgeVlanEntry::State(BridgePortState::Forwarding),
+                BridgeVlanEntry::NeighSuppress(false),
+                BridgeVlanEntry::MulticastRouter(
+                    BridgePortMulticastRouter::TempQuery,
+                ),
+            ]),
+            BridgeVlanAttribute::Entry(vec![
+                BridgeVlanEntry::Info(BridgeVlanInfo { flags: 0, vid: 10 }),
+                BridgeVlanEntry::Range(20),
+                BridgeVlanEntry::State(BridgePortState::Blocking),
+                BridgeVlanEntry::NeighSuppress(false),
+                BridgeVlanEntry::MulticastRouter(
+                    BridgePortMulticastRouter::TempQuery,
+                ),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        BridgeVlanMessage::parse_with_param(
+            &BridgeVlanMessageBuffer::new(&raw),
+            RTM_NEWVLAN
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add br0 type bridge vlan_filtering 1
+//      ip link add d0 type veth peer name d1
+//      ip link set d0 master br0
+//      bridge vlan del dev d0 vid 1
+//      bridge vlan add dev d0 vid 10
+//      bridge vlan set dev d0 vid 10 state blocking
+// Raw netlink message(header removed) of `RTM_NEWVLAN` reply for d0 to
+// command below, following the layout of `br_vlan_fill_vids()` in Linux 6.18:
+//      bridge -d -s vlan show
+#[test]
+fn test_bridge_vlan_port_vlans_with_stats() {
+    let raw = vec![
+        0x07, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x58, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x05, 0x00, 0x03, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x34, 0x00, 0x05, 0x80,
+        0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = BridgeVlanMessage {
+        header: BridgeVlanHeader {
+            family: AddressFamily::Bridge,
+            ifindex: 4,
+        },
+        attributes: vec![BridgeVlanAttribute::Entry(vec![
+            BridgeVlanEntry::Info(BridgeVlanInfo { flags: 0, vid: 10 }),
+            BridgeVlanEntry::State(BridgePortState::Blocking),
+            BridgeVlanEntry::NeighSuppress(false),
+            BridgeVlanEntry::MulticastRouter(
+                BridgePortMulticastRouter::TempQuery,
+            ),
+            BridgeVlanEntry::Stats(vec![
+                BridgeVlanStats::RxBytes(0),
+                BridgeVlanStats::RxPackets(0),
+                BridgeVlanStats::TxBytes(0),
+                BridgeVlanStats::TxPackets(0),
+            ]),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        BridgeVlanMessage::parse_with_param(
+            &BridgeVlanMessageBuffer::new(&raw),
+            RTM_NEWVLAN
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u8},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::{route::RouteAddress, AddressFamily};
+
+// The IP6MRA_CREPORT_* share the same values with IPMRA_CREPORT_* except
+// IPv6 has no IPMRA_CREPORT_TABLE.
+const IPMRA_CREPORT_MSGTYPE: u16 = 1;
+const IPMRA_CREPORT_VIF_ID: u16 = 2;
+const IPMRA_CREPORT_SRC_ADDR: u16 = 3;
+const IPMRA_CREPORT_DST_ADDR: u16 = 4;
+const IPMRA_CREPORT_PKT: u16 = 5;
+const IPMRA_CREPORT_TABLE: u16 = 6;
+
+/// Netlink attributes of `RTM_NEWCACHEREPORT` message
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum CacheReportAttribute {
+    MessageType(CacheReportType),
+    /// Index of the virtual interface(VIF) for IPv4 or multicast interface
+    /// (MIF) for IPv6 which received the packet
+    VifId(u32),
+    SourceAddress(RouteAddress),
+    DestinationAddress(RouteAddress),
+    /// The packet which triggered this report
+    Packet(Vec<u8>),
+    /// Multicast routing table ID, IPv4 only
+    Table(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for CacheReportAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::MessageType(_) => 1,
+            Self::VifId(_) | Self::Table(_) => 4,
+            Self::SourceAddress(v) | Self::DestinationAddress(v) => {
+                v.buffer_len()
+            }
+            Self::Packet(v) => v.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::MessageType(v) => buffer[0] = (*v).into(),
+            Self::VifId(v) | Self::Table(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::SourceAddress(v) | Self::DestinationAddress(v) => {
+                v.emit(buffer)
+            }
+            Self::Packet(v) => buffer.copy_from_slice(v.as_slice()),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::MessageType(_) => IPMRA_CREPORT_MSGTYPE,
+            Self::VifId(_) => IPMRA_CREPORT_VIF_ID,
+            Self::SourceAddress(_) => IPMRA_CREPORT_SRC_ADDR,
+            Self::DestinationAddress(_) => IPMRA_CREPORT_DST_ADDR,
+            Self::Packet(_) => IPMRA_CREPORT_PKT,
+            Self::Table(_) => IPMRA_CREPORT_TABLE,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NlaBuffer<&'a T>, AddressFamily>
+    for CacheReportAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        address_family: AddressFamily,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IPMRA_CREPORT_MSGTYPE => Self::MessageType(
+                parse_u8(payload)
+                    .context(format!(
+                        "invalid IPMRA_CREPORT_MSGTYPE value {payload:?}"
+                    ))?
+                    .into(),
+            ),
+            IPMRA_CREPORT_VIF_ID => Self::VifId(parse_u32(payload).context(
+                format!("invalid IPMRA_CREPORT_VIF_ID value {payload:?}"),
+            )?),
+            IPMRA_CREPORT_SRC_ADDR => Self::SourceAddress(
+                RouteAddress::parse(address_family, payload).context(
+                    format!("invalid IPMRA_CREPORT_SRC_ADDR value {payload:?}"),
+                )?,
+            ),
+            IPMRA_CREPORT_DST_ADDR => Self::DestinationAddress(
+                RouteAddress::parse(address_family, payload).context(
+                    format!("invalid IPMRA_CREPORT_DST_ADDR value {payload:?}"),
+                )?,
+            ),
+            IPMRA_CREPORT_PKT => Self::Packet(payload.to_vec()),
+            IPMRA_CREPORT_TABLE => Self::Table(parse_u32(payload).context(
+                format!("invalid IPMRA_CREPORT_TABLE value {payload:?}"),
+            )?),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+// The MRT6MSG_* share the same values with IGMPMSG_*
+const IGMPMSG_NOCACHE: u8 = 1;
+const IGMPMSG_WRONGVIF: u8 = 2;
+const IGMPMSG_WHOLEPKT: u8 = 3;
+const IGMPMSG_WRVIFWHOLE: u8 = 4;
+
+/// Type of multicast routing upcall
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum CacheReportType {
+    /// No multicast forwarding cache entry found for the packet
+    NoCache,
+    /// Packet arrived on an interface other than the expected one
+    WrongVif,
+    /// Whole packet received on the PIM register interface
+    WholePacket,
+    /// Combination of [CacheReportType::WrongVif] and
+    /// [CacheReportType::WholePacket]
+    WrongVifWholePacket,
+    Other(u8),
+}
+
+impl From<CacheReportType> for u8 {
+    fn from(v: CacheReportType) -> Self {
+        match v {
+            CacheReportType::NoCache => IGMPMSG_NOCACHE,
+            CacheReportType::WrongVif => IGMPMSG_WRONGVIF,
+            CacheReportType::WholePacket => IGMPMSG_WHOLEPKT,
+            CacheReportType::WrongVifWholePacket => IGMPMSG_WRVIFWHOLE,
+            CacheReportType::Other(d) => d,
+        }
+    }
+}
+
+impl From<u8> for CacheReportType {
+    fn from(d: u8) -> Self {
+        match d {
+            IGMPMSG_NOCACHE => Self::NoCache,
+            IGMPMSG_WRONGVIF => Self::WrongVif,
+            IGMPMSG_WHOLEPKT => Self::WholePacket,
+            IGMPMSG_WRVIFWHOLE => Self::WrongVifWholePacket,
+            _ => Self::Other(d),
+        }
+    }
+}
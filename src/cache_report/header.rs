@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::AddressFamily;
+
+// The `struct rtgenmsg` only holds a u8 family, but the attributes are
+// placed at `NLMSG_ALIGN(sizeof(struct rtgenmsg))`.
+const CACHE_REPORT_HEADER_LEN: usize = 4;
+
+pub(crate) const RTNL_FAMILY_IPMR: u8 = 128;
+pub(crate) const RTNL_FAMILY_IP6MR: u8 = 129;
+
+buffer!(CacheReportMessageBuffer(CACHE_REPORT_HEADER_LEN) {
+    family: (u8, 0),
+    payload: (slice, CACHE_REPORT_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> CacheReportMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+/// High level representation of `RTM_NEWCACHEREPORT` message header
+/// (`struct rtgenmsg`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct CacheReportHeader {
+    /// `RTNL_FAMILY_IPMR`(128) for IPv4 multicast routing or
+    /// `RTNL_FAMILY_IP6MR`(129) for IPv6 multicast routing, both are
+    /// stored as [AddressFamily::Other].
+    pub family: AddressFamily,
+}
+
+impl CacheReportHeader {
+    /// Address family of the addresses held by this cache report.
+    pub(crate) fn address_family(&self) -> AddressFamily {
+        match u8::from(self.family) {
+            RTNL_FAMILY_IPMR => AddressFamily::Inet,
+            RTNL_FAMILY_IP6MR => AddressFamily::Inet6,
+            _ => self.family,
+        }
+    }
+}
+
+impl Emitable for CacheReportHeader {
+    fn buffer_len(&self) -> usize {
+        CACHE_REPORT_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[..CACHE_REPORT_HEADER_LEN].fill(0);
+        let mut packet = CacheReportMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<CacheReportMessageBuffer<T>>
+    for CacheReportHeader
+{
+    fn parse(buf: &CacheReportMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family().into(),
+        })
+    }
+}
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::{
+    cache_report::{
+        CacheReportAttribute, CacheReportHeader, CacheReportMessageBuffer,
+    },
+    AddressFamily,
+};
+
+/// The `RTM_NEWCACHEREPORT` message is multicasted by kernel to
+/// `RTNLGRP_IPV4_MROUTE_R` or `RTNLGRP_IPV6_MROUTE_R` group along with the
+/// upcall(e.g. `IGMPMSG_NOCACHE`) sent to the multicast routing socket.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct CacheReportMessage {
+    pub header: CacheReportHeader,
+    pub attributes: Vec<CacheReportAttribute>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<CacheReportMessageBuffer<&'a T>>
+    for CacheReportMessage
+{
+    fn parse(
+        buf: &CacheReportMessageBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        let header = CacheReportHeader::parse(buf)
+            .context("failed to parse cache report message header")?;
+        let address_family = header.address_family();
+        Ok(Self {
+            header,
+            attributes: Vec::<CacheReportAttribute>::parse_with_param(
+                buf,
+                address_family,
+            )
+            .context("failed to parse cache report message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a>
+    ParseableParametrized<CacheReportMessageBuffer<&'a T>, AddressFamily>
+    for Vec<CacheReportAttribute>
+{
+    fn parse_with_param(
+        buf: &CacheReportMessageBuffer<&'a T>,
+        address_family: AddressFamily,
+    ) -> Result<Self, DecodeError> {
+        let mut attributes = vec![];
+        for nla_buf in buf.attributes() {
+            attributes.push(CacheReportAttribute::parse_with_param(
+                &nla_buf?,
+                address_family,
+            )?);
+        }
+        Ok(attributes)
+    }
+}
+
+impl Emitable for CacheReportMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod header;
+mod message;
+#[cfg(test)]
+mod tests;
+
+pub use self::attribute::{CacheReportAttribute, CacheReportType};
+pub use self::header::{CacheReportHeader, CacheReportMessageBuffer};
+pub use self::message::CacheReportMessage;
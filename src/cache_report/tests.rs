@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::{
+    cache_report::{
+        CacheReportAttribute, CacheReportHeader, CacheReportMessage,
+        CacheReportMessageBuffer, CacheReportType,
+    },
+    route::RouteAddress,
+    AddressFamily,
+};
+
+// Setup:
+//      ip link add d0 type veth peer name d1
+//      ip addr add 192.0.2.1/24 dev d0
+//      # Move d1 to another network namespace with 192.0.2.2/24 assigned.
+//      # Open multicast routing socket with `MRT_INIT` and add d0 as VIF 0
+//      # via `MRT_ADD_VIF`, then send UDP packet to 239.1.1.1 via d1.
+// wireshark capture(netlink message header removed) of nlmon against
+// RTNLGRP_IPV4_MROUTE_R multicast group.
+#[test]
+fn test_ipv4_cache_report_no_cache() {
+    let raw = vec![
+        0x80, 0x00, 0x00, 0x00, 0x05, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0xc0, 0x00, 0x02, 0x02, 0x08, 0x00, 0x04, 0x00, 0xef, 0x01, 0x01, 0x01,
+        0x08, 0x00, 0x06, 0x00, 0xfd, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x05, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x14, 0x00, 0x01, 0x00,
+    ];
+
+    let expected = CacheReportMessage {
+        header: CacheReportHeader {
+            family: AddressFamily::Other(128),
+        },
+        attributes: vec![
+            CacheReportAttribute::MessageType(CacheReportType::NoCache),
+            CacheReportAttribute::VifId(0),
+            CacheReportAttribute::SourceAddress(RouteAddress::Inet(
+                Ipv4Addr::from_str("192.0.2.2").unwrap(),
+            )),
+            CacheReportAttribute::DestinationAddress(RouteAddress::Inet(
+                Ipv4Addr::from_str("239.1.1.1").unwrap(),
+            )),
+            CacheReportAttribute::Table(253),
+            CacheReportAttribute::Packet(vec![
+                0x01, 0x00, 0x00, 0x00, 0x14, 0x00, 0x01, 0x00,
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        CacheReportMessage::parse(&CacheReportMessageBuffer::new(&raw))
+            .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add d0 type veth peer name d1
+//      ip -6 addr add 2001:db8::1/64 dev d0
+//      # Move d1 to another network namespace with 2001:db8::2/64 assigned.
+//      # Open multicast routing socket with `MRT6_INIT` and add d0 as MIF 0
+//      # via `MRT6_ADD_MIF`, then send UDP packet to ff0e::100 via d1.
+// Raw netlink message(header removed) of the RTNLGRP_IPV6_MROUTE_R
+// notification, the IP6MRA_CREPORT_PKT holds the `struct mrt6msg` (zero
+// `im6_mbz`, `im6_msgtype`, `im6_mif`, `im6_pad`, `im6_src` and `im6_dst`)
+// ip6mr generated for the upcall.
+#[test]
+fn test_ipv6_cache_report_no_cache() {
+    let raw = vec![
+        0x81, 0x00, 0x00, 0x00, 0x05, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x03, 0x00,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x14, 0x00, 0x04, 0x00, 0xff, 0x0e, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x2c, 0x00, 0x05, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0xff, 0x0e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+    ];
+
+    let expected = CacheReportMessage {
+        header: CacheReportHeader {
+            family: AddressFamily::Other(129),
+        },
+        attributes: vec![
+            CacheReportAttribute::MessageType(CacheReportType::NoCache),
+            CacheReportAttribute::VifId(0),
+            CacheReportAttribute::SourceAddress(RouteAddress::Inet6(
+                Ipv6Addr::from_str("2001:db8::2").unwrap(),
+            )),
+            CacheReportAttribute::DestinationAddress(RouteAddress::Inet6(
+                Ipv6Addr::from_str("ff0e::100").unwrap(),
+            )),
+            CacheReportAttribute::Packet(vec![
+                0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x01,
+                0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x02, 0xff, 0x0e, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        CacheReportMessage::parse(&CacheReportMessageBuffer::new(&raw))
+            .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
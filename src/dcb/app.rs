@@ -0,0 +1,227 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u8,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const DCB_ATTR_IEEE_APP: u16 = 1;
+const DCB_ATTR_DCB_APP: u16 = 2;
+
+const DCB_APP_LEN: usize = 4;
+
+buffer!(DcbAppBuffer(DCB_APP_LEN) {
+    selector: (u8, 0),
+    priority: (u8, 1),
+    protocol: (u16, 2..DCB_APP_LEN),
+});
+
+/// Application priority entry(`struct dcb_app`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DcbApp {
+    pub selector: DcbAppSelector,
+    pub priority: u8,
+    /// Ethertype, TCP/UDP port, DSCP or PCP value depending on
+    /// `selector`
+    pub protocol: u16,
+}
+
+impl<T: AsRef<[u8]>> Parseable<DcbAppBuffer<T>> for DcbApp {
+    fn parse(buf: &DcbAppBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            selector: buf.selector().into(),
+            priority: buf.priority(),
+            protocol: buf.protocol(),
+        })
+    }
+}
+
+impl Emitable for DcbApp {
+    fn buffer_len(&self) -> usize {
+        DCB_APP_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = DcbAppBuffer::new(buffer);
+        buffer.set_selector(self.selector.into());
+        buffer.set_priority(self.priority);
+        buffer.set_protocol(self.protocol);
+    }
+}
+
+/// Entry of [super::DcbIeeeAttribute::AppTable] and
+/// [super::DcbIeeeAttribute::RewriteTable]. Kernel uses
+/// [DcbAppEntry::Dcb] for [DcbAppSelector::Pcp] and [DcbAppEntry::Ieee]
+/// for others.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum DcbAppEntry {
+    Ieee(DcbApp),
+    Dcb(DcbApp),
+    Other(DefaultNla),
+}
+
+impl Nla for DcbAppEntry {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ieee(v) | Self::Dcb(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ieee(v) | Self::Dcb(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ieee(_) => DCB_ATTR_IEEE_APP,
+            Self::Dcb(_) => DCB_ATTR_DCB_APP,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for DcbAppEntry {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            DCB_ATTR_IEEE_APP => Self::Ieee(
+                DcbApp::parse(&DcbAppBuffer::new_checked(payload).context(
+                    format!("invalid DCB_ATTR_IEEE_APP {payload:?}"),
+                )?)
+                .context(format!("invalid DCB_ATTR_IEEE_APP {payload:?}"))?,
+            ),
+            DCB_ATTR_DCB_APP => Self::Dcb(
+                DcbApp::parse(&DcbAppBuffer::new_checked(payload).context(
+                    format!("invalid DCB_ATTR_DCB_APP {payload:?}"),
+                )?)
+                .context(format!("invalid DCB_ATTR_DCB_APP {payload:?}"))?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+/// Entry of [super::DcbIeeeAttribute::AppTrustTable], ordered from the
+/// most trusted selector. Kernel uses [DcbAppTrust::Dcb] for
+/// [DcbAppSelector::Pcp] and [DcbAppTrust::Ieee] for others.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum DcbAppTrust {
+    Ieee(DcbAppSelector),
+    Dcb(DcbAppSelector),
+    Other(DefaultNla),
+}
+
+impl Nla for DcbAppTrust {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ieee(_) | Self::Dcb(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ieee(v) | Self::Dcb(v) => buffer[0] = (*v).into(),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ieee(_) => DCB_ATTR_IEEE_APP,
+            Self::Dcb(_) => DCB_ATTR_DCB_APP,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for DcbAppTrust {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            DCB_ATTR_IEEE_APP => Self::Ieee(
+                parse_u8(payload)
+                    .context(format!("invalid DCB_ATTR_IEEE_APP {payload:?}"))?
+                    .into(),
+            ),
+            DCB_ATTR_DCB_APP => Self::Dcb(
+                parse_u8(payload)
+                    .context(format!("invalid DCB_ATTR_DCB_APP {payload:?}"))?
+                    .into(),
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+const IEEE_8021QAZ_APP_SEL_ETHERTYPE: u8 = 1;
+const IEEE_8021QAZ_APP_SEL_STREAM: u8 = 2;
+const IEEE_8021QAZ_APP_SEL_DGRAM: u8 = 3;
+const IEEE_8021QAZ_APP_SEL_ANY: u8 = 4;
+const IEEE_8021QAZ_APP_SEL_DSCP: u8 = 5;
+const DCB_APP_SEL_PCP: u8 = 255;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum DcbAppSelector {
+    Ethertype,
+    /// TCP or SCTP port
+    Stream,
+    /// UDP or DCCP port
+    Datagram,
+    /// TCP, SCTP, UDP or DCCP port
+    Any,
+    Dscp,
+    Pcp,
+    Other(u8),
+}
+
+impl From<u8> for DcbAppSelector {
+    fn from(d: u8) -> Self {
+        match d {
+            IEEE_8021QAZ_APP_SEL_ETHERTYPE => Self::Ethertype,
+            IEEE_8021QAZ_APP_SEL_STREAM => Self::Stream,
+            IEEE_8021QAZ_APP_SEL_DGRAM => Self::Datagram,
+            IEEE_8021QAZ_APP_SEL_ANY => Self::Any,
+            IEEE_8021QAZ_APP_SEL_DSCP => Self::Dscp,
+            DCB_APP_SEL_PCP => Self::Pcp,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<DcbAppSelector> for u8 {
+    fn from(v: DcbAppSelector) -> u8 {
+        match v {
+            DcbAppSelector::Ethertype => IEEE_8021QAZ_APP_SEL_ETHERTYPE,
+            DcbAppSelector::Stream => IEEE_8021QAZ_APP_SEL_STREAM,
+            DcbAppSelector::Datagram => IEEE_8021QAZ_APP_SEL_DGRAM,
+            DcbAppSelector::Any => IEEE_8021QAZ_APP_SEL_ANY,
+            DcbAppSelector::Dscp => IEEE_8021QAZ_APP_SEL_DSCP,
+            DcbAppSelector::Pcp => DCB_APP_SEL_PCP,
+            DcbAppSelector::Other(d) => d,
+        }
+    }
+}
+
+impl Default for DcbAppSelector {
+    fn default() -> Self {
+        Self::Other(0)
+    }
+}
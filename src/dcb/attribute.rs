@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_string, parse_u8},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use super::{DcbCommand, DcbIeeeAttribute};
+
+const DCB_ATTR_IFNAME: u16 = 1;
+// const DCB_ATTR_STATE: u16 = 2;
+// const DCB_ATTR_PFC_STATE: u16 = 3;
+// const DCB_ATTR_PFC_CFG: u16 = 4;
+// const DCB_ATTR_NUM_TC: u16 = 5;
+// const DCB_ATTR_PG_CFG: u16 = 6;
+// const DCB_ATTR_SET_ALL: u16 = 7;
+// const DCB_ATTR_PERM_HWADDR: u16 = 8;
+// const DCB_ATTR_CAP: u16 = 9;
+// const DCB_ATTR_NUMTCS: u16 = 10;
+// const DCB_ATTR_BCN: u16 = 11;
+// const DCB_ATTR_APP: u16 = 12;
+const DCB_ATTR_IEEE: u16 = 13;
+const DCB_ATTR_DCBX: u16 = 14;
+// const DCB_ATTR_FEATCFG: u16 = 15;
+// const DCB_ATTR_CEE: u16 = 16;
+
+/// Netlink attributes of `RTM_GETDCB` and `RTM_SETDCB` messages.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum DcbAttribute {
+    /// Interface name, required by every request
+    IfName(String),
+    /// IEEE 802.1Qaz configuration, used by [super::DcbCommand::IeeeGet],
+    /// [super::DcbCommand::IeeeSet] and [super::DcbCommand::IeeeDelete]
+    Ieee(Vec<DcbIeeeAttribute>),
+    /// Reply of [super::DcbCommand::IeeeSet] and
+    /// [super::DcbCommand::IeeeDelete], 0 for success
+    IeeeStatus(u8),
+    /// DCBX mode. In reply of [super::DcbCommand::SetDcbx], it holds the
+    /// return code instead, 0 for success.
+    Dcbx(DcbDcbxFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for DcbAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::IfName(s) => s.len() + 1,
+            Self::Ieee(v) => v.as_slice().buffer_len(),
+            Self::IeeeStatus(_) | Self::Dcbx(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::IfName(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::Ieee(v) => v.as_slice().emit(buffer),
+            Self::IeeeStatus(v) => buffer[0] = *v,
+            Self::Dcbx(v) => buffer[0] = v.bits(),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::IfName(_) => DCB_ATTR_IFNAME,
+            // Kernel is not setting NLA_F_NESTED for DCB_ATTR_IEEE
+            Self::Ieee(_) | Self::IeeeStatus(_) => DCB_ATTR_IEEE,
+            Self::Dcbx(_) => DCB_ATTR_DCBX,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+// The `command` is the `DcbHeader.command` of the message holding this
+// attribute. Kernel replies with the same command as the request.
+impl<'a, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NlaBuffer<&'a T>, DcbCommand> for DcbAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        command: DcbCommand,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            DCB_ATTR_IFNAME => Self::IfName(
+                parse_string(payload)
+                    .context(format!("invalid DCB_ATTR_IFNAME {payload:?}"))?,
+            ),
+            // In reply of DCB_CMD_IEEE_SET and DCB_CMD_IEEE_DEL, the
+            // DCB_ATTR_IEEE holds u8 return code, while the request of
+            // them holds nested attributes.
+            DCB_ATTR_IEEE
+                if matches!(
+                    command,
+                    DcbCommand::IeeeSet | DcbCommand::IeeeDelete
+                ) && payload.len() == 1 =>
+            {
+                Self::IeeeStatus(
+                    parse_u8(payload).context(format!(
+                        "invalid DCB_ATTR_IEEE {payload:?}"
+                    ))?,
+                )
+            }
+            DCB_ATTR_IEEE => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(format!(
+                        "invalid DCB_ATTR_IEEE value {payload:?}"
+                    ))?;
+                    nlas.push(DcbIeeeAttribute::parse(&nla)?);
+                }
+                Self::Ieee(nlas)
+            }
+            DCB_ATTR_DCBX => Self::Dcbx(DcbDcbxFlags::from_bits_retain(
+                parse_u8(payload)
+                    .context(format!("invalid DCB_ATTR_DCBX {payload:?}"))?,
+            )),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+const DCB_CAP_DCBX_HOST: u8 = 0x01;
+const DCB_CAP_DCBX_LLD_MANAGED: u8 = 0x02;
+const DCB_CAP_DCBX_VER_CEE: u8 = 0x04;
+const DCB_CAP_DCBX_VER_IEEE: u8 = 0x08;
+const DCB_CAP_DCBX_STATIC: u8 = 0x10;
+
+bitflags! {
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[non_exhaustive]
+    pub struct DcbDcbxFlags: u8 {
+        /// DCBX negotiation is performed by host LLDP agent
+        const Host = DCB_CAP_DCBX_HOST;
+        /// DCBX negotiation is performed by device firmware
+        const LldManaged = DCB_CAP_DCBX_LLD_MANAGED;
+        const VerCee = DCB_CAP_DCBX_VER_CEE;
+        const VerIeee = DCB_CAP_DCBX_VER_IEEE;
+        /// No DCBX negotiation, use static configuration only
+        const Static = DCB_CAP_DCBX_STATIC;
+        const _ = !0;
+    }
+}
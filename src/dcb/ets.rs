@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub(crate) const IEEE_8021QAZ_MAX_TCS: usize = 8;
+
+const IEEE_ETS_LEN: usize = 59;
+
+buffer!(DcbIeeeEtsBuffer(IEEE_ETS_LEN) {
+    willing: (u8, 0),
+    ets_cap: (u8, 1),
+    cbs: (u8, 2),
+    tc_tx_bw: (slice, 3..11),
+    tc_rx_bw: (slice, 11..19),
+    tc_tsa: (slice, 19..27),
+    prio_tc: (slice, 27..35),
+    tc_reco_bw: (slice, 35..43),
+    tc_reco_tsa: (slice, 43..51),
+    reco_prio_tc: (slice, 51..IEEE_ETS_LEN),
+});
+
+/// IEEE 802.1Qaz Enhanced Transmission Selection(`struct ieee_ets`).
+/// Arrays are indexed by traffic class except `prio_tc` and
+/// `reco_prio_tc` which are indexed by priority.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DcbIeeeEts {
+    pub willing: bool,
+    /// Number of traffic classes supporting ETS
+    pub ets_cap: u8,
+    /// Credit based shaper
+    pub cbs: bool,
+    /// Transmit bandwidth percentage
+    pub tc_tx_bw: [u8; IEEE_8021QAZ_MAX_TCS],
+    /// Receive bandwidth percentage
+    pub tc_rx_bw: [u8; IEEE_8021QAZ_MAX_TCS],
+    pub tc_tsa: [DcbIeeeTsa; IEEE_8021QAZ_MAX_TCS],
+    /// Traffic class of each priority
+    pub prio_tc: [u8; IEEE_8021QAZ_MAX_TCS],
+    pub tc_reco_bw: [u8; IEEE_8021QAZ_MAX_TCS],
+    pub tc_reco_tsa: [DcbIeeeTsa; IEEE_8021QAZ_MAX_TCS],
+    pub reco_prio_tc: [u8; IEEE_8021QAZ_MAX_TCS],
+}
+
+fn parse_tsa(payload: &[u8]) -> [DcbIeeeTsa; IEEE_8021QAZ_MAX_TCS] {
+    let mut ret = [DcbIeeeTsa::default(); IEEE_8021QAZ_MAX_TCS];
+    for (tsa, d) in ret.iter_mut().zip(payload) {
+        *tsa = (*d).into();
+    }
+    ret
+}
+
+fn emit_tsa(tsa: &[DcbIeeeTsa; IEEE_8021QAZ_MAX_TCS], buffer: &mut [u8]) {
+    for (d, v) in buffer.iter_mut().zip(tsa) {
+        *d = (*v).into();
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<DcbIeeeEtsBuffer<&'a T>>
+    for DcbIeeeEts
+{
+    fn parse(buf: &DcbIeeeEtsBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self {
+            willing: buf.willing() > 0,
+            ets_cap: buf.ets_cap(),
+            cbs: buf.cbs() > 0,
+            tc_tsa: parse_tsa(buf.tc_tsa()),
+            tc_reco_tsa: parse_tsa(buf.tc_reco_tsa()),
+            ..Default::default()
+        };
+        ret.tc_tx_bw.copy_from_slice(buf.tc_tx_bw());
+        ret.tc_rx_bw.copy_from_slice(buf.tc_rx_bw());
+        ret.prio_tc.copy_from_slice(buf.prio_tc());
+        ret.tc_reco_bw.copy_from_slice(buf.tc_reco_bw());
+        ret.reco_prio_tc.copy_from_slice(buf.reco_prio_tc());
+        Ok(ret)
+    }
+}
+
+impl Emitable for DcbIeeeEts {
+    fn buffer_len(&self) -> usize {
+        IEEE_ETS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = DcbIeeeEtsBuffer::new(buffer);
+        buffer.set_willing(self.willing.into());
+        buffer.set_ets_cap(self.ets_cap);
+        buffer.set_cbs(self.cbs.into());
+        buffer.tc_tx_bw_mut().copy_from_slice(&self.tc_tx_bw);
+        buffer.tc_rx_bw_mut().copy_from_slice(&self.tc_rx_bw);
+        emit_tsa(&self.tc_tsa, buffer.tc_tsa_mut());
+        buffer.prio_tc_mut().copy_from_slice(&self.prio_tc);
+        buffer.tc_reco_bw_mut().copy_from_slice(&self.tc_reco_bw);
+        emit_tsa(&self.tc_reco_tsa, buffer.tc_reco_tsa_mut());
+        buffer
+            .reco_prio_tc_mut()
+            .copy_from_slice(&self.reco_prio_tc);
+    }
+}
+
+const IEEE_8021QAZ_TSA_STRICT: u8 = 0;
+const IEEE_8021QAZ_TSA_CB_SHAPER: u8 = 1;
+const IEEE_8021QAZ_TSA_ETS: u8 = 2;
+const IEEE_8021QAZ_TSA_VENDOR: u8 = 255;
+
+/// Transmission Selection Algorithm of traffic class
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum DcbIeeeTsa {
+    #[default]
+    Strict,
+    CreditBasedShaper,
+    Ets,
+    Vendor,
+    Other(u8),
+}
+
+impl From<u8> for DcbIeeeTsa {
+    fn from(d: u8) -> Self {
+        match d {
+            IEEE_8021QAZ_TSA_STRICT => Self::Strict,
+            IEEE_8021QAZ_TSA_CB_SHAPER => Self::CreditBasedShaper,
+            IEEE_8021QAZ_TSA_ETS => Self::Ets,
+            IEEE_8021QAZ_TSA_VENDOR => Self::Vendor,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<DcbIeeeTsa> for u8 {
+    fn from(v: DcbIeeeTsa) -> u8 {
+        match v {
+            DcbIeeeTsa::Strict => IEEE_8021QAZ_TSA_STRICT,
+            DcbIeeeTsa::CreditBasedShaper => IEEE_8021QAZ_TSA_CB_SHAPER,
+            DcbIeeeTsa::Ets => IEEE_8021QAZ_TSA_ETS,
+            DcbIeeeTsa::Vendor => IEEE_8021QAZ_TSA_VENDOR,
+            DcbIeeeTsa::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::AddressFamily;
+
+const DCB_HEADER_LEN: usize = 4;
+
+buffer!(DcbMessageBuffer(DCB_HEADER_LEN) {
+    family: (u8, 0),
+    command: (u8, 1),
+    pad: (u16, 2..DCB_HEADER_LEN),
+    payload: (slice, DCB_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> DcbMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+/// High level representation of `RTM_GETDCB` and `RTM_SETDCB` messages
+/// headers(`struct dcbmsg`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct DcbHeader {
+    /// Always [AddressFamily::Unspec]
+    pub family: AddressFamily,
+    pub command: DcbCommand,
+}
+
+impl<T: AsRef<[u8]>> Parseable<DcbMessageBuffer<T>> for DcbHeader {
+    fn parse(buf: &DcbMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family().into(),
+            command: buf.command().into(),
+        })
+    }
+}
+
+impl Emitable for DcbHeader {
+    fn buffer_len(&self) -> usize {
+        DCB_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = DcbMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_command(self.command.into());
+        packet.set_pad(0);
+    }
+}
+
+const DCB_CMD_UNDEFINED: u8 = 0;
+const DCB_CMD_GSTATE: u8 = 1;
+const DCB_CMD_SSTATE: u8 = 2;
+const DCB_CMD_PGTX_GCFG: u8 = 3;
+const DCB_CMD_PGTX_SCFG: u8 = 4;
+const DCB_CMD_PGRX_GCFG: u8 = 5;
+const DCB_CMD_PGRX_SCFG: u8 = 6;
+const DCB_CMD_PFC_GCFG: u8 = 7;
+const DCB_CMD_PFC_SCFG: u8 = 8;
+const DCB_CMD_SET_ALL: u8 = 9;
+const DCB_CMD_GPERM_HWADDR: u8 = 10;
+const DCB_CMD_GCAP: u8 = 11;
+const DCB_CMD_GNUMTCS: u8 = 12;
+const DCB_CMD_SNUMTCS: u8 = 13;
+const DCB_CMD_PFC_GSTATE: u8 = 14;
+const DCB_CMD_PFC_SSTATE: u8 = 15;
+const DCB_CMD_BCN_GCFG: u8 = 16;
+const DCB_CMD_BCN_SCFG: u8 = 17;
+const DCB_CMD_GAPP: u8 = 18;
+const DCB_CMD_SAPP: u8 = 19;
+const DCB_CMD_IEEE_SET: u8 = 20;
+const DCB_CMD_IEEE_GET: u8 = 21;
+const DCB_CMD_GDCBX: u8 = 22;
+const DCB_CMD_SDCBX: u8 = 23;
+const DCB_CMD_GFEATCFG: u8 = 24;
+const DCB_CMD_SFEATCFG: u8 = 25;
+const DCB_CMD_CEE_GET: u8 = 26;
+const DCB_CMD_IEEE_DEL: u8 = 27;
+
+/// DCB command carried in `struct dcbmsg`, the reply shares the same
+/// command with the request.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum DcbCommand {
+    #[default]
+    Undefined,
+    GetState,
+    SetState,
+    GetPgTxConfig,
+    SetPgTxConfig,
+    GetPgRxConfig,
+    SetPgRxConfig,
+    GetPfcConfig,
+    SetPfcConfig,
+    SetAll,
+    GetPermanentHwAddress,
+    GetCapability,
+    GetNumTcs,
+    SetNumTcs,
+    GetPfcState,
+    SetPfcState,
+    GetBcnConfig,
+    SetBcnConfig,
+    GetApp,
+    SetApp,
+    IeeeSet,
+    IeeeGet,
+    GetDcbx,
+    SetDcbx,
+    GetFeatureConfig,
+    SetFeatureConfig,
+    CeeGet,
+    IeeeDelete,
+    Other(u8),
+}
+
+impl From<u8> for DcbCommand {
+    fn from(d: u8) -> Self {
+        match d {
+            DCB_CMD_UNDEFINED => Self::Undefined,
+            DCB_CMD_GSTATE => Self::GetState,
+            DCB_CMD_SSTATE => Self::SetState,
+            DCB_CMD_PGTX_GCFG => Self::GetPgTxConfig,
+            DCB_CMD_PGTX_SCFG => Self::SetPgTxConfig,
+            DCB_CMD_PGRX_GCFG => Self::GetPgRxConfig,
+            DCB_CMD_PGRX_SCFG => Self::SetPgRxConfig,
+            DCB_CMD_PFC_GCFG => Self::GetPfcConfig,
+            DCB_CMD_PFC_SCFG => Self::SetPfcConfig,
+            DCB_CMD_SET_ALL => Self::SetAll,
+            DCB_CMD_GPERM_HWADDR => Self::GetPermanentHwAddress,
+            DCB_CMD_GCAP => Self::GetCapability,
+            DCB_CMD_GNUMTCS => Self::GetNumTcs,
+            DCB_CMD_SNUMTCS => Self::SetNumTcs,
+            DCB_CMD_PFC_GSTATE => Self::GetPfcState,
+            DCB_CMD_PFC_SSTATE => Self::SetPfcState,
+            DCB_CMD_BCN_GCFG => Self::GetBcnConfig,
+            DCB_CMD_BCN_SCFG => Self::SetBcnConfig,
+            DCB_CMD_GAPP => Self::GetApp,
+            DCB_CMD_SAPP => Self::SetApp,
+            DCB_CMD_IEEE_SET => Self::IeeeSet,
+            DCB_CMD_IEEE_GET => Self::IeeeGet,
+            DCB_CMD_GDCBX => Self::GetDcbx,
+            DCB_CMD_SDCBX => Self::SetDcbx,
+            DCB_CMD_GFEATCFG => Self::GetFeatureConfig,
+            DCB_CMD_SFEATCFG => Self::SetFeatureConfig,
+            DCB_CMD_CEE_GET => Self::CeeGet,
+            DCB_CMD_IEEE_DEL => Self::IeeeDelete,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<DcbCommand> for u8 {
+    fn from(v: DcbCommand) -> u8 {
+        match v {
+            DcbCommand::Undefined => DCB_CMD_UNDEFINED,
+            DcbCommand::GetState => DCB_CMD_GSTATE,
+            DcbCommand::SetState => DCB_CMD_SSTATE,
+            DcbCommand::GetPgTxConfig => DCB_CMD_PGTX_GCFG,
+            DcbCommand::SetPgTxConfig => DCB_CMD_PGTX_SCFG,
+            DcbCommand::GetPgRxConfig => DCB_CMD_PGRX_GCFG,
+            DcbCommand::SetPgRxConfig => DCB_CMD_PGRX_SCFG,
+            DcbCommand::GetPfcConfig => DCB_CMD_PFC_GCFG,
+            DcbCommand::SetPfcConfig => DCB_CMD_PFC_SCFG,
+            DcbCommand::SetAll => DCB_CMD_SET_ALL,
+            DcbCommand::GetPermanentHwAddress => DCB_CMD_GPERM_HWADDR,
+            DcbCommand::GetCapability => DCB_CMD_GCAP,
+            DcbCommand::GetNumTcs => DCB_CMD_GNUMTCS,
+            DcbCommand::SetNumTcs => DCB_CMD_SNUMTCS,
+            DcbCommand::GetPfcState => DCB_CMD_PFC_GSTATE,
+            DcbCommand::SetPfcState => DCB_CMD_PFC_SSTATE,
+            DcbCommand::GetBcnConfig => DCB_CMD_BCN_GCFG,
+            DcbCommand::SetBcnConfig => DCB_CMD_BCN_SCFG,
+            DcbCommand::GetApp => DCB_CMD_GAPP,
+            DcbCommand::SetApp => DCB_CMD_SAPP,
+            DcbCommand::IeeeSet => DCB_CMD_IEEE_SET,
+            DcbCommand::IeeeGet => DCB_CMD_IEEE_GET,
+            DcbCommand::GetDcbx => DCB_CMD_GDCBX,
+            DcbCommand::SetDcbx => DCB_CMD_SDCBX,
+            DcbCommand::GetFeatureConfig => DCB_CMD_GFEATCFG,
+            DcbCommand::SetFeatureConfig => DCB_CMD_SFEATCFG,
+            DcbCommand::CeeGet => DCB_CMD_CEE_GET,
+            DcbCommand::IeeeDelete => DCB_CMD_IEEE_DEL,
+            DcbCommand::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    DcbAppEntry, DcbAppTrust, DcbIeeeEts, DcbIeeeEtsBuffer, DcbIeeeMaxRate,
+    DcbIeeeMaxRateBuffer, DcbIeeePfc, DcbIeeePfcBuffer, DcbIeeeQcn,
+    DcbIeeeQcnBuffer, DcbIeeeQcnStats, DcbIeeeQcnStatsBuffer,
+};
+
+const DCB_ATTR_IEEE_ETS: u16 = 1;
+const DCB_ATTR_IEEE_PFC: u16 = 2;
+const DCB_ATTR_IEEE_APP_TABLE: u16 = 3;
+const DCB_ATTR_IEEE_PEER_ETS: u16 = 4;
+const DCB_ATTR_IEEE_PEER_PFC: u16 = 5;
+// const DCB_ATTR_IEEE_PEER_APP: u16 = 6;
+const DCB_ATTR_IEEE_MAXRATE: u16 = 7;
+const DCB_ATTR_IEEE_QCN: u16 = 8;
+const DCB_ATTR_IEEE_QCN_STATS: u16 = 9;
+// const DCB_ATTR_DCB_BUFFER: u16 = 10;
+const DCB_ATTR_DCB_APP_TRUST_TABLE: u16 = 11;
+const DCB_ATTR_DCB_REWR_TABLE: u16 = 12;
+
+/// IEEE 802.1Qaz attributes nested in [super::DcbAttribute::Ieee].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+#[allow(clippy::large_enum_variant)]
+pub enum DcbIeeeAttribute {
+    /// Enhanced Transmission Selection
+    Ets(DcbIeeeEts),
+    /// Priority-based Flow Control
+    Pfc(DcbIeeePfc),
+    /// Application priority table
+    AppTable(Vec<DcbAppEntry>),
+    /// ETS configuration advertised by link peer
+    PeerEts(DcbIeeeEts),
+    /// PFC configuration advertised by link peer
+    PeerPfc(DcbIeeePfc),
+    MaxRate(DcbIeeeMaxRate),
+    /// Quantized Congestion Notification
+    Qcn(DcbIeeeQcn),
+    QcnStats(DcbIeeeQcnStats),
+    /// Ordered list of selectors trusted for packet prioritization
+    AppTrustTable(Vec<DcbAppTrust>),
+    /// Priority rewrite table
+    RewriteTable(Vec<DcbAppEntry>),
+    Other(DefaultNla),
+}
+
+impl Nla for DcbIeeeAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ets(v) | Self::PeerEts(v) => v.buffer_len(),
+            Self::Pfc(v) | Self::PeerPfc(v) => v.buffer_len(),
+            Self::AppTable(v) | Self::RewriteTable(v) => {
+                v.as_slice().buffer_len()
+            }
+            Self::MaxRate(v) => v.buffer_len(),
+            Self::Qcn(v) => v.buffer_len(),
+            Self::QcnStats(v) => v.buffer_len(),
+            Self::AppTrustTable(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ets(v) | Self::PeerEts(v) => v.emit(buffer),
+            Self::Pfc(v) | Self::PeerPfc(v) => v.emit(buffer),
+            Self::AppTable(v) | Self::RewriteTable(v) => {
+                v.as_slice().emit(buffer)
+            }
+            Self::MaxRate(v) => v.emit(buffer),
+            Self::Qcn(v) => v.emit(buffer),
+            Self::QcnStats(v) => v.emit(buffer),
+            Self::AppTrustTable(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ets(_) => DCB_ATTR_IEEE_ETS,
+            Self::Pfc(_) => DCB_ATTR_IEEE_PFC,
+            // Kernel is not setting NLA_F_NESTED for DCB_ATTR_IEEE_APP_TABLE
+            Self::AppTable(_) => DCB_ATTR_IEEE_APP_TABLE,
+            Self::PeerEts(_) => DCB_ATTR_IEEE_PEER_ETS,
+            Self::PeerPfc(_) => DCB_ATTR_IEEE_PEER_PFC,
+            Self::MaxRate(_) => DCB_ATTR_IEEE_MAXRATE,
+            Self::Qcn(_) => DCB_ATTR_IEEE_QCN,
+            Self::QcnStats(_) => DCB_ATTR_IEEE_QCN_STATS,
+            Self::AppTrustTable(_) => {
+                DCB_ATTR_DCB_APP_TRUST_TABLE | NLA_F_NESTED
+            }
+            Self::RewriteTable(_) => DCB_ATTR_DCB_REWR_TABLE | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for DcbIeeeAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            DCB_ATTR_IEEE_ETS => Self::Ets(
+                DcbIeeeEts::parse(
+                    &DcbIeeeEtsBuffer::new_checked(payload).context(
+                        format!("invalid DCB_ATTR_IEEE_ETS {payload:?}"),
+                    )?,
+                )
+                .context(format!("invalid DCB_ATTR_IEEE_ETS {payload:?}"))?,
+            ),
+            DCB_ATTR_IEEE_PFC => Self::Pfc(
+                DcbIeeePfc::parse(
+                    &DcbIeeePfcBuffer::new_checked(payload).context(
+                        format!("invalid DCB_ATTR_IEEE_PFC {payload:?}"),
+                    )?,
+                )
+                .context(format!("invalid DCB_ATTR_IEEE_PFC {payload:?}"))?,
+            ),
+            DCB_ATTR_IEEE_APP_TABLE => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(format!(
+                        "invalid DCB_ATTR_IEEE_APP_TABLE value {payload:?}"
+                    ))?;
+                    nlas.push(DcbAppEntry::parse(&nla)?);
+                }
+                Self::AppTable(nlas)
+            }
+            DCB_ATTR_IEEE_PEER_ETS => Self::PeerEts(
+                DcbIeeeEts::parse(
+                    &DcbIeeeEtsBuffer::new_checked(payload).context(
+                        format!("invalid DCB_ATTR_IEEE_PEER_ETS {payload:?}"),
+                    )?,
+                )
+                .context(format!(
+                    "invalid DCB_ATTR_IEEE_PEER_ETS {payload:?}"
+                ))?,
+            ),
+            DCB_ATTR_IEEE_PEER_PFC => Self::PeerPfc(
+                DcbIeeePfc::parse(
+                    &DcbIeeePfcBuffer::new_checked(payload).context(
+                        format!("invalid DCB_ATTR_IEEE_PEER_PFC {payload:?}"),
+                    )?,
+                )
+                .context(format!(
+                    "invalid DCB_ATTR_IEEE_PEER_PFC {payload:?}"
+                ))?,
+            ),
+            DCB_ATTR_IEEE_MAXRATE => Self::MaxRate(
+                DcbIeeeMaxRate::parse(
+                    &DcbIeeeMaxRateBuffer::new_checked(payload).context(
+                        format!("invalid DCB_ATTR_IEEE_MAXRATE {payload:?}"),
+                    )?,
+                )
+                .context(format!(
+                    "invalid DCB_ATTR_IEEE_MAXRATE {payload:?}"
+                ))?,
+            ),
+            DCB_ATTR_IEEE_QCN => Self::Qcn(
+                DcbIeeeQcn::parse(
+                    &DcbIeeeQcnBuffer::new_checked(payload).context(
+                        format!("invalid DCB_ATTR_IEEE_QCN {payload:?}"),
+                    )?,
+                )
+                .context(format!("invalid DCB_ATTR_IEEE_QCN {payload:?}"))?,
+            ),
+            DCB_ATTR_IEEE_QCN_STATS => Self::QcnStats(
+                DcbIeeeQcnStats::parse(
+                    &DcbIeeeQcnStatsBuffer::new_checked(payload).context(
+                        format!("invalid DCB_ATTR_IEEE_QCN_STATS {payload:?}"),
+                    )?,
+                )
+                .context(format!(
+                    "invalid DCB_ATTR_IEEE_QCN_STATS {payload:?}"
+                ))?,
+            ),
+            DCB_ATTR_DCB_APP_TRUST_TABLE => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(format!(
+                        "invalid DCB_ATTR_DCB_APP_TRUST_TABLE value \
+                        {payload:?}"
+                    ))?;
+                    nlas.push(DcbAppTrust::parse(&nla)?);
+                }
+                Self::AppTrustTable(nlas)
+            }
+            DCB_ATTR_DCB_REWR_TABLE => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(format!(
+                        "invalid DCB_ATTR_DCB_REWR_TABLE value {payload:?}"
+                    ))?;
+                    nlas.push(DcbAppEntry::parse(&nla)?);
+                }
+                Self::RewriteTable(nlas)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
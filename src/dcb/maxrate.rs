@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::ets::IEEE_8021QAZ_MAX_TCS;
+
+const IEEE_MAXRATE_LEN: usize = 64;
+
+buffer!(DcbIeeeMaxRateBuffer(IEEE_MAXRATE_LEN) {
+    tc_maxrate: (slice, 0..IEEE_MAXRATE_LEN),
+});
+
+/// Maximum transmit bandwidth of each traffic class in kbit/s
+/// (`struct ieee_maxrate`), 0 means unlimited.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DcbIeeeMaxRate {
+    pub tc_maxrate: [u64; IEEE_8021QAZ_MAX_TCS],
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<DcbIeeeMaxRateBuffer<&'a T>>
+    for DcbIeeeMaxRate
+{
+    fn parse(buf: &DcbIeeeMaxRateBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        NativeEndian::read_u64_into(buf.tc_maxrate(), &mut ret.tc_maxrate);
+        Ok(ret)
+    }
+}
+
+impl Emitable for DcbIeeeMaxRate {
+    fn buffer_len(&self) -> usize {
+        IEEE_MAXRATE_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = DcbIeeeMaxRateBuffer::new(buffer);
+        NativeEndian::write_u64_into(&self.tc_maxrate, buffer.tc_maxrate_mut());
+    }
+}
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::dcb::{DcbAttribute, DcbCommand, DcbHeader, DcbMessageBuffer};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct DcbMessage {
+    pub header: DcbHeader,
+    pub attributes: Vec<DcbAttribute>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<DcbMessageBuffer<&'a T>>
+    for DcbMessage
+{
+    fn parse(buf: &DcbMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let header = DcbHeader::parse(buf)
+            .context("failed to parse DCB message header")?;
+        let attributes =
+            Vec::<DcbAttribute>::parse_with_param(buf, header.command)
+                .context("failed to parse DCB message NLAs")?;
+        Ok(Self { header, attributes })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a>
+    ParseableParametrized<DcbMessageBuffer<&'a T>, DcbCommand>
+    for Vec<DcbAttribute>
+{
+    fn parse_with_param(
+        buf: &DcbMessageBuffer<&'a T>,
+        command: DcbCommand,
+    ) -> Result<Self, DecodeError> {
+        let mut attributes = vec![];
+        for nla_buf in buf.attributes() {
+            attributes
+                .push(DcbAttribute::parse_with_param(&nla_buf?, command)?);
+        }
+        Ok(attributes)
+    }
+}
+
+impl Emitable for DcbMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
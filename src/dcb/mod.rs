@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+
+mod app;
+mod attribute;
+mod ets;
+mod header;
+mod ieee;
+mod maxrate;
+mod message;
+mod pfc;
+mod qcn;
+#[cfg(test)]
+mod tests;
+
+pub use self::app::{
+    DcbApp, DcbAppBuffer, DcbAppEntry, DcbAppSelector, DcbAppTrust,
+};
+pub use self::attribute::{DcbAttribute, DcbDcbxFlags};
+pub use self::ets::{DcbIeeeEts, DcbIeeeEtsBuffer, DcbIeeeTsa};
+pub use self::header::{DcbCommand, DcbHeader, DcbMessageBuffer};
+pub use self::ieee::DcbIeeeAttribute;
+pub use self::maxrate::{DcbIeeeMaxRate, DcbIeeeMaxRateBuffer};
+pub use self::message::DcbMessage;
+pub use self::pfc::{DcbIeeePfc, DcbIeeePfcBuffer};
+pub use self::qcn::{
+    DcbIeeeQcn, DcbIeeeQcnBuffer, DcbIeeeQcnStats, DcbIeeeQcnStatsBuffer,
+};
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::ets::IEEE_8021QAZ_MAX_TCS;
+
+const IEEE_PFC_LEN: usize = 136;
+
+buffer!(DcbIeeePfcBuffer(IEEE_PFC_LEN) {
+    pfc_cap: (u8, 0),
+    pfc_en: (u8, 1),
+    mbc: (u8, 2),
+    delay: (u16, 4..6),
+    requests: (slice, 8..72),
+    indications: (slice, 72..IEEE_PFC_LEN),
+});
+
+/// IEEE 802.1Qaz Priority-based Flow Control(`struct ieee_pfc`).
+/// Arrays are indexed by priority.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DcbIeeePfc {
+    /// Number of traffic classes supporting PFC
+    pub pfc_cap: u8,
+    /// Bitmap of priorities with PFC enabled
+    pub pfc_en: u8,
+    /// MACsec bypass capability
+    pub mbc: bool,
+    /// Allowance for cable length and internal delay in bit times
+    pub delay: u16,
+    /// Count of PFC frames sent
+    pub requests: [u64; IEEE_8021QAZ_MAX_TCS],
+    /// Count of PFC frames received
+    pub indications: [u64; IEEE_8021QAZ_MAX_TCS],
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<DcbIeeePfcBuffer<&'a T>>
+    for DcbIeeePfc
+{
+    fn parse(buf: &DcbIeeePfcBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self {
+            pfc_cap: buf.pfc_cap(),
+            pfc_en: buf.pfc_en(),
+            mbc: buf.mbc() > 0,
+            delay: buf.delay(),
+            ..Default::default()
+        };
+        NativeEndian::read_u64_into(buf.requests(), &mut ret.requests);
+        NativeEndian::read_u64_into(buf.indications(), &mut ret.indications);
+        Ok(ret)
+    }
+}
+
+impl Emitable for DcbIeeePfc {
+    fn buffer_len(&self) -> usize {
+        IEEE_PFC_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = DcbIeeePfcBuffer::new(buffer);
+        buffer.set_pfc_cap(self.pfc_cap);
+        buffer.set_pfc_en(self.pfc_en);
+        buffer.set_mbc(self.mbc.into());
+        buffer.set_delay(self.delay);
+        NativeEndian::write_u64_into(&self.requests, buffer.requests_mut());
+        NativeEndian::write_u64_into(
+            &self.indications,
+            buffer.indications_mut(),
+        );
+    }
+}
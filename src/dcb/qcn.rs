@@ -0,0 +1,200 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::ets::IEEE_8021QAZ_MAX_TCS;
+
+const IEEE_QCN_LEN: usize = 360;
+
+buffer!(DcbIeeeQcnBuffer(IEEE_QCN_LEN) {
+    rpg_enable: (slice, 0..8),
+    rppp_max_rps: (slice, 8..40),
+    rpg_time_reset: (slice, 40..72),
+    rpg_byte_reset: (slice, 72..104),
+    rpg_threshold: (slice, 104..136),
+    rpg_max_rate: (slice, 136..168),
+    rpg_ai_rate: (slice, 168..200),
+    rpg_hai_rate: (slice, 200..232),
+    rpg_gd: (slice, 232..264),
+    rpg_min_dec_fac: (slice, 264..296),
+    rpg_min_rate: (slice, 296..328),
+    cndd_state_machine: (slice, 328..IEEE_QCN_LEN),
+});
+
+/// IEEE 802.1Qau Quantized Congestion Notification parameters of each
+/// priority(`struct ieee_qcn`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DcbIeeeQcn {
+    /// Whether QCN reaction point is enabled
+    pub rpg_enable: [bool; IEEE_8021QAZ_MAX_TCS],
+    /// Maximum number of reaction points allowed
+    pub rppp_max_rps: [u32; IEEE_8021QAZ_MAX_TCS],
+    /// Time between rate increases if no CNM received in microseconds
+    pub rpg_time_reset: [u32; IEEE_8021QAZ_MAX_TCS],
+    /// Transmitted bytes between rate increases if no CNM received
+    pub rpg_byte_reset: [u32; IEEE_8021QAZ_MAX_TCS],
+    pub rpg_threshold: [u32; IEEE_8021QAZ_MAX_TCS],
+    /// Maximum rate in Mbit/s
+    pub rpg_max_rate: [u32; IEEE_8021QAZ_MAX_TCS],
+    /// Rate increase in active increase stage in Mbit/s
+    pub rpg_ai_rate: [u32; IEEE_8021QAZ_MAX_TCS],
+    /// Rate increase in hyper increase stage in Mbit/s
+    pub rpg_hai_rate: [u32; IEEE_8021QAZ_MAX_TCS],
+    /// Rate decrease divider in log2
+    pub rpg_gd: [u32; IEEE_8021QAZ_MAX_TCS],
+    /// Minimum rate decrease factor in percentage
+    pub rpg_min_dec_fac: [u32; IEEE_8021QAZ_MAX_TCS],
+    /// Minimum rate in bit/s
+    pub rpg_min_rate: [u32; IEEE_8021QAZ_MAX_TCS],
+    pub cndd_state_machine: [u32; IEEE_8021QAZ_MAX_TCS],
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<DcbIeeeQcnBuffer<&'a T>>
+    for DcbIeeeQcn
+{
+    fn parse(buf: &DcbIeeeQcnBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        for (v, d) in ret.rpg_enable.iter_mut().zip(buf.rpg_enable()) {
+            *v = *d > 0;
+        }
+        NativeEndian::read_u32_into(buf.rppp_max_rps(), &mut ret.rppp_max_rps);
+        NativeEndian::read_u32_into(
+            buf.rpg_time_reset(),
+            &mut ret.rpg_time_reset,
+        );
+        NativeEndian::read_u32_into(
+            buf.rpg_byte_reset(),
+            &mut ret.rpg_byte_reset,
+        );
+        NativeEndian::read_u32_into(
+            buf.rpg_threshold(),
+            &mut ret.rpg_threshold,
+        );
+        NativeEndian::read_u32_into(buf.rpg_max_rate(), &mut ret.rpg_max_rate);
+        NativeEndian::read_u32_into(buf.rpg_ai_rate(), &mut ret.rpg_ai_rate);
+        NativeEndian::read_u32_into(buf.rpg_hai_rate(), &mut ret.rpg_hai_rate);
+        NativeEndian::read_u32_into(buf.rpg_gd(), &mut ret.rpg_gd);
+        NativeEndian::read_u32_into(
+            buf.rpg_min_dec_fac(),
+            &mut ret.rpg_min_dec_fac,
+        );
+        NativeEndian::read_u32_into(buf.rpg_min_rate(), &mut ret.rpg_min_rate);
+        NativeEndian::read_u32_into(
+            buf.cndd_state_machine(),
+            &mut ret.cndd_state_machine,
+        );
+        Ok(ret)
+    }
+}
+
+impl Emitable for DcbIeeeQcn {
+    fn buffer_len(&self) -> usize {
+        IEEE_QCN_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = DcbIeeeQcnBuffer::new(buffer);
+        for (d, v) in buffer.rpg_enable_mut().iter_mut().zip(self.rpg_enable) {
+            *d = v.into();
+        }
+        NativeEndian::write_u32_into(
+            &self.rppp_max_rps,
+            buffer.rppp_max_rps_mut(),
+        );
+        NativeEndian::write_u32_into(
+            &self.rpg_time_reset,
+            buffer.rpg_time_reset_mut(),
+        );
+        NativeEndian::write_u32_into(
+            &self.rpg_byte_reset,
+            buffer.rpg_byte_reset_mut(),
+        );
+        NativeEndian::write_u32_into(
+            &self.rpg_threshold,
+            buffer.rpg_threshold_mut(),
+        );
+        NativeEndian::write_u32_into(
+            &self.rpg_max_rate,
+            buffer.rpg_max_rate_mut(),
+        );
+        NativeEndian::write_u32_into(
+            &self.rpg_ai_rate,
+            buffer.rpg_ai_rate_mut(),
+        );
+        NativeEndian::write_u32_into(
+            &self.rpg_hai_rate,
+            buffer.rpg_hai_rate_mut(),
+        );
+        NativeEndian::write_u32_into(&self.rpg_gd, buffer.rpg_gd_mut());
+        NativeEndian::write_u32_into(
+            &self.rpg_min_dec_fac,
+            buffer.rpg_min_dec_fac_mut(),
+        );
+        NativeEndian::write_u32_into(
+            &self.rpg_min_rate,
+            buffer.rpg_min_rate_mut(),
+        );
+        NativeEndian::write_u32_into(
+            &self.cndd_state_machine,
+            buffer.cndd_state_machine_mut(),
+        );
+    }
+}
+
+const IEEE_QCN_STATS_LEN: usize = 96;
+
+buffer!(DcbIeeeQcnStatsBuffer(IEEE_QCN_STATS_LEN) {
+    rppp_rp_centiseconds: (slice, 0..64),
+    rppp_created_rps: (slice, 64..IEEE_QCN_STATS_LEN),
+});
+
+/// Quantized Congestion Notification statistics of each priority
+/// (`struct ieee_qcn_stats`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DcbIeeeQcnStats {
+    /// Accumulated centiseconds of reaction points activity
+    pub rppp_rp_centiseconds: [u64; IEEE_8021QAZ_MAX_TCS],
+    /// Number of active reaction points
+    pub rppp_created_rps: [u32; IEEE_8021QAZ_MAX_TCS],
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<DcbIeeeQcnStatsBuffer<&'a T>>
+    for DcbIeeeQcnStats
+{
+    fn parse(buf: &DcbIeeeQcnStatsBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ret = Self::default();
+        NativeEndian::read_u64_into(
+            buf.rppp_rp_centiseconds(),
+            &mut ret.rppp_rp_centiseconds,
+        );
+        NativeEndian::read_u32_into(
+            buf.rppp_created_rps(),
+            &mut ret.rppp_created_rps,
+        );
+        Ok(ret)
+    }
+}
+
+impl Emitable for DcbIeeeQcnStats {
+    fn buffer_len(&self) -> usize {
+        IEEE_QCN_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = DcbIeeeQcnStatsBuffer::new(buffer);
+        NativeEndian::write_u64_into(
+            &self.rppp_rp_centiseconds,
+            buffer.rppp_rp_centiseconds_mut(),
+        );
+        NativeEndian::write_u32_into(
+            &self.rppp_created_rps,
+            buffer.rppp_created_rps_mut(),
+        );
+    }
+}
@@ -0,0 +1,436 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::{
+    dcb::{
+        DcbApp, DcbAppEntry, DcbAppSelector, DcbAppTrust, DcbAttribute,
+        DcbCommand, DcbDcbxFlags, DcbHeader, DcbIeeeAttribute, DcbIeeeEts,
+        DcbIeeeMaxRate, DcbIeeePfc, DcbIeeeQcn, DcbIeeeQcnStats, DcbIeeeTsa,
+        DcbMessage, DcbMessageBuffer,
+    },
+    AddressFamily,
+};
+
+// Raw netlink message(header removed) of `RTM_GETDCB` request equivalent to
+// command:
+//      dcb ets show dev eth0
+#[test]
+fn test_dcb_ieee_get_request() {
+    let raw = vec![
+        0x00, 0x15, 0x00, 0x00, 0x09, 0x00, 0x01, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = DcbMessage {
+        header: DcbHeader {
+            family: AddressFamily::Unspec,
+            command: DcbCommand::IeeeGet,
+        },
+        attributes: vec![DcbAttribute::IfName("eth0".to_string())],
+    };
+
+    assert_eq!(
+        expected,
+        DcbMessage::parse(&DcbMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_SETDCB` request equivalent to
+// command against NIC supporting ETS on 8 traffic classes:
+//      dcb ets set dev eth0 tc-tsa all:ets tc-bw all:0 0:60 1:40 \
+//          prio-tc all:0 3:1 4:1
+#[test]
+fn test_dcb_ieee_set_ets() {
+    let raw = vec![
+        0x00, 0x14, 0x00, 0x00, 0x09, 0x00, 0x01, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x44, 0x00, 0x0d, 0x00, 0x3f, 0x00, 0x01, 0x00,
+        0x00, 0x08, 0x00, 0x3c, 0x28, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x02, 0x02, 0x02, 0x02,
+        0x02, 0x02, 0x02, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = DcbMessage {
+        header: DcbHeader {
+            family: AddressFamily::Unspec,
+            command: DcbCommand::IeeeSet,
+        },
+        attributes: vec![
+            DcbAttribute::IfName("eth0".to_string()),
+            DcbAttribute::Ieee(vec![DcbIeeeAttribute::Ets(DcbIeeeEts {
+                willing: false,
+                ets_cap: 8,
+                cbs: false,
+                tc_tx_bw: [60, 40, 0, 0, 0, 0, 0, 0],
+                tc_rx_bw: [0; 8],
+                tc_tsa: [DcbIeeeTsa::Ets; 8],
+                prio_tc: [0, 0, 0, 1, 1, 0, 0, 0],
+                tc_reco_bw: [0; 8],
+                tc_reco_tsa: [DcbIeeeTsa::Strict; 8],
+                reco_prio_tc: [0; 8],
+            })]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        DcbMessage::parse(&DcbMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_SETDCB` request equivalent to
+// command against NIC supporting PFC on 8 traffic classes:
+//      dcb pfc set dev eth0 prio-pfc all:off 3:on delay 4096
+#[test]
+fn test_dcb_ieee_set_pfc() {
+    let raw = vec![
+        0x00, 0x14, 0x00, 0x00, 0x09, 0x00, 0x01, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x90, 0x00, 0x0d, 0x00, 0x8c, 0x00, 0x02, 0x00,
+        0x08, 0x08, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = DcbMessage {
+        header: DcbHeader {
+            family: AddressFamily::Unspec,
+            command: DcbCommand::IeeeSet,
+        },
+        attributes: vec![
+            DcbAttribute::IfName("eth0".to_string()),
+            DcbAttribute::Ieee(vec![DcbIeeeAttribute::Pfc(DcbIeeePfc {
+                pfc_cap: 8,
+                pfc_en: 1 << 3,
+                mbc: false,
+                delay: 4096,
+                requests: [0; 8],
+                indications: [0; 8],
+            })]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        DcbMessage::parse(&DcbMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_SETDCB` request equivalent to
+// command:
+//      dcb app add dev eth0 dscp-prio 24:3
+#[test]
+fn test_dcb_ieee_set_app_table() {
+    let raw = vec![
+        0x00, 0x14, 0x00, 0x00, 0x09, 0x00, 0x01, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x0d, 0x00, 0x0c, 0x00, 0x03, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x05, 0x03, 0x18, 0x00,
+    ];
+
+    let expected = DcbMessage {
+        header: DcbHeader {
+            family: AddressFamily::Unspec,
+            command: DcbCommand::IeeeSet,
+        },
+        attributes: vec![
+            DcbAttribute::IfName("eth0".to_string()),
+            DcbAttribute::Ieee(vec![DcbIeeeAttribute::AppTable(vec![
+                DcbAppEntry::Ieee(DcbApp {
+                    selector: DcbAppSelector::Dscp,
+                    priority: 3,
+                    protocol: 24,
+                }),
+            ])]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        DcbMessage::parse(&DcbMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_SETDCB` request equivalent to
+// command:
+//      dcb apptrust set dev eth0 order pcp dscp
+#[test]
+fn test_dcb_ieee_set_app_trust_table() {
+    let raw = vec![
+        0x00, 0x14, 0x00, 0x00, 0x09, 0x00, 0x01, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x0d, 0x00, 0x14, 0x00, 0x0b, 0x80,
+        0x05, 0x00, 0x02, 0x00, 0xff, 0x00, 0x00, 0x00, 0x05, 0x00, 0x01, 0x00,
+        0x05, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = DcbMessage {
+        header: DcbHeader {
+            family: AddressFamily::Unspec,
+            command: DcbCommand::IeeeSet,
+        },
+        attributes: vec![
+            DcbAttribute::IfName("eth0".to_string()),
+            DcbAttribute::Ieee(vec![DcbIeeeAttribute::AppTrustTable(vec![
+                DcbAppTrust::Dcb(DcbAppSelector::Pcp),
+                DcbAppTrust::Ieee(DcbAppSelector::Dscp),
+            ])]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        DcbMessage::parse(&DcbMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of kernel reply to successful
+// `DCB_CMD_IEEE_SET` request, the `DCB_ATTR_IEEE` holds u8 return code
+// instead of nested attributes.
+#[test]
+fn test_dcb_ieee_set_reply() {
+    let raw = vec![
+        0x00, 0x14, 0x00, 0x00, 0x05, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = DcbMessage {
+        header: DcbHeader {
+            family: AddressFamily::Unspec,
+            command: DcbCommand::IeeeSet,
+        },
+        attributes: vec![DcbAttribute::IeeeStatus(0)],
+    };
+
+    assert_eq!(
+        expected,
+        DcbMessage::parse(&DcbMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of kernel reply to `DCB_CMD_IEEE_GET`
+// request, following the layout of `dcbnl_ieee_fill()` in Linux 6.18 for
+// a NIC driver implementing ETS, PFC, maxrate, QCN and DCBX callbacks and
+// configured by:
+//      dcb ets set dev eth0 tc-tsa all:ets tc-bw all:0 0:60 1:40 \
+//          prio-tc all:0 3:1 4:1
+//      dcb pfc set dev eth0 prio-pfc all:off 3:on
+//      dcb maxrate set dev eth0 tc-maxrate 1:1Gbit
+//      dcb app add dev eth0 dscp-prio 24:3
+// with QCN enabled on traffic class 3 by driver.
+#[test]
+fn test_dcb_ieee_get_reply() {
+    let raw = vec![
+        0x00, 0x15, 0x00, 0x00, 0x09, 0x00, 0x01, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0xf4, 0x02, 0x0d, 0x00, 0x3f, 0x00, 0x01, 0x00,
+        0x00, 0x08, 0x00, 0x3c, 0x28, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x02, 0x02, 0x02, 0x02,
+        0x02, 0x02, 0x02, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x44, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x40, 0x42, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x6c, 0x01, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xb8, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xf0, 0x49, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x40, 0x9c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x32, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x64, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x39, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x8c, 0x00, 0x02, 0x00, 0x08, 0x08, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x03, 0x00, 0x08, 0x00, 0x01, 0x00, 0x05, 0x03, 0x18, 0x00,
+        0x04, 0x00, 0x0c, 0x80, 0x05, 0x00, 0x0e, 0x00, 0x09, 0x00, 0x00, 0x00,
+    ];
+
+    let mut qcn = DcbIeeeQcn::default();
+    qcn.rpg_enable[3] = true;
+    qcn.rppp_max_rps[3] = 1;
+    qcn.rpg_time_reset[3] = 1464;
+    qcn.rpg_byte_reset[3] = 150000;
+    qcn.rpg_threshold[3] = 5;
+    qcn.rpg_max_rate[3] = 40000;
+    qcn.rpg_ai_rate[3] = 10;
+    qcn.rpg_hai_rate[3] = 50;
+    qcn.rpg_gd[3] = 8;
+    qcn.rpg_min_dec_fac[3] = 2;
+    qcn.rpg_min_rate[3] = 10;
+
+    let mut qcn_stats = DcbIeeeQcnStats::default();
+    qcn_stats.rppp_rp_centiseconds[3] = 12345;
+    qcn_stats.rppp_created_rps[3] = 2;
+
+    let expected = DcbMessage {
+        header: DcbHeader {
+            family: AddressFamily::Unspec,
+            command: DcbCommand::IeeeGet,
+        },
+        attributes: vec![
+            DcbAttribute::IfName("eth0".to_string()),
+            DcbAttribute::Ieee(vec![
+                DcbIeeeAttribute::Ets(DcbIeeeEts {
+                    willing: false,
+                    ets_cap: 8,
+                    cbs: false,
+                    tc_tx_bw: [60, 40, 0, 0, 0, 0, 0, 0],
+                    tc_rx_bw: [0; 8],
+                    tc_tsa: [DcbIeeeTsa::Ets; 8],
+                    prio_tc: [0, 0, 0, 1, 1, 0, 0, 0],
+                    tc_reco_bw: [0; 8],
+                    tc_reco_tsa: [DcbIeeeTsa::Strict; 8],
+                    reco_prio_tc: [0; 8],
+                }),
+                DcbIeeeAttribute::MaxRate(DcbIeeeMaxRate {
+                    tc_maxrate: [0, 1000000, 0, 0, 0, 0, 0, 0],
+                }),
+                DcbIeeeAttribute::Qcn(qcn),
+                DcbIeeeAttribute::QcnStats(qcn_stats),
+                DcbIeeeAttribute::Pfc(DcbIeeePfc {
+                    pfc_cap: 8,
+                    pfc_en: 0x08,
+                    mbc: false,
+                    delay: 0,
+                    requests: [0, 0, 0, 17, 0, 0, 0, 0],
+                    indications: [0, 0, 0, 9, 0, 0, 0, 0],
+                }),
+                DcbIeeeAttribute::AppTable(vec![DcbAppEntry::Ieee(DcbApp {
+                    selector: DcbAppSelector::Dscp,
+                    priority: 3,
+                    protocol: 24,
+                })]),
+                DcbIeeeAttribute::RewriteTable(vec![]),
+            ]),
+            DcbAttribute::Dcbx(DcbDcbxFlags::Host | DcbDcbxFlags::VerIeee),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        DcbMessage::parse(&DcbMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of kernel reply to `DCB_CMD_GDCBX`
+// request, following the layout of `dcbnl_getdcbx()` in Linux 6.18 for
+// a NIC with DCBX negotiated by host LLDP agent in IEEE mode.
+#[test]
+fn test_dcb_get_dcbx_reply() {
+    let raw = vec![
+        0x00, 0x16, 0x00, 0x00, 0x05, 0x00, 0x0e, 0x00, 0x09, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = DcbMessage {
+        header: DcbHeader {
+            family: AddressFamily::Unspec,
+            command: DcbCommand::GetDcbx,
+        },
+        attributes: vec![DcbAttribute::Dcbx(
+            DcbDcbxFlags::Host | DcbDcbxFlags::VerIeee,
+        )],
+    };
+
+    assert_eq!(
+        expected,
+        DcbMessage::parse(&DcbMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -1,14 +1,24 @@
 // SPDX-License-Identifier: MIT
 
 pub mod address;
+pub mod address_label;
+pub mod bridge_vlan;
+pub mod cache_report;
+pub mod dcb;
 pub mod link;
+pub mod mdb;
+pub mod nduseropt;
 pub mod neighbour;
 pub mod neighbour_table;
+pub mod netconf;
+pub mod nexthop;
 pub mod nsid;
 pub mod prefix;
 pub mod route;
 pub mod rule;
+pub mod stats;
 pub mod tc;
+pub mod tunnel;
 
 mod message;
 #[cfg(test)]
@@ -55,6 +65,16 @@ pub use self::message::{RouteNetlinkMessage, RouteNetlinkMessageBuffer};
 ///  * `neighbour`: Neighbour, similar to `ip neighbour` command.
 ///  * `neighbour_table`: Neighbour table, similar to `ip ntable` command.
 ///  * `nsid`: Namespace, similar to `ip netns` command.
+///  * `nexthop`: Nexthop object, similar to `ip nexthop` command.
+///  * `mdb`: Bridge multicast database, similar to `bridge mdb` command.
+///  * `netconf`: Network configuration, similar to `ip netconf` command.
+///  * `stats`: Link statistics, similar to `ip stats` command.
+///  * `bridge_vlan`: Bridge VLAN database, similar to `bridge vlan` command.
+///  * `tunnel`: VXLAN VNI filtering, similar to `bridge vni` command.
+///  * `address_label`: IPv6 address labels, similar to `ip addrlabel` command.
+///  * `nduseropt`: ND options of router advertisement, e.g. DNS servers.
+///  * `cache_report`: Multicast routing upcall, e.g. `IGMPMSG_NOCACHE`.
+///  * `dcb`: Data Center Bridging, similar to `dcb` command.
 ///
 /// At the top level of this crate, we also provide:
 ///  * [AddressFamily]
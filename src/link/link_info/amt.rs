@@ -0,0 +1,170 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16_be, parse_u32},
+    traits::Parseable,
+    DecodeError,
+};
+
+use crate::ip::parse_ipv4_addr;
+
+const IFLA_AMT_MODE: u16 = 1;
+const IFLA_AMT_RELAY_PORT: u16 = 2;
+const IFLA_AMT_GATEWAY_PORT: u16 = 3;
+const IFLA_AMT_LINK: u16 = 4;
+const IFLA_AMT_LOCAL_IP: u16 = 5;
+const IFLA_AMT_REMOTE_IP: u16 = 6;
+const IFLA_AMT_DISCOVERY_IP: u16 = 7;
+const IFLA_AMT_MAX_TUNNELS: u16 = 8;
+
+const AMT_MODE_GATEWAY: u32 = 0;
+const AMT_MODE_RELAY: u32 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum InfoAmt {
+    Mode(AmtMode),
+    /// UDP port of the relay
+    RelayPort(u16),
+    /// UDP port of the gateway
+    GatewayPort(u16),
+    /// Interface index of the underlying device
+    Link(u32),
+    LocalIp(Ipv4Addr),
+    /// Address of the relay, only for gateway mode
+    RemoteIp(Ipv4Addr),
+    /// Address used by gateway to discover the relay
+    DiscoveryIp(Ipv4Addr),
+    /// Maximum number of tunnels, only for relay mode
+    MaxTunnels(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoAmt {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RelayPort(_) | Self::GatewayPort(_) => 2,
+            Self::Mode(_)
+            | Self::Link(_)
+            | Self::LocalIp(_)
+            | Self::RemoteIp(_)
+            | Self::DiscoveryIp(_)
+            | Self::MaxTunnels(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Mode(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::RelayPort(value) | Self::GatewayPort(value) => {
+                BigEndian::write_u16(buffer, *value)
+            }
+            Self::Link(value) | Self::MaxTunnels(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::LocalIp(value)
+            | Self::RemoteIp(value)
+            | Self::DiscoveryIp(value) => {
+                buffer.copy_from_slice(&value.octets())
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Mode(_) => IFLA_AMT_MODE,
+            Self::RelayPort(_) => IFLA_AMT_RELAY_PORT,
+            Self::GatewayPort(_) => IFLA_AMT_GATEWAY_PORT,
+            Self::Link(_) => IFLA_AMT_LINK,
+            Self::LocalIp(_) => IFLA_AMT_LOCAL_IP,
+            Self::RemoteIp(_) => IFLA_AMT_REMOTE_IP,
+            Self::DiscoveryIp(_) => IFLA_AMT_DISCOVERY_IP,
+            Self::MaxTunnels(_) => IFLA_AMT_MAX_TUNNELS,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoAmt {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_AMT_MODE => Self::Mode(
+                parse_u32(payload)
+                    .context("invalid IFLA_AMT_MODE value")?
+                    .into(),
+            ),
+            IFLA_AMT_RELAY_PORT => Self::RelayPort(
+                parse_u16_be(payload)
+                    .context("invalid IFLA_AMT_RELAY_PORT value")?,
+            ),
+            IFLA_AMT_GATEWAY_PORT => Self::GatewayPort(
+                parse_u16_be(payload)
+                    .context("invalid IFLA_AMT_GATEWAY_PORT value")?,
+            ),
+            IFLA_AMT_LINK => Self::Link(
+                parse_u32(payload).context("invalid IFLA_AMT_LINK value")?,
+            ),
+            IFLA_AMT_LOCAL_IP => Self::LocalIp(
+                parse_ipv4_addr(payload)
+                    .context("invalid IFLA_AMT_LOCAL_IP value")?,
+            ),
+            IFLA_AMT_REMOTE_IP => Self::RemoteIp(
+                parse_ipv4_addr(payload)
+                    .context("invalid IFLA_AMT_REMOTE_IP value")?,
+            ),
+            IFLA_AMT_DISCOVERY_IP => Self::DiscoveryIp(
+                parse_ipv4_addr(payload)
+                    .context("invalid IFLA_AMT_DISCOVERY_IP value")?,
+            ),
+            IFLA_AMT_MAX_TUNNELS => Self::MaxTunnels(
+                parse_u32(payload)
+                    .context("invalid IFLA_AMT_MAX_TUNNELS value")?,
+            ),
+            unknown_kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!(
+                    "Failed to parse IFLA_INFO_DATA(amt) NLA type: \
+                    {unknown_kind} as DefaultNla"
+                ))?)
+            }
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum AmtMode {
+    #[default]
+    Gateway,
+    Relay,
+    Other(u32),
+}
+
+impl From<u32> for AmtMode {
+    fn from(d: u32) -> Self {
+        match d {
+            AMT_MODE_GATEWAY => Self::Gateway,
+            AMT_MODE_RELAY => Self::Relay,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<AmtMode> for u32 {
+    fn from(d: AmtMode) -> Self {
+        match d {
+            AmtMode::Gateway => AMT_MODE_GATEWAY,
+            AmtMode::Relay => AMT_MODE_RELAY,
+            AmtMode::Other(value) => value,
+        }
+    }
+}
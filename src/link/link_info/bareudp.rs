@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u16_be},
+    traits::Parseable,
+    DecodeError,
+};
+
+const IFLA_BAREUDP_PORT: u16 = 1;
+const IFLA_BAREUDP_ETHERTYPE: u16 = 2;
+const IFLA_BAREUDP_SRCPORT_MIN: u16 = 3;
+const IFLA_BAREUDP_MULTIPROTO_MODE: u16 = 4;
+
+const ETH_P_IP: u16 = 0x0800;
+const ETH_P_IPV6: u16 = 0x86DD;
+const ETH_P_MPLS_UC: u16 = 0x8847;
+const ETH_P_MPLS_MC: u16 = 0x8848;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum InfoBareUdp {
+    /// Destination UDP port
+    Port(u16),
+    /// Protocol of the tunnelled traffic
+    EtherType(BareUdpEtherType),
+    /// Lower bound of the source UDP port range
+    SrcPortMin(u16),
+    /// Also accept IPv6 with [BareUdpEtherType::Ipv4] or multicast MPLS
+    /// with [BareUdpEtherType::MplsUnicast].
+    MultiProtoMode,
+    Other(DefaultNla),
+}
+
+impl Nla for InfoBareUdp {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Port(_) | Self::EtherType(_) | Self::SrcPortMin(_) => 2,
+            Self::MultiProtoMode => 0,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Port(value) => BigEndian::write_u16(buffer, *value),
+            Self::EtherType(value) => {
+                BigEndian::write_u16(buffer, (*value).into())
+            }
+            Self::SrcPortMin(value) => NativeEndian::write_u16(buffer, *value),
+            Self::MultiProtoMode => (),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Port(_) => IFLA_BAREUDP_PORT,
+            Self::EtherType(_) => IFLA_BAREUDP_ETHERTYPE,
+            Self::SrcPortMin(_) => IFLA_BAREUDP_SRCPORT_MIN,
+            Self::MultiProtoMode => IFLA_BAREUDP_MULTIPROTO_MODE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBareUdp {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BAREUDP_PORT => Self::Port(
+                parse_u16_be(payload)
+                    .context("invalid IFLA_BAREUDP_PORT value")?,
+            ),
+            IFLA_BAREUDP_ETHERTYPE => Self::EtherType(
+                parse_u16_be(payload)
+                    .context("invalid IFLA_BAREUDP_ETHERTYPE value")?
+                    .into(),
+            ),
+            IFLA_BAREUDP_SRCPORT_MIN => Self::SrcPortMin(
+                parse_u16(payload)
+                    .context("invalid IFLA_BAREUDP_SRCPORT_MIN value")?,
+            ),
+            IFLA_BAREUDP_MULTIPROTO_MODE => Self::MultiProtoMode,
+            unknown_kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!(
+                    "Failed to parse IFLA_INFO_DATA(bareudp) NLA type: \
+                    {unknown_kind} as DefaultNla"
+                ))?)
+            }
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum BareUdpEtherType {
+    #[default]
+    Ipv4,
+    Ipv6,
+    MplsUnicast,
+    MplsMulticast,
+    Other(u16),
+}
+
+impl From<u16> for BareUdpEtherType {
+    fn from(d: u16) -> Self {
+        match d {
+            ETH_P_IP => Self::Ipv4,
+            ETH_P_IPV6 => Self::Ipv6,
+            ETH_P_MPLS_UC => Self::MplsUnicast,
+            ETH_P_MPLS_MC => Self::MplsMulticast,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<BareUdpEtherType> for u16 {
+    fn from(d: BareUdpEtherType) -> Self {
+        match d {
+            BareUdpEtherType::Ipv4 => ETH_P_IP,
+            BareUdpEtherType::Ipv6 => ETH_P_IPV6,
+            BareUdpEtherType::MplsUnicast => ETH_P_MPLS_UC,
+            BareUdpEtherType::MplsMulticast => ETH_P_MPLS_MC,
+            BareUdpEtherType::Other(value) => value,
+        }
+    }
+}
@@ -0,0 +1,545 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    CanBerrCounter, CanBerrCounterBuffer, CanBitTiming, CanBitTimingBuffer,
+    CanBitTimingConst, CanBitTimingConstBuffer,
+};
+
+const IFLA_CAN_BITTIMING: u16 = 1;
+const IFLA_CAN_BITTIMING_CONST: u16 = 2;
+const IFLA_CAN_CLOCK: u16 = 3;
+const IFLA_CAN_STATE: u16 = 4;
+const IFLA_CAN_CTRLMODE: u16 = 5;
+const IFLA_CAN_RESTART_MS: u16 = 6;
+const IFLA_CAN_RESTART: u16 = 7;
+const IFLA_CAN_BERR_COUNTER: u16 = 8;
+const IFLA_CAN_DATA_BITTIMING: u16 = 9;
+const IFLA_CAN_DATA_BITTIMING_CONST: u16 = 10;
+const IFLA_CAN_TERMINATION: u16 = 11;
+const IFLA_CAN_TERMINATION_CONST: u16 = 12;
+const IFLA_CAN_BITRATE_CONST: u16 = 13;
+const IFLA_CAN_DATA_BITRATE_CONST: u16 = 14;
+const IFLA_CAN_BITRATE_MAX: u16 = 15;
+const IFLA_CAN_TDC: u16 = 16;
+const IFLA_CAN_CTRLMODE_EXT: u16 = 17;
+
+const CAN_CTRLMODE_LEN: usize = 8;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum InfoCan {
+    BitTiming(CanBitTiming),
+    BitTimingConst(CanBitTimingConst),
+    /// CAN system clock frequency in Hz
+    Clock(u32),
+    State(CanState),
+    CtrlMode(CanCtrlMode),
+    /// Delay in milliseconds before automatically restarting after bus-off,
+    /// 0 means disabled.
+    RestartMs(u32),
+    /// Request manual restart after bus-off, only valid in request.
+    Restart,
+    BerrCounter(CanBerrCounter),
+    /// Bit timing of the CAN FD data phase
+    DataBitTiming(CanBitTiming),
+    DataBitTimingConst(CanBitTimingConst),
+    /// Bus termination resistance in Ohm
+    Termination(u16),
+    /// Supported bus termination resistances in Ohm
+    TerminationConst(Vec<u16>),
+    /// Supported bit rates for hardware without flexible bit timing
+    BitRateConst(Vec<u32>),
+    /// Supported data phase bit rates for hardware without flexible bit
+    /// timing
+    DataBitRateConst(Vec<u32>),
+    BitRateMax(u32),
+    /// Transmitter delay compensation of the CAN FD data phase
+    Tdc(Vec<CanTdc>),
+    CtrlModeExt(Vec<CanCtrlModeExt>),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoCan {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::BitTiming(v) | Self::DataBitTiming(v) => v.buffer_len(),
+            Self::BitTimingConst(v) | Self::DataBitTimingConst(v) => {
+                v.buffer_len()
+            }
+            Self::CtrlMode(_) => CAN_CTRLMODE_LEN,
+            Self::BerrCounter(v) => v.buffer_len(),
+            Self::Termination(_) => 2,
+            Self::Clock(_)
+            | Self::State(_)
+            | Self::RestartMs(_)
+            | Self::Restart
+            | Self::BitRateMax(_) => 4,
+            Self::TerminationConst(v) => v.len() * 2,
+            Self::BitRateConst(v) | Self::DataBitRateConst(v) => v.len() * 4,
+            Self::Tdc(nlas) => nlas.as_slice().buffer_len(),
+            Self::CtrlModeExt(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::BitTiming(v) | Self::DataBitTiming(v) => v.emit(buffer),
+            Self::BitTimingConst(v) | Self::DataBitTimingConst(v) => {
+                v.emit(buffer)
+            }
+            Self::Clock(value)
+            | Self::RestartMs(value)
+            | Self::BitRateMax(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::State(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::CtrlMode(value) => {
+                NativeEndian::write_u32(buffer, value.mask.bits());
+                NativeEndian::write_u32(&mut buffer[4..], value.flags.bits());
+            }
+            // Kernel ignores the value, iproute2 is using 1
+            Self::Restart => NativeEndian::write_u32(buffer, 1),
+            Self::BerrCounter(v) => v.emit(buffer),
+            Self::Termination(value) => NativeEndian::write_u16(buffer, *value),
+            Self::TerminationConst(v) => {
+                NativeEndian::write_u16_into(v.as_slice(), buffer)
+            }
+            Self::BitRateConst(v) | Self::DataBitRateConst(v) => {
+                NativeEndian::write_u32_into(v.as_slice(), buffer)
+            }
+            Self::Tdc(nlas) => nlas.as_slice().emit(buffer),
+            Self::CtrlModeExt(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::BitTiming(_) => IFLA_CAN_BITTIMING,
+            Self::BitTimingConst(_) => IFLA_CAN_BITTIMING_CONST,
+            Self::Clock(_) => IFLA_CAN_CLOCK,
+            Self::State(_) => IFLA_CAN_STATE,
+            Self::CtrlMode(_) => IFLA_CAN_CTRLMODE,
+            Self::RestartMs(_) => IFLA_CAN_RESTART_MS,
+            Self::Restart => IFLA_CAN_RESTART,
+            Self::BerrCounter(_) => IFLA_CAN_BERR_COUNTER,
+            Self::DataBitTiming(_) => IFLA_CAN_DATA_BITTIMING,
+            Self::DataBitTimingConst(_) => IFLA_CAN_DATA_BITTIMING_CONST,
+            Self::Termination(_) => IFLA_CAN_TERMINATION,
+            Self::TerminationConst(_) => IFLA_CAN_TERMINATION_CONST,
+            Self::BitRateConst(_) => IFLA_CAN_BITRATE_CONST,
+            Self::DataBitRateConst(_) => IFLA_CAN_DATA_BITRATE_CONST,
+            Self::BitRateMax(_) => IFLA_CAN_BITRATE_MAX,
+            Self::Tdc(_) => IFLA_CAN_TDC | NLA_F_NESTED,
+            Self::CtrlModeExt(_) => IFLA_CAN_CTRLMODE_EXT | NLA_F_NESTED,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoCan {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_CAN_BITTIMING => Self::BitTiming(
+                CanBitTiming::parse(
+                    &CanBitTimingBuffer::new_checked(payload)
+                        .context("invalid IFLA_CAN_BITTIMING value")?,
+                )
+                .context("invalid IFLA_CAN_BITTIMING value")?,
+            ),
+            IFLA_CAN_BITTIMING_CONST => Self::BitTimingConst(
+                CanBitTimingConst::parse(
+                    &CanBitTimingConstBuffer::new_checked(payload)
+                        .context("invalid IFLA_CAN_BITTIMING_CONST value")?,
+                )
+                .context("invalid IFLA_CAN_BITTIMING_CONST value")?,
+            ),
+            IFLA_CAN_CLOCK => Self::Clock(
+                parse_u32(payload).context("invalid IFLA_CAN_CLOCK value")?,
+            ),
+            IFLA_CAN_STATE => Self::State(
+                parse_u32(payload)
+                    .context("invalid IFLA_CAN_STATE value")?
+                    .into(),
+            ),
+            IFLA_CAN_CTRLMODE => {
+                if payload.len() < CAN_CTRLMODE_LEN {
+                    return Err(format!(
+                        "invalid IFLA_CAN_CTRLMODE value {payload:?}"
+                    )
+                    .into());
+                }
+                Self::CtrlMode(CanCtrlMode {
+                    mask: CanCtrlModeFlags::from_bits_retain(
+                        NativeEndian::read_u32(&payload[..4]),
+                    ),
+                    flags: CanCtrlModeFlags::from_bits_retain(
+                        NativeEndian::read_u32(&payload[4..8]),
+                    ),
+                })
+            }
+            IFLA_CAN_RESTART_MS => Self::RestartMs(
+                parse_u32(payload)
+                    .context("invalid IFLA_CAN_RESTART_MS value")?,
+            ),
+            IFLA_CAN_RESTART => Self::Restart,
+            IFLA_CAN_BERR_COUNTER => Self::BerrCounter(
+                CanBerrCounter::parse(
+                    &CanBerrCounterBuffer::new_checked(payload)
+                        .context("invalid IFLA_CAN_BERR_COUNTER value")?,
+                )
+                .context("invalid IFLA_CAN_BERR_COUNTER value")?,
+            ),
+            IFLA_CAN_DATA_BITTIMING => Self::DataBitTiming(
+                CanBitTiming::parse(
+                    &CanBitTimingBuffer::new_checked(payload)
+                        .context("invalid IFLA_CAN_DATA_BITTIMING value")?,
+                )
+                .context("invalid IFLA_CAN_DATA_BITTIMING value")?,
+            ),
+            IFLA_CAN_DATA_BITTIMING_CONST => Self::DataBitTimingConst(
+                CanBitTimingConst::parse(
+                    &CanBitTimingConstBuffer::new_checked(payload).context(
+                        "invalid IFLA_CAN_DATA_BITTIMING_CONST value",
+                    )?,
+                )
+                .context("invalid IFLA_CAN_DATA_BITTIMING_CONST value")?,
+            ),
+            IFLA_CAN_TERMINATION => Self::Termination(
+                parse_u16(payload)
+                    .context("invalid IFLA_CAN_TERMINATION value")?,
+            ),
+            IFLA_CAN_TERMINATION_CONST => Self::TerminationConst(
+                payload
+                    .chunks_exact(2)
+                    .map(NativeEndian::read_u16)
+                    .collect(),
+            ),
+            IFLA_CAN_BITRATE_CONST => Self::BitRateConst(
+                payload
+                    .chunks_exact(4)
+                    .map(NativeEndian::read_u32)
+                    .collect(),
+            ),
+            IFLA_CAN_DATA_BITRATE_CONST => Self::DataBitRateConst(
+                payload
+                    .chunks_exact(4)
+                    .map(NativeEndian::read_u32)
+                    .collect(),
+            ),
+            IFLA_CAN_BITRATE_MAX => Self::BitRateMax(
+                parse_u32(payload)
+                    .context("invalid IFLA_CAN_BITRATE_MAX value")?,
+            ),
+            IFLA_CAN_TDC => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(format!(
+                        "invalid IFLA_CAN_TDC value {payload:?}"
+                    ))?;
+                    nlas.push(CanTdc::parse(&nla)?);
+                }
+                Self::Tdc(nlas)
+            }
+            IFLA_CAN_CTRLMODE_EXT => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(format!(
+                        "invalid IFLA_CAN_CTRLMODE_EXT value {payload:?}"
+                    ))?;
+                    nlas.push(CanCtrlModeExt::parse(&nla)?);
+                }
+                Self::CtrlModeExt(nlas)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind} for can"))?,
+            ),
+        })
+    }
+}
+
+const CAN_STATE_ERROR_ACTIVE: u32 = 0;
+const CAN_STATE_ERROR_WARNING: u32 = 1;
+const CAN_STATE_ERROR_PASSIVE: u32 = 2;
+const CAN_STATE_BUS_OFF: u32 = 3;
+const CAN_STATE_STOPPED: u32 = 4;
+const CAN_STATE_SLEEPING: u32 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum CanState {
+    /// RX/TX error count < 96
+    #[default]
+    ErrorActive,
+    /// RX/TX error count < 128
+    ErrorWarning,
+    /// RX/TX error count < 256
+    ErrorPassive,
+    /// RX/TX error count >= 256
+    BusOff,
+    /// Device is stopped
+    Stopped,
+    /// Device is sleeping
+    Sleeping,
+    Other(u32),
+}
+
+impl From<u32> for CanState {
+    fn from(d: u32) -> Self {
+        match d {
+            CAN_STATE_ERROR_ACTIVE => Self::ErrorActive,
+            CAN_STATE_ERROR_WARNING => Self::ErrorWarning,
+            CAN_STATE_ERROR_PASSIVE => Self::ErrorPassive,
+            CAN_STATE_BUS_OFF => Self::BusOff,
+            CAN_STATE_STOPPED => Self::Stopped,
+            CAN_STATE_SLEEPING => Self::Sleeping,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<CanState> for u32 {
+    fn from(d: CanState) -> Self {
+        match d {
+            CanState::ErrorActive => CAN_STATE_ERROR_ACTIVE,
+            CanState::ErrorWarning => CAN_STATE_ERROR_WARNING,
+            CanState::ErrorPassive => CAN_STATE_ERROR_PASSIVE,
+            CanState::BusOff => CAN_STATE_BUS_OFF,
+            CanState::Stopped => CAN_STATE_STOPPED,
+            CanState::Sleeping => CAN_STATE_SLEEPING,
+            CanState::Other(value) => value,
+        }
+    }
+}
+
+/// CAN controller mode(`struct can_ctrlmode`). Only modes set in `mask`
+/// are changed to the state in `flags` when configuring.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct CanCtrlMode {
+    pub mask: CanCtrlModeFlags,
+    pub flags: CanCtrlModeFlags,
+}
+
+const CAN_CTRLMODE_LOOPBACK: u32 = 0x01;
+const CAN_CTRLMODE_LISTENONLY: u32 = 0x02;
+const CAN_CTRLMODE_3_SAMPLES: u32 = 0x04;
+const CAN_CTRLMODE_ONE_SHOT: u32 = 0x08;
+const CAN_CTRLMODE_BERR_REPORTING: u32 = 0x10;
+const CAN_CTRLMODE_FD: u32 = 0x20;
+const CAN_CTRLMODE_PRESUME_ACK: u32 = 0x40;
+const CAN_CTRLMODE_FD_NON_ISO: u32 = 0x80;
+const CAN_CTRLMODE_CC_LEN8_DLC: u32 = 0x100;
+const CAN_CTRLMODE_TDC_AUTO: u32 = 0x200;
+const CAN_CTRLMODE_TDC_MANUAL: u32 = 0x400;
+
+bitflags! {
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[non_exhaustive]
+    pub struct CanCtrlModeFlags: u32 {
+        const Loopback = CAN_CTRLMODE_LOOPBACK;
+        const ListenOnly = CAN_CTRLMODE_LISTENONLY;
+        /// Triple sampling mode
+        const TripleSampling = CAN_CTRLMODE_3_SAMPLES;
+        const OneShot = CAN_CTRLMODE_ONE_SHOT;
+        /// Bus-error reporting
+        const BerrReporting = CAN_CTRLMODE_BERR_REPORTING;
+        /// CAN FD mode
+        const Fd = CAN_CTRLMODE_FD;
+        /// Ignore missing CAN ACKs
+        const PresumeAck = CAN_CTRLMODE_PRESUME_ACK;
+        /// CAN FD in non-ISO mode
+        const FdNonIso = CAN_CTRLMODE_FD_NON_ISO;
+        /// Classic CAN DLC option
+        const CcLen8Dlc = CAN_CTRLMODE_CC_LEN8_DLC;
+        /// Transmitter delay compensation value calculated by hardware
+        const TdcAuto = CAN_CTRLMODE_TDC_AUTO;
+        /// Transmitter delay compensation value provided by user
+        const TdcManual = CAN_CTRLMODE_TDC_MANUAL;
+        const _ = !0;
+    }
+}
+
+const IFLA_CAN_TDC_TDCV_MIN: u16 = 1;
+const IFLA_CAN_TDC_TDCV_MAX: u16 = 2;
+const IFLA_CAN_TDC_TDCO_MIN: u16 = 3;
+const IFLA_CAN_TDC_TDCO_MAX: u16 = 4;
+const IFLA_CAN_TDC_TDCF_MIN: u16 = 5;
+const IFLA_CAN_TDC_TDCF_MAX: u16 = 6;
+const IFLA_CAN_TDC_TDCV: u16 = 7;
+const IFLA_CAN_TDC_TDCO: u16 = 8;
+const IFLA_CAN_TDC_TDCF: u16 = 9;
+
+/// Transmitter delay compensation, all values are in minimum time quanta.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum CanTdc {
+    TdcvMin(u32),
+    TdcvMax(u32),
+    TdcoMin(u32),
+    TdcoMax(u32),
+    TdcfMin(u32),
+    TdcfMax(u32),
+    /// Transmitter delay compensation value
+    Tdcv(u32),
+    /// Transmitter delay compensation offset
+    Tdco(u32),
+    /// Transmitter delay compensation filter window
+    Tdcf(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for CanTdc {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Other(nla) => nla.value_len(),
+            _ => 4,
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::TdcvMin(value)
+            | Self::TdcvMax(value)
+            | Self::TdcoMin(value)
+            | Self::TdcoMax(value)
+            | Self::TdcfMin(value)
+            | Self::TdcfMax(value)
+            | Self::Tdcv(value)
+            | Self::Tdco(value)
+            | Self::Tdcf(value) => NativeEndian::write_u32(buffer, *value),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::TdcvMin(_) => IFLA_CAN_TDC_TDCV_MIN,
+            Self::TdcvMax(_) => IFLA_CAN_TDC_TDCV_MAX,
+            Self::TdcoMin(_) => IFLA_CAN_TDC_TDCO_MIN,
+            Self::TdcoMax(_) => IFLA_CAN_TDC_TDCO_MAX,
+            Self::TdcfMin(_) => IFLA_CAN_TDC_TDCF_MIN,
+            Self::TdcfMax(_) => IFLA_CAN_TDC_TDCF_MAX,
+            Self::Tdcv(_) => IFLA_CAN_TDC_TDCV,
+            Self::Tdco(_) => IFLA_CAN_TDC_TDCO,
+            Self::Tdcf(_) => IFLA_CAN_TDC_TDCF,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for CanTdc {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_CAN_TDC_TDCV_MIN => Self::TdcvMin(
+                parse_u32(payload)
+                    .context("invalid IFLA_CAN_TDC_TDCV_MIN value")?,
+            ),
+            IFLA_CAN_TDC_TDCV_MAX => Self::TdcvMax(
+                parse_u32(payload)
+                    .context("invalid IFLA_CAN_TDC_TDCV_MAX value")?,
+            ),
+            IFLA_CAN_TDC_TDCO_MIN => Self::TdcoMin(
+                parse_u32(payload)
+                    .context("invalid IFLA_CAN_TDC_TDCO_MIN value")?,
+            ),
+            IFLA_CAN_TDC_TDCO_MAX => Self::TdcoMax(
+                parse_u32(payload)
+                    .context("invalid IFLA_CAN_TDC_TDCO_MAX value")?,
+            ),
+            IFLA_CAN_TDC_TDCF_MIN => Self::TdcfMin(
+                parse_u32(payload)
+                    .context("invalid IFLA_CAN_TDC_TDCF_MIN value")?,
+            ),
+            IFLA_CAN_TDC_TDCF_MAX => Self::TdcfMax(
+                parse_u32(payload)
+                    .context("invalid IFLA_CAN_TDC_TDCF_MAX value")?,
+            ),
+            IFLA_CAN_TDC_TDCV => Self::Tdcv(
+                parse_u32(payload)
+                    .context("invalid IFLA_CAN_TDC_TDCV value")?,
+            ),
+            IFLA_CAN_TDC_TDCO => Self::Tdco(
+                parse_u32(payload)
+                    .context("invalid IFLA_CAN_TDC_TDCO value")?,
+            ),
+            IFLA_CAN_TDC_TDCF => Self::Tdcf(
+                parse_u32(payload)
+                    .context("invalid IFLA_CAN_TDC_TDCF value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind} for can tdc"))?,
+            ),
+        })
+    }
+}
+
+const IFLA_CAN_CTRLMODE_SUPPORTED: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum CanCtrlModeExt {
+    /// Controller modes supported by the device
+    Supported(CanCtrlModeFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for CanCtrlModeExt {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Supported(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Supported(value) => {
+                NativeEndian::write_u32(buffer, value.bits())
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Supported(_) => IFLA_CAN_CTRLMODE_SUPPORTED,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for CanCtrlModeExt
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_CAN_CTRLMODE_SUPPORTED => {
+                Self::Supported(CanCtrlModeFlags::from_bits_retain(
+                    parse_u32(payload)
+                        .context("invalid IFLA_CAN_CTRLMODE_SUPPORTED value")?,
+                ))
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for can ctrlmode ext"
+            ))?),
+        })
+    }
+}
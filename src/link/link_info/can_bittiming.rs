@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const CAN_BITTIMING_LEN: usize = 32;
+
+buffer!(CanBitTimingBuffer(CAN_BITTIMING_LEN) {
+    bitrate: (u32, 0..4),
+    sample_point: (u32, 4..8),
+    tq: (u32, 8..12),
+    prop_seg: (u32, 12..16),
+    phase_seg1: (u32, 16..20),
+    phase_seg2: (u32, 20..24),
+    sjw: (u32, 24..28),
+    brp: (u32, 28..32),
+});
+
+/// CAN bit timing(`struct can_bittiming`). When configuring, setting
+/// `bitrate` and optionally `sample_point` is enough for kernel to calculate
+/// the rest.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct CanBitTiming {
+    /// Bit rate in bits/second
+    pub bitrate: u32,
+    /// Sample point in one-tenth of a percent, e.g. 875 for 87.5%
+    pub sample_point: u32,
+    /// Time quanta in nanoseconds
+    pub tq: u32,
+    /// Propagation segment in time quanta
+    pub prop_seg: u32,
+    /// Phase buffer segment 1 in time quanta
+    pub phase_seg1: u32,
+    /// Phase buffer segment 2 in time quanta
+    pub phase_seg2: u32,
+    /// Synchronisation jump width in time quanta
+    pub sjw: u32,
+    /// Bit rate prescaler
+    pub brp: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<CanBitTimingBuffer<T>> for CanBitTiming {
+    fn parse(buf: &CanBitTimingBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            bitrate: buf.bitrate(),
+            sample_point: buf.sample_point(),
+            tq: buf.tq(),
+            prop_seg: buf.prop_seg(),
+            phase_seg1: buf.phase_seg1(),
+            phase_seg2: buf.phase_seg2(),
+            sjw: buf.sjw(),
+            brp: buf.brp(),
+        })
+    }
+}
+
+impl Emitable for CanBitTiming {
+    fn buffer_len(&self) -> usize {
+        CAN_BITTIMING_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = CanBitTimingBuffer::new(buffer);
+        buffer.set_bitrate(self.bitrate);
+        buffer.set_sample_point(self.sample_point);
+        buffer.set_tq(self.tq);
+        buffer.set_prop_seg(self.prop_seg);
+        buffer.set_phase_seg1(self.phase_seg1);
+        buffer.set_phase_seg2(self.phase_seg2);
+        buffer.set_sjw(self.sjw);
+        buffer.set_brp(self.brp);
+    }
+}
+
+const CAN_BITTIMING_CONST_NAME_LEN: usize = 16;
+const CAN_BITTIMING_CONST_LEN: usize = 48;
+
+buffer!(CanBitTimingConstBuffer(CAN_BITTIMING_CONST_LEN) {
+    name: (slice, 0..CAN_BITTIMING_CONST_NAME_LEN),
+    tseg1_min: (u32, 16..20),
+    tseg1_max: (u32, 20..24),
+    tseg2_min: (u32, 24..28),
+    tseg2_max: (u32, 28..32),
+    sjw_max: (u32, 32..36),
+    brp_min: (u32, 36..40),
+    brp_max: (u32, 40..44),
+    brp_inc: (u32, 44..48),
+});
+
+/// Hardware dependent CAN bit timing limits(`struct can_bittiming_const`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct CanBitTimingConst {
+    /// Name of CAN controller hardware, at most 15 bytes
+    pub name: String,
+    /// Minimum of time segment 1 = prop_seg + phase_seg1
+    pub tseg1_min: u32,
+    /// Maximum of time segment 1 = prop_seg + phase_seg1
+    pub tseg1_max: u32,
+    /// Minimum of time segment 2 = phase_seg2
+    pub tseg2_min: u32,
+    /// Maximum of time segment 2 = phase_seg2
+    pub tseg2_max: u32,
+    /// Maximum of synchronisation jump width
+    pub sjw_max: u32,
+    /// Minimum of bit rate prescaler
+    pub brp_min: u32,
+    /// Maximum of bit rate prescaler
+    pub brp_max: u32,
+    /// Increment of bit rate prescaler
+    pub brp_inc: u32,
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<CanBitTimingConstBuffer<&'a T>>
+    for CanBitTimingConst
+{
+    fn parse(
+        buf: &CanBitTimingConstBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        let name = buf.name();
+        let name_len = name.iter().position(|c| *c == 0).unwrap_or(name.len());
+        Ok(Self {
+            name: String::from_utf8(name[..name_len].to_vec())
+                .context("invalid name of can_bittiming_const")?,
+            tseg1_min: buf.tseg1_min(),
+            tseg1_max: buf.tseg1_max(),
+            tseg2_min: buf.tseg2_min(),
+            tseg2_max: buf.tseg2_max(),
+            sjw_max: buf.sjw_max(),
+            brp_min: buf.brp_min(),
+            brp_max: buf.brp_max(),
+            brp_inc: buf.brp_inc(),
+        })
+    }
+}
+
+impl Emitable for CanBitTimingConst {
+    fn buffer_len(&self) -> usize {
+        CAN_BITTIMING_CONST_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = CanBitTimingConstBuffer::new(buffer);
+        let name = buffer.name_mut();
+        // Always leave room for the NULL terminator
+        let name_len = self.name.len().min(CAN_BITTIMING_CONST_NAME_LEN - 1);
+        name.fill(0);
+        name[..name_len].copy_from_slice(&self.name.as_bytes()[..name_len]);
+        buffer.set_tseg1_min(self.tseg1_min);
+        buffer.set_tseg1_max(self.tseg1_max);
+        buffer.set_tseg2_min(self.tseg2_min);
+        buffer.set_tseg2_max(self.tseg2_max);
+        buffer.set_sjw_max(self.sjw_max);
+        buffer.set_brp_min(self.brp_min);
+        buffer.set_brp_max(self.brp_max);
+        buffer.set_brp_inc(self.brp_inc);
+    }
+}
+
+const CAN_BERR_COUNTER_LEN: usize = 4;
+
+buffer!(CanBerrCounterBuffer(CAN_BERR_COUNTER_LEN) {
+    txerr: (u16, 0..2),
+    rxerr: (u16, 2..4),
+});
+
+/// CAN bus error counters(`struct can_berr_counter`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct CanBerrCounter {
+    pub txerr: u16,
+    pub rxerr: u16,
+}
+
+impl<T: AsRef<[u8]>> Parseable<CanBerrCounterBuffer<T>> for CanBerrCounter {
+    fn parse(buf: &CanBerrCounterBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            txerr: buf.txerr(),
+            rxerr: buf.rxerr(),
+        })
+    }
+}
+
+impl Emitable for CanBerrCounter {
+    fn buffer_len(&self) -> usize {
+        CAN_BERR_COUNTER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = CanBerrCounterBuffer::new(buffer);
+        buffer.set_txerr(self.txerr);
+        buffer.set_rxerr(self.rxerr);
+    }
+}
@@ -0,0 +1,249 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_ip, parse_u16_be, parse_u32, parse_u32_be, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+const IFLA_GENEVE_ID: u16 = 1;
+const IFLA_GENEVE_REMOTE: u16 = 2;
+const IFLA_GENEVE_TTL: u16 = 3;
+const IFLA_GENEVE_TOS: u16 = 4;
+const IFLA_GENEVE_PORT: u16 = 5;
+const IFLA_GENEVE_COLLECT_METADATA: u16 = 6;
+const IFLA_GENEVE_REMOTE6: u16 = 7;
+const IFLA_GENEVE_UDP_CSUM: u16 = 8;
+const IFLA_GENEVE_UDP_ZERO_CSUM6_TX: u16 = 9;
+const IFLA_GENEVE_UDP_ZERO_CSUM6_RX: u16 = 10;
+const IFLA_GENEVE_LABEL: u16 = 11;
+const IFLA_GENEVE_TTL_INHERIT: u16 = 12;
+const IFLA_GENEVE_DF: u16 = 13;
+const IFLA_GENEVE_INNER_PROTO_INHERIT: u16 = 14;
+const IFLA_GENEVE_PORT_RANGE: u16 = 15;
+
+const GENEVE_DF_UNSET: u8 = 0;
+const GENEVE_DF_SET: u8 = 1;
+const GENEVE_DF_INHERIT: u8 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum InfoGeneve {
+    /// Virtual Network Identifier, only lower 24 bits are used.
+    Id(u32),
+    Remote(Ipv4Addr),
+    Remote6(Ipv6Addr),
+    Ttl(u8),
+    Tos(u8),
+    Port(u16),
+    CollectMetadata,
+    UDPCsum(bool),
+    UDPZeroCsumTX(bool),
+    UDPZeroCsumRX(bool),
+    /// IPv6 flow label, only lower 20 bits are used.
+    Label(u32),
+    TtlInherit(bool),
+    Df(GeneveDf),
+    InnerProtoInherit,
+    /// Source UDP port range in the form of `(low, high)`.
+    PortRange((u16, u16)),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoGeneve {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::CollectMetadata | Self::InnerProtoInherit => 0,
+            Self::Ttl(_)
+            | Self::Tos(_)
+            | Self::UDPCsum(_)
+            | Self::UDPZeroCsumTX(_)
+            | Self::UDPZeroCsumRX(_)
+            | Self::TtlInherit(_)
+            | Self::Df(_) => 1,
+            Self::Port(_) => 2,
+            Self::Id(_)
+            | Self::Remote(_)
+            | Self::Label(_)
+            | Self::PortRange(_) => 4,
+            Self::Remote6(_) => 16,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Id(value) => NativeEndian::write_u32(buffer, *value),
+            Self::Remote(value) => buffer.copy_from_slice(&value.octets()),
+            Self::Remote6(value) => buffer.copy_from_slice(&value.octets()),
+            Self::Ttl(value) | Self::Tos(value) => buffer[0] = *value,
+            Self::Port(value) => BigEndian::write_u16(buffer, *value),
+            Self::CollectMetadata | Self::InnerProtoInherit => (),
+            Self::UDPCsum(value)
+            | Self::UDPZeroCsumTX(value)
+            | Self::UDPZeroCsumRX(value)
+            | Self::TtlInherit(value) => buffer[0] = *value as u8,
+            Self::Label(value) => BigEndian::write_u32(buffer, *value),
+            Self::Df(value) => buffer[0] = (*value).into(),
+            Self::PortRange(range) => {
+                BigEndian::write_u16(buffer, range.0);
+                BigEndian::write_u16(&mut buffer[2..], range.1)
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => IFLA_GENEVE_ID,
+            Self::Remote(_) => IFLA_GENEVE_REMOTE,
+            Self::Remote6(_) => IFLA_GENEVE_REMOTE6,
+            Self::Ttl(_) => IFLA_GENEVE_TTL,
+            Self::Tos(_) => IFLA_GENEVE_TOS,
+            Self::Port(_) => IFLA_GENEVE_PORT,
+            Self::CollectMetadata => IFLA_GENEVE_COLLECT_METADATA,
+            Self::UDPCsum(_) => IFLA_GENEVE_UDP_CSUM,
+            Self::UDPZeroCsumTX(_) => IFLA_GENEVE_UDP_ZERO_CSUM6_TX,
+            Self::UDPZeroCsumRX(_) => IFLA_GENEVE_UDP_ZERO_CSUM6_RX,
+            Self::Label(_) => IFLA_GENEVE_LABEL,
+            Self::TtlInherit(_) => IFLA_GENEVE_TTL_INHERIT,
+            Self::Df(_) => IFLA_GENEVE_DF,
+            Self::InnerProtoInherit => IFLA_GENEVE_INNER_PROTO_INHERIT,
+            Self::PortRange(_) => IFLA_GENEVE_PORT_RANGE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGeneve {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_GENEVE_ID => Self::Id(
+                parse_u32(payload).context("invalid IFLA_GENEVE_ID value")?,
+            ),
+            IFLA_GENEVE_REMOTE => match parse_ip(payload) {
+                Ok(IpAddr::V4(addr)) => Self::Remote(addr),
+                Ok(v) => {
+                    return Err(DecodeError::from(format!(
+                        "Invalid IFLA_GENEVE_REMOTE, \
+                        expecting IPv4 address, but got {v}"
+                    )))
+                }
+                Err(e) => {
+                    return Err(DecodeError::from(format!(
+                        "Invalid IFLA_GENEVE_REMOTE {e}"
+                    )))
+                }
+            },
+            IFLA_GENEVE_REMOTE6 => match parse_ip(payload) {
+                Ok(IpAddr::V6(addr)) => Self::Remote6(addr),
+                Ok(v) => {
+                    return Err(DecodeError::from(format!(
+                        "Invalid IFLA_GENEVE_REMOTE6, \
+                        expecting IPv6 address, but got {v}"
+                    )))
+                }
+                Err(e) => {
+                    return Err(DecodeError::from(format!(
+                        "Invalid IFLA_GENEVE_REMOTE6 {e}"
+                    )))
+                }
+            },
+            IFLA_GENEVE_TTL => Self::Ttl(
+                parse_u8(payload).context("invalid IFLA_GENEVE_TTL value")?,
+            ),
+            IFLA_GENEVE_TOS => Self::Tos(
+                parse_u8(payload).context("invalid IFLA_GENEVE_TOS value")?,
+            ),
+            IFLA_GENEVE_PORT => Self::Port(
+                parse_u16_be(payload)
+                    .context("invalid IFLA_GENEVE_PORT value")?,
+            ),
+            IFLA_GENEVE_COLLECT_METADATA => Self::CollectMetadata,
+            IFLA_GENEVE_UDP_CSUM => Self::UDPCsum(
+                parse_u8(payload)
+                    .context("invalid IFLA_GENEVE_UDP_CSUM value")?
+                    > 0,
+            ),
+            IFLA_GENEVE_UDP_ZERO_CSUM6_TX => Self::UDPZeroCsumTX(
+                parse_u8(payload)
+                    .context("invalid IFLA_GENEVE_UDP_ZERO_CSUM6_TX value")?
+                    > 0,
+            ),
+            IFLA_GENEVE_UDP_ZERO_CSUM6_RX => Self::UDPZeroCsumRX(
+                parse_u8(payload)
+                    .context("invalid IFLA_GENEVE_UDP_ZERO_CSUM6_RX value")?
+                    > 0,
+            ),
+            IFLA_GENEVE_LABEL => Self::Label(
+                parse_u32_be(payload)
+                    .context("invalid IFLA_GENEVE_LABEL value")?,
+            ),
+            IFLA_GENEVE_TTL_INHERIT => Self::TtlInherit(
+                parse_u8(payload)
+                    .context("invalid IFLA_GENEVE_TTL_INHERIT value")?
+                    > 0,
+            ),
+            IFLA_GENEVE_DF => Self::Df(
+                parse_u8(payload)
+                    .context("invalid IFLA_GENEVE_DF value")?
+                    .into(),
+            ),
+            IFLA_GENEVE_INNER_PROTO_INHERIT => Self::InnerProtoInherit,
+            IFLA_GENEVE_PORT_RANGE => {
+                let err = "invalid IFLA_GENEVE_PORT_RANGE value";
+                if payload.len() != 4 {
+                    return Err(err.into());
+                }
+                let low = parse_u16_be(&payload[0..2]).context(err)?;
+                let high = parse_u16_be(&payload[2..]).context(err)?;
+                Self::PortRange((low, high))
+            }
+            unknown_kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!(
+                    "Failed to parse IFLA_INFO_DATA(geneve) NLA type: \
+                    {unknown_kind} as DefaultNla"
+                ))?)
+            }
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum GeneveDf {
+    #[default]
+    Unset,
+    Set,
+    /// Copy the DF bit from the inner IPv4 header.
+    Inherit,
+    Other(u8),
+}
+
+impl From<u8> for GeneveDf {
+    fn from(d: u8) -> Self {
+        match d {
+            GENEVE_DF_UNSET => Self::Unset,
+            GENEVE_DF_SET => Self::Set,
+            GENEVE_DF_INHERIT => Self::Inherit,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<GeneveDf> for u8 {
+    fn from(d: GeneveDf) -> Self {
+        match d {
+            GeneveDf::Unset => GENEVE_DF_UNSET,
+            GeneveDf::Set => GENEVE_DF_SET,
+            GeneveDf::Inherit => GENEVE_DF_INHERIT,
+            GeneveDf::Other(value) => value,
+        }
+    }
+}
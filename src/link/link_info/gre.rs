@@ -1,32 +1,169 @@
 // SPDX-License-Identifier: MIT
 
+use std::net::Ipv4Addr;
+
 use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u16_be, parse_u32, parse_u32_be, parse_u8},
     DecodeError, Parseable,
 };
 
+use super::{
+    tunnel_encap::{IFLA_GRE_ENCAP_DPORT, IFLA_GRE_ENCAP_TYPE},
+    ErspanDirection, TunnelEncap,
+};
+use crate::ip::parse_ipv4_addr;
+
+// The IFLA_GRE_* attributes are shared by all GRE based interfaces, IPv6
+// only ones are used by `gre6.rs`.
+pub(super) const IFLA_GRE_LINK: u16 = 1;
+pub(super) const IFLA_GRE_IFLAGS: u16 = 2;
+pub(super) const IFLA_GRE_OFLAGS: u16 = 3;
+pub(super) const IFLA_GRE_IKEY: u16 = 4;
+pub(super) const IFLA_GRE_OKEY: u16 = 5;
+pub(super) const IFLA_GRE_LOCAL: u16 = 6;
+pub(super) const IFLA_GRE_REMOTE: u16 = 7;
+pub(super) const IFLA_GRE_TTL: u16 = 8;
+const IFLA_GRE_TOS: u16 = 9;
+const IFLA_GRE_PMTUDISC: u16 = 10;
+pub(super) const IFLA_GRE_ENCAP_LIMIT: u16 = 11;
+pub(super) const IFLA_GRE_FLOWINFO: u16 = 12;
+pub(super) const IFLA_GRE_FLAGS: u16 = 13;
+pub(super) const IFLA_GRE_COLLECT_METADATA: u16 = 18;
+const IFLA_GRE_IGNORE_DF: u16 = 19;
+pub(super) const IFLA_GRE_FWMARK: u16 = 20;
+pub(super) const IFLA_GRE_ERSPAN_INDEX: u16 = 21;
+pub(super) const IFLA_GRE_ERSPAN_VER: u16 = 22;
+pub(super) const IFLA_GRE_ERSPAN_DIR: u16 = 23;
+pub(super) const IFLA_GRE_ERSPAN_HWID: u16 = 24;
+
+const GRE_CSUM: u16 = 0x8000;
+const GRE_ROUTING: u16 = 0x4000;
+const GRE_KEY: u16 = 0x2000;
+const GRE_SEQ: u16 = 0x1000;
+const GRE_STRICT: u16 = 0x0800;
+
+/// GRE tunnel over IPv4, the `gre` interface. The `gretap`(
+/// [super::InfoGreTap]) and `erspan`([super::InfoErspan]) interfaces share
+/// the same attributes. The `Index`, `Version`, `Direction` and
+/// `HardwareId` are only used by `erspan`.
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum InfoGreTun {
+    /// Interface index of underlying link
+    Link(u32),
+    /// GRE header flags expected on received packets
+    IFlags(GreFlags),
+    /// GRE header flags of transmitted packets
+    OFlags(GreFlags),
+    /// Key expected on received packets, only checked when `IFlags`
+    /// holds [GreFlags::Key]
+    IKey(u32),
+    /// Key of transmitted packets, only sent when `OFlags` holds
+    /// [GreFlags::Key]
+    OKey(u32),
+    Local(Ipv4Addr),
+    Remote(Ipv4Addr),
+    /// TTL of the delivery header, 0 to copy it from the payload packet
+    Ttl(u8),
+    /// TOS of the delivery header, 1 to copy it from the payload packet
+    Tos(u8),
+    /// Path MTU discovery on the tunnel by setting the DF bit of the
+    /// delivery header, enabled by default
+    PMtuDisc(bool),
+    Encap(TunnelEncap),
+    /// Tunnel parameters are taken from the metadata of each packet
+    /// instead of the interface(`external` mode of iproute2)
+    CollectMetadata,
+    /// Allow fragmenting the GRE packet even when the payload packet has
+    /// the DF bit, conflicts with `PMtuDisc(true)`
+    IgnoreDf(bool),
+    /// Firewall mark used for routing the GRE packets
+    FwMark(u32),
+    /// Session index of ERSPAN type II(version 1) header
+    Index(u32),
+    /// ERSPAN version, 1 for type II and 2 for type III
+    Version(u8),
+    /// Direction of mirrored traffic in ERSPAN type III(version 2) header
+    Direction(ErspanDirection),
+    /// Hardware ID of ERSPAN type III(version 2) header
+    HardwareId(u16),
     Other(DefaultNla),
 }
 
 impl Nla for InfoGreTun {
     fn value_len(&self) -> usize {
         match self {
+            Self::Encap(v) => v.value_len(),
+            Self::CollectMetadata => 0,
+            Self::Ttl(_)
+            | Self::Tos(_)
+            | Self::PMtuDisc(_)
+            | Self::IgnoreDf(_)
+            | Self::Version(_)
+            | Self::Direction(_) => 1,
+            Self::IFlags(_) | Self::OFlags(_) | Self::HardwareId(_) => 2,
+            Self::Link(_)
+            | Self::IKey(_)
+            | Self::OKey(_)
+            | Self::Local(_)
+            | Self::Remote(_)
+            | Self::FwMark(_)
+            | Self::Index(_) => 4,
             Self::Other(nla) => nla.value_len(),
         }
     }
 
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
+            Self::Link(value) | Self::FwMark(value) | Self::Index(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::IFlags(value) | Self::OFlags(value) => {
+                BigEndian::write_u16(buffer, value.bits())
+            }
+            Self::IKey(value) | Self::OKey(value) => {
+                BigEndian::write_u32(buffer, *value)
+            }
+            Self::Local(value) | Self::Remote(value) => {
+                buffer.copy_from_slice(&value.octets())
+            }
+            Self::Ttl(value) | Self::Tos(value) | Self::Version(value) => {
+                buffer[0] = *value
+            }
+            Self::PMtuDisc(value) | Self::IgnoreDf(value) => {
+                buffer[0] = *value as u8
+            }
+            Self::Encap(v) => v.emit_value(buffer),
+            Self::CollectMetadata => (),
+            Self::Direction(value) => buffer[0] = (*value).into(),
+            Self::HardwareId(value) => NativeEndian::write_u16(buffer, *value),
             Self::Other(nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
         match self {
+            Self::Link(_) => IFLA_GRE_LINK,
+            Self::IFlags(_) => IFLA_GRE_IFLAGS,
+            Self::OFlags(_) => IFLA_GRE_OFLAGS,
+            Self::IKey(_) => IFLA_GRE_IKEY,
+            Self::OKey(_) => IFLA_GRE_OKEY,
+            Self::Local(_) => IFLA_GRE_LOCAL,
+            Self::Remote(_) => IFLA_GRE_REMOTE,
+            Self::Ttl(_) => IFLA_GRE_TTL,
+            Self::Tos(_) => IFLA_GRE_TOS,
+            Self::PMtuDisc(_) => IFLA_GRE_PMTUDISC,
+            Self::Encap(v) => v.kind(IFLA_GRE_ENCAP_TYPE),
+            Self::CollectMetadata => IFLA_GRE_COLLECT_METADATA,
+            Self::IgnoreDf(_) => IFLA_GRE_IGNORE_DF,
+            Self::FwMark(_) => IFLA_GRE_FWMARK,
+            Self::Index(_) => IFLA_GRE_ERSPAN_INDEX,
+            Self::Version(_) => IFLA_GRE_ERSPAN_VER,
+            Self::Direction(_) => IFLA_GRE_ERSPAN_DIR,
+            Self::HardwareId(_) => IFLA_GRE_ERSPAN_HWID,
             Self::Other(nla) => nla.kind(),
         }
     }
@@ -34,8 +171,72 @@ impl Nla for InfoGreTun {
 
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGreTun {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        #[allow(clippy::match_single_binding)]
+        let payload = buf.value();
         Ok(match buf.kind() {
+            IFLA_GRE_LINK => Self::Link(
+                parse_u32(payload).context("invalid IFLA_GRE_LINK value")?,
+            ),
+            IFLA_GRE_IFLAGS => Self::IFlags(GreFlags::from_bits_retain(
+                parse_u16_be(payload)
+                    .context("invalid IFLA_GRE_IFLAGS value")?,
+            )),
+            IFLA_GRE_OFLAGS => Self::OFlags(GreFlags::from_bits_retain(
+                parse_u16_be(payload)
+                    .context("invalid IFLA_GRE_OFLAGS value")?,
+            )),
+            IFLA_GRE_IKEY => Self::IKey(
+                parse_u32_be(payload).context("invalid IFLA_GRE_IKEY value")?,
+            ),
+            IFLA_GRE_OKEY => Self::OKey(
+                parse_u32_be(payload).context("invalid IFLA_GRE_OKEY value")?,
+            ),
+            IFLA_GRE_LOCAL => Self::Local(
+                parse_ipv4_addr(payload)
+                    .context("invalid IFLA_GRE_LOCAL value")?,
+            ),
+            IFLA_GRE_REMOTE => Self::Remote(
+                parse_ipv4_addr(payload)
+                    .context("invalid IFLA_GRE_REMOTE value")?,
+            ),
+            IFLA_GRE_TTL => Self::Ttl(
+                parse_u8(payload).context("invalid IFLA_GRE_TTL value")?,
+            ),
+            IFLA_GRE_TOS => Self::Tos(
+                parse_u8(payload).context("invalid IFLA_GRE_TOS value")?,
+            ),
+            IFLA_GRE_PMTUDISC => Self::PMtuDisc(
+                parse_u8(payload).context("invalid IFLA_GRE_PMTUDISC value")?
+                    > 0,
+            ),
+            kind @ IFLA_GRE_ENCAP_TYPE..=IFLA_GRE_ENCAP_DPORT => Self::Encap(
+                TunnelEncap::parse(kind - IFLA_GRE_ENCAP_TYPE, payload)?,
+            ),
+            IFLA_GRE_COLLECT_METADATA => Self::CollectMetadata,
+            IFLA_GRE_IGNORE_DF => Self::IgnoreDf(
+                parse_u8(payload)
+                    .context("invalid IFLA_GRE_IGNORE_DF value")?
+                    > 0,
+            ),
+            IFLA_GRE_FWMARK => Self::FwMark(
+                parse_u32(payload).context("invalid IFLA_GRE_FWMARK value")?,
+            ),
+            IFLA_GRE_ERSPAN_INDEX => Self::Index(
+                parse_u32(payload)
+                    .context("invalid IFLA_GRE_ERSPAN_INDEX value")?,
+            ),
+            IFLA_GRE_ERSPAN_VER => Self::Version(
+                parse_u8(payload)
+                    .context("invalid IFLA_GRE_ERSPAN_VER value")?,
+            ),
+            IFLA_GRE_ERSPAN_DIR => Self::Direction(
+                parse_u8(payload)
+                    .context("invalid IFLA_GRE_ERSPAN_DIR value")?
+                    .into(),
+            ),
+            IFLA_GRE_ERSPAN_HWID => Self::HardwareId(
+                parse_u16(payload)
+                    .context("invalid IFLA_GRE_ERSPAN_HWID value")?,
+            ),
             kind => Self::Other(
                 DefaultNla::parse(buf)
                     .context(format!("unknown NLA type {kind} for gre"))?,
@@ -43,3 +244,25 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGreTun {
         })
     }
 }
+
+/// The `gretap` interface, Ethernet over GRE over IPv4.
+pub type InfoGreTap = InfoGreTun;
+
+bitflags! {
+    /// Flags of GRE header
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[non_exhaustive]
+    pub struct GreFlags: u16 {
+        /// Checksum present
+        const Csum = GRE_CSUM;
+        /// Routing present
+        const Routing = GRE_ROUTING;
+        /// Key present
+        const Key = GRE_KEY;
+        /// Sequence number present
+        const Seq = GRE_SEQ;
+        /// Strict source route
+        const Strict = GRE_STRICT;
+        const _ = !0;
+    }
+}
@@ -1,32 +1,145 @@
 // SPDX-License-Identifier: MIT
 
+use std::net::Ipv6Addr;
+
 use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u16_be, parse_u32, parse_u32_be, parse_u8},
     DecodeError, Parseable,
 };
 
+use super::{
+    gre::{
+        IFLA_GRE_COLLECT_METADATA, IFLA_GRE_ENCAP_LIMIT, IFLA_GRE_ERSPAN_DIR,
+        IFLA_GRE_ERSPAN_HWID, IFLA_GRE_ERSPAN_INDEX, IFLA_GRE_ERSPAN_VER,
+        IFLA_GRE_FLAGS, IFLA_GRE_FLOWINFO, IFLA_GRE_FWMARK, IFLA_GRE_IFLAGS,
+        IFLA_GRE_IKEY, IFLA_GRE_LINK, IFLA_GRE_LOCAL, IFLA_GRE_OFLAGS,
+        IFLA_GRE_OKEY, IFLA_GRE_REMOTE, IFLA_GRE_TTL,
+    },
+    tunnel_encap::{IFLA_GRE_ENCAP_DPORT, IFLA_GRE_ENCAP_TYPE},
+    ErspanDirection, GreFlags, Ip6TunnelFlags, TunnelEncap,
+};
+use crate::ip::parse_ipv6_addr;
+
+/// GRE tunnel over IPv6, the `ip6gre` interface. The `ip6gretap`(
+/// [super::InfoGreTap6]) and `ip6erspan`([super::InfoIp6Erspan])
+/// interfaces share the same attributes. The `Index`, `Version`,
+/// `Direction` and `HardwareId` are only used by `ip6erspan`.
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum InfoGreTun6 {
+    /// Interface index of underlying link
+    Link(u32),
+    /// GRE header flags expected on received packets
+    IFlags(GreFlags),
+    /// GRE header flags of transmitted packets
+    OFlags(GreFlags),
+    /// Key expected on received packets, only checked when `IFlags`
+    /// holds [GreFlags::Key]
+    IKey(u32),
+    /// Key of transmitted packets, only sent when `OFlags` holds
+    /// [GreFlags::Key]
+    OKey(u32),
+    Local(Ipv6Addr),
+    Remote(Ipv6Addr),
+    /// Hop limit of the delivery header, 0 to copy it from the payload
+    /// packet
+    Ttl(u8),
+    /// Value of the tunnel encapsulation limit option added to the
+    /// delivery header unless `Flags` holds
+    /// [Ip6TunnelFlags::IgnEncapLimit]
+    EncapLimit(u8),
+    /// Traffic class(bits 20-27) and flow label(bits 0-19) of the
+    /// delivery header
+    FlowInfo(u32),
+    Flags(Ip6TunnelFlags),
+    Encap(TunnelEncap),
+    /// Tunnel parameters are taken from the metadata of each packet
+    /// instead of the interface(`external` mode of iproute2)
+    CollectMetadata,
+    /// Firewall mark used for routing the GRE packets
+    FwMark(u32),
+    /// Session index of ERSPAN type II(version 1) header
+    Index(u32),
+    /// ERSPAN version, 1 for type II and 2 for type III
+    Version(u8),
+    /// Direction of mirrored traffic in ERSPAN type III(version 2) header
+    Direction(ErspanDirection),
+    /// Hardware ID of ERSPAN type III(version 2) header
+    HardwareId(u16),
     Other(DefaultNla),
 }
 
 impl Nla for InfoGreTun6 {
     fn value_len(&self) -> usize {
         match self {
+            Self::Encap(v) => v.value_len(),
+            Self::CollectMetadata => 0,
+            Self::Ttl(_)
+            | Self::EncapLimit(_)
+            | Self::Version(_)
+            | Self::Direction(_) => 1,
+            Self::IFlags(_) | Self::OFlags(_) | Self::HardwareId(_) => 2,
+            Self::Link(_)
+            | Self::IKey(_)
+            | Self::OKey(_)
+            | Self::FlowInfo(_)
+            | Self::Flags(_)
+            | Self::FwMark(_)
+            | Self::Index(_) => 4,
+            Self::Local(_) | Self::Remote(_) => 16,
             Self::Other(nla) => nla.value_len(),
         }
     }
 
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
+            Self::Link(value) | Self::FwMark(value) | Self::Index(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::IFlags(value) | Self::OFlags(value) => {
+                BigEndian::write_u16(buffer, value.bits())
+            }
+            Self::IKey(value) | Self::OKey(value) | Self::FlowInfo(value) => {
+                BigEndian::write_u32(buffer, *value)
+            }
+            Self::Local(value) | Self::Remote(value) => {
+                buffer.copy_from_slice(&value.octets())
+            }
+            Self::Ttl(value)
+            | Self::EncapLimit(value)
+            | Self::Version(value) => buffer[0] = *value,
+            Self::Flags(value) => NativeEndian::write_u32(buffer, value.bits()),
+            Self::Encap(v) => v.emit_value(buffer),
+            Self::CollectMetadata => (),
+            Self::Direction(value) => buffer[0] = (*value).into(),
+            Self::HardwareId(value) => NativeEndian::write_u16(buffer, *value),
             Self::Other(nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
         match self {
+            Self::Link(_) => IFLA_GRE_LINK,
+            Self::IFlags(_) => IFLA_GRE_IFLAGS,
+            Self::OFlags(_) => IFLA_GRE_OFLAGS,
+            Self::IKey(_) => IFLA_GRE_IKEY,
+            Self::OKey(_) => IFLA_GRE_OKEY,
+            Self::Local(_) => IFLA_GRE_LOCAL,
+            Self::Remote(_) => IFLA_GRE_REMOTE,
+            Self::Ttl(_) => IFLA_GRE_TTL,
+            Self::EncapLimit(_) => IFLA_GRE_ENCAP_LIMIT,
+            Self::FlowInfo(_) => IFLA_GRE_FLOWINFO,
+            Self::Flags(_) => IFLA_GRE_FLAGS,
+            Self::Encap(v) => v.kind(IFLA_GRE_ENCAP_TYPE),
+            Self::CollectMetadata => IFLA_GRE_COLLECT_METADATA,
+            Self::FwMark(_) => IFLA_GRE_FWMARK,
+            Self::Index(_) => IFLA_GRE_ERSPAN_INDEX,
+            Self::Version(_) => IFLA_GRE_ERSPAN_VER,
+            Self::Direction(_) => IFLA_GRE_ERSPAN_DIR,
+            Self::HardwareId(_) => IFLA_GRE_ERSPAN_HWID,
             Self::Other(nla) => nla.kind(),
         }
     }
@@ -34,8 +147,71 @@ impl Nla for InfoGreTun6 {
 
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGreTun6 {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        #[allow(clippy::match_single_binding)]
+        let payload = buf.value();
         Ok(match buf.kind() {
+            IFLA_GRE_LINK => Self::Link(
+                parse_u32(payload).context("invalid IFLA_GRE_LINK value")?,
+            ),
+            IFLA_GRE_IFLAGS => Self::IFlags(GreFlags::from_bits_retain(
+                parse_u16_be(payload)
+                    .context("invalid IFLA_GRE_IFLAGS value")?,
+            )),
+            IFLA_GRE_OFLAGS => Self::OFlags(GreFlags::from_bits_retain(
+                parse_u16_be(payload)
+                    .context("invalid IFLA_GRE_OFLAGS value")?,
+            )),
+            IFLA_GRE_IKEY => Self::IKey(
+                parse_u32_be(payload).context("invalid IFLA_GRE_IKEY value")?,
+            ),
+            IFLA_GRE_OKEY => Self::OKey(
+                parse_u32_be(payload).context("invalid IFLA_GRE_OKEY value")?,
+            ),
+            IFLA_GRE_LOCAL => Self::Local(
+                parse_ipv6_addr(payload)
+                    .context("invalid IFLA_GRE_LOCAL value")?,
+            ),
+            IFLA_GRE_REMOTE => Self::Remote(
+                parse_ipv6_addr(payload)
+                    .context("invalid IFLA_GRE_REMOTE value")?,
+            ),
+            IFLA_GRE_TTL => Self::Ttl(
+                parse_u8(payload).context("invalid IFLA_GRE_TTL value")?,
+            ),
+            IFLA_GRE_ENCAP_LIMIT => Self::EncapLimit(
+                parse_u8(payload)
+                    .context("invalid IFLA_GRE_ENCAP_LIMIT value")?,
+            ),
+            IFLA_GRE_FLOWINFO => Self::FlowInfo(
+                parse_u32_be(payload)
+                    .context("invalid IFLA_GRE_FLOWINFO value")?,
+            ),
+            IFLA_GRE_FLAGS => Self::Flags(Ip6TunnelFlags::from_bits_retain(
+                parse_u32(payload).context("invalid IFLA_GRE_FLAGS value")?,
+            )),
+            kind @ IFLA_GRE_ENCAP_TYPE..=IFLA_GRE_ENCAP_DPORT => Self::Encap(
+                TunnelEncap::parse(kind - IFLA_GRE_ENCAP_TYPE, payload)?,
+            ),
+            IFLA_GRE_COLLECT_METADATA => Self::CollectMetadata,
+            IFLA_GRE_FWMARK => Self::FwMark(
+                parse_u32(payload).context("invalid IFLA_GRE_FWMARK value")?,
+            ),
+            IFLA_GRE_ERSPAN_INDEX => Self::Index(
+                parse_u32(payload)
+                    .context("invalid IFLA_GRE_ERSPAN_INDEX value")?,
+            ),
+            IFLA_GRE_ERSPAN_VER => Self::Version(
+                parse_u8(payload)
+                    .context("invalid IFLA_GRE_ERSPAN_VER value")?,
+            ),
+            IFLA_GRE_ERSPAN_DIR => Self::Direction(
+                parse_u8(payload)
+                    .context("invalid IFLA_GRE_ERSPAN_DIR value")?
+                    .into(),
+            ),
+            IFLA_GRE_ERSPAN_HWID => Self::HardwareId(
+                parse_u16(payload)
+                    .context("invalid IFLA_GRE_ERSPAN_HWID value")?,
+            ),
             kind => Self::Other(
                 DefaultNla::parse(buf)
                     .context(format!("unknown NLA type {kind} for ip6gre"))?,
@@ -43,3 +219,6 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGreTun6 {
         })
     }
 }
+
+/// The `ip6gretap` interface, Ethernet over GRE over IPv6.
+pub type InfoGreTap6 = InfoGreTun6;
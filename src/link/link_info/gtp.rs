@@ -1,32 +1,86 @@
 // SPDX-License-Identifier: MIT
 
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
 use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_ip, parse_u32, parse_u8},
     DecodeError, Parseable,
 };
 
+const IFLA_GTP_FD0: u16 = 1;
+const IFLA_GTP_FD1: u16 = 2;
+const IFLA_GTP_PDP_HASHSIZE: u16 = 3;
+const IFLA_GTP_ROLE: u16 = 4;
+const IFLA_GTP_CREATE_SOCKETS: u16 = 5;
+const IFLA_GTP_RESTART_COUNT: u16 = 6;
+const IFLA_GTP_LOCAL: u16 = 7;
+const IFLA_GTP_LOCAL6: u16 = 8;
+
+const GTP_ROLE_GGSN: u32 = 0;
+const GTP_ROLE_SGSN: u32 = 1;
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum InfoGtp {
+    /// File descriptor of GTPv0 UDP socket
+    Fd0(u32),
+    /// File descriptor of GTPv1-U UDP socket
+    Fd1(u32),
+    PdpHashsize(u32),
+    Role(GtpRole),
+    /// Let kernel create the UDP sockets instead of using `Fd0` and `Fd1`
+    CreateSockets(bool),
+    RestartCount(u8),
+    /// Local address of kernel created sockets
+    Local(Ipv4Addr),
+    /// Local IPv6 address of kernel created sockets
+    Local6(Ipv6Addr),
     Other(DefaultNla),
 }
 
 impl Nla for InfoGtp {
     fn value_len(&self) -> usize {
         match self {
+            Self::CreateSockets(_) | Self::RestartCount(_) => 1,
+            Self::Fd0(_)
+            | Self::Fd1(_)
+            | Self::PdpHashsize(_)
+            | Self::Role(_)
+            | Self::Local(_) => 4,
+            Self::Local6(_) => 16,
             Self::Other(nla) => nla.value_len(),
         }
     }
 
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
+            Self::Fd0(value) | Self::Fd1(value) | Self::PdpHashsize(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Role(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::CreateSockets(value) => buffer[0] = *value as u8,
+            Self::RestartCount(value) => buffer[0] = *value,
+            Self::Local(value) => buffer.copy_from_slice(&value.octets()),
+            Self::Local6(value) => buffer.copy_from_slice(&value.octets()),
             Self::Other(nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
         match self {
+            Self::Fd0(_) => IFLA_GTP_FD0,
+            Self::Fd1(_) => IFLA_GTP_FD1,
+            Self::PdpHashsize(_) => IFLA_GTP_PDP_HASHSIZE,
+            Self::Role(_) => IFLA_GTP_ROLE,
+            Self::CreateSockets(_) => IFLA_GTP_CREATE_SOCKETS,
+            Self::RestartCount(_) => IFLA_GTP_RESTART_COUNT,
+            Self::Local(_) => IFLA_GTP_LOCAL,
+            Self::Local6(_) => IFLA_GTP_LOCAL6,
             Self::Other(nla) => nla.kind(),
         }
     }
@@ -34,8 +88,60 @@ impl Nla for InfoGtp {
 
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGtp {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        #[allow(clippy::match_single_binding)]
+        let payload = buf.value();
         Ok(match buf.kind() {
+            IFLA_GTP_FD0 => Self::Fd0(
+                parse_u32(payload).context("invalid IFLA_GTP_FD0 value")?,
+            ),
+            IFLA_GTP_FD1 => Self::Fd1(
+                parse_u32(payload).context("invalid IFLA_GTP_FD1 value")?,
+            ),
+            IFLA_GTP_PDP_HASHSIZE => Self::PdpHashsize(
+                parse_u32(payload)
+                    .context("invalid IFLA_GTP_PDP_HASHSIZE value")?,
+            ),
+            IFLA_GTP_ROLE => Self::Role(
+                parse_u32(payload)
+                    .context("invalid IFLA_GTP_ROLE value")?
+                    .into(),
+            ),
+            IFLA_GTP_CREATE_SOCKETS => Self::CreateSockets(
+                parse_u8(payload)
+                    .context("invalid IFLA_GTP_CREATE_SOCKETS value")?
+                    > 0,
+            ),
+            IFLA_GTP_RESTART_COUNT => Self::RestartCount(
+                parse_u8(payload)
+                    .context("invalid IFLA_GTP_RESTART_COUNT value")?,
+            ),
+            IFLA_GTP_LOCAL => match parse_ip(payload) {
+                Ok(IpAddr::V4(addr)) => Self::Local(addr),
+                Ok(v) => {
+                    return Err(DecodeError::from(format!(
+                        "Invalid IFLA_GTP_LOCAL, \
+                        expecting IPv4 address, but got {v}"
+                    )))
+                }
+                Err(e) => {
+                    return Err(DecodeError::from(format!(
+                        "Invalid IFLA_GTP_LOCAL {e}"
+                    )))
+                }
+            },
+            IFLA_GTP_LOCAL6 => match parse_ip(payload) {
+                Ok(IpAddr::V6(addr)) => Self::Local6(addr),
+                Ok(v) => {
+                    return Err(DecodeError::from(format!(
+                        "Invalid IFLA_GTP_LOCAL6, \
+                        expecting IPv6 address, but got {v}"
+                    )))
+                }
+                Err(e) => {
+                    return Err(DecodeError::from(format!(
+                        "Invalid IFLA_GTP_LOCAL6 {e}"
+                    )))
+                }
+            },
             kind => Self::Other(
                 DefaultNla::parse(buf)
                     .context(format!("unknown NLA type {kind} for gtp"))?,
@@ -43,3 +149,34 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGtp {
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum GtpRole {
+    /// Gateway GPRS Support Node, network side
+    #[default]
+    Ggsn,
+    /// Serving GPRS Support Node, user equipment side
+    Sgsn,
+    Other(u32),
+}
+
+impl From<u32> for GtpRole {
+    fn from(d: u32) -> Self {
+        match d {
+            GTP_ROLE_GGSN => Self::Ggsn,
+            GTP_ROLE_SGSN => Self::Sgsn,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<GtpRole> for u32 {
+    fn from(d: GtpRole) -> Self {
+        match d {
+            GtpRole::Ggsn => GTP_ROLE_GGSN,
+            GtpRole::Sgsn => GTP_ROLE_SGSN,
+            GtpRole::Other(value) => value,
+        }
+    }
+}
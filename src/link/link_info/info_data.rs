@@ -8,10 +8,12 @@ use netlink_packet_utils::{
 };
 
 use super::super::{
-    InfoBond, InfoBridge, InfoGreTap, InfoGreTap6, InfoGreTun, InfoGreTun6,
-    InfoGtp, InfoHsr, InfoIpVlan, InfoIpVtap, InfoIpoib, InfoKind, InfoMacSec,
-    InfoMacVlan, InfoMacVtap, InfoSitTun, InfoTun, InfoVeth, InfoVlan, InfoVrf,
-    InfoVti, InfoVxlan, InfoXfrm,
+    InfoAmt, InfoBareUdp, InfoBond, InfoBridge, InfoCan, InfoErspan,
+    InfoGeneve, InfoGreTap, InfoGreTap6, InfoGreTun, InfoGreTun6, InfoGtp,
+    InfoHsr, InfoIp6Erspan, InfoIp6Tunnel, InfoIpTunnel, InfoIpVlan,
+    InfoIpVtap, InfoIpoib, InfoKind, InfoMacSec, InfoMacVlan, InfoMacVtap,
+    InfoNetkit, InfoRmnet, InfoSitTun, InfoTun, InfoVeth, InfoVlan, InfoVrf,
+    InfoVti, InfoVxcan, InfoVxlan, InfoWwan, InfoXfrm,
 };
 
 const IFLA_INFO_DATA: u16 = 2;
@@ -31,6 +33,8 @@ pub enum InfoData {
     MacVtap(Vec<InfoMacVtap>),
     GreTap(Vec<InfoGreTap>),
     GreTap6(Vec<InfoGreTap6>),
+    IpTun(Vec<InfoIpTunnel>),
+    Ip6Tun(Vec<InfoIp6Tunnel>),
     SitTun(Vec<InfoSitTun>),
     GreTun(Vec<InfoGreTun>),
     GreTun6(Vec<InfoGreTun6>),
@@ -41,6 +45,16 @@ pub enum InfoData {
     Xfrm(Vec<InfoXfrm>),
     MacSec(Vec<InfoMacSec>),
     Hsr(Vec<InfoHsr>),
+    Geneve(Vec<InfoGeneve>),
+    Erspan(Vec<InfoErspan>),
+    Ip6Erspan(Vec<InfoIp6Erspan>),
+    Can(Vec<InfoCan>),
+    Vxcan(InfoVxcan),
+    Netkit(Vec<InfoNetkit>),
+    BareUdp(Vec<InfoBareUdp>),
+    Amt(Vec<InfoAmt>),
+    Rmnet(Vec<InfoRmnet>),
+    Wwan(Vec<InfoWwan>),
     Other(Vec<u8>),
 }
 
@@ -64,11 +78,23 @@ impl Nla for InfoData {
             Self::Tun(nlas) => nlas.as_slice().buffer_len(),
             Self::GreTap(nlas) => nlas.as_slice().buffer_len(),
             Self::GreTap6(nlas) => nlas.as_slice().buffer_len(),
+            Self::IpTun(nlas) => nlas.as_slice().buffer_len(),
+            Self::Ip6Tun(nlas) => nlas.as_slice().buffer_len(),
             Self::SitTun(nlas) => nlas.as_slice().buffer_len(),
             Self::GreTun(nlas) => nlas.as_slice().buffer_len(),
             Self::GreTun6(nlas) => nlas.as_slice().buffer_len(),
             Self::Vti(nlas) => nlas.as_slice().buffer_len(),
             Self::Gtp(nlas) => nlas.as_slice().buffer_len(),
+            Self::Geneve(nlas) => nlas.as_slice().buffer_len(),
+            Self::Erspan(nlas) => nlas.as_slice().buffer_len(),
+            Self::Ip6Erspan(nlas) => nlas.as_slice().buffer_len(),
+            Self::Can(nlas) => nlas.as_slice().buffer_len(),
+            Self::Vxcan(msg) => msg.buffer_len(),
+            Self::Netkit(nlas) => nlas.as_slice().buffer_len(),
+            Self::BareUdp(nlas) => nlas.as_slice().buffer_len(),
+            Self::Amt(nlas) => nlas.as_slice().buffer_len(),
+            Self::Rmnet(nlas) => nlas.as_slice().buffer_len(),
+            Self::Wwan(nlas) => nlas.as_slice().buffer_len(),
             Self::Other(v) => v.len(),
         }
     }
@@ -92,11 +118,23 @@ impl Nla for InfoData {
             Self::Tun(nlas) => nlas.as_slice().emit(buffer),
             Self::GreTap(nlas) => nlas.as_slice().emit(buffer),
             Self::GreTap6(nlas) => nlas.as_slice().emit(buffer),
+            Self::IpTun(nlas) => nlas.as_slice().emit(buffer),
+            Self::Ip6Tun(nlas) => nlas.as_slice().emit(buffer),
             Self::SitTun(nlas) => nlas.as_slice().emit(buffer),
             Self::GreTun(nlas) => nlas.as_slice().emit(buffer),
             Self::GreTun6(nlas) => nlas.as_slice().emit(buffer),
             Self::Vti(nlas) => nlas.as_slice().emit(buffer),
             Self::Gtp(nlas) => nlas.as_slice().emit(buffer),
+            Self::Geneve(nlas) => nlas.as_slice().emit(buffer),
+            Self::Erspan(nlas) => nlas.as_slice().emit(buffer),
+            Self::Ip6Erspan(nlas) => nlas.as_slice().emit(buffer),
+            Self::Can(nlas) => nlas.as_slice().emit(buffer),
+            Self::Vxcan(msg) => msg.emit(buffer),
+            Self::Netkit(nlas) => nlas.as_slice().emit(buffer),
+            Self::BareUdp(nlas) => nlas.as_slice().emit(buffer),
+            Self::Amt(nlas) => nlas.as_slice().emit(buffer),
+            Self::Rmnet(nlas) => nlas.as_slice().emit(buffer),
+            Self::Wwan(nlas) => nlas.as_slice().emit(buffer),
             Self::Other(v) => buffer.copy_from_slice(v.as_slice()),
         }
     }
@@ -240,6 +278,28 @@ impl InfoData {
                 }
                 InfoData::GreTap6(v)
             }
+            InfoKind::IpTun => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(format!(
+                        "invalid IFLA_INFO_DATA for {kind} {payload:?}"
+                    ))?;
+                    let parsed = InfoIpTunnel::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::IpTun(v)
+            }
+            InfoKind::Ip6Tun => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(format!(
+                        "invalid IFLA_INFO_DATA for {kind} {payload:?}"
+                    ))?;
+                    let parsed = InfoIp6Tunnel::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Ip6Tun(v)
+            }
             InfoKind::SitTun => {
                 let mut v = Vec::new();
                 for nla in NlasIterator::new(payload) {
@@ -350,6 +410,112 @@ impl InfoData {
                 }
                 InfoData::Hsr(v)
             }
+            InfoKind::Geneve => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(format!(
+                        "invalid IFLA_INFO_DATA for {kind} {payload:?}"
+                    ))?;
+                    let parsed = InfoGeneve::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Geneve(v)
+            }
+            InfoKind::Erspan => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(format!(
+                        "invalid IFLA_INFO_DATA for {kind} {payload:?}"
+                    ))?;
+                    let parsed = InfoErspan::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Erspan(v)
+            }
+            InfoKind::Ip6Erspan => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(format!(
+                        "invalid IFLA_INFO_DATA for {kind} {payload:?}"
+                    ))?;
+                    let parsed = InfoIp6Erspan::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Ip6Erspan(v)
+            }
+            InfoKind::Can => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(format!(
+                        "invalid IFLA_INFO_DATA for {kind} {payload:?}"
+                    ))?;
+                    let parsed = InfoCan::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Can(v)
+            }
+            InfoKind::Vxcan => {
+                let nla_buf = NlaBuffer::new_checked(&payload).context(
+                    format!("invalid IFLA_INFO_DATA for {kind} {payload:?}"),
+                )?;
+                let parsed = InfoVxcan::parse(&nla_buf)?;
+                InfoData::Vxcan(parsed)
+            }
+            InfoKind::Netkit => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(format!(
+                        "invalid IFLA_INFO_DATA for {kind} {payload:?}"
+                    ))?;
+                    let parsed = InfoNetkit::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Netkit(v)
+            }
+            InfoKind::BareUdp => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(format!(
+                        "invalid IFLA_INFO_DATA for {kind} {payload:?}"
+                    ))?;
+                    let parsed = InfoBareUdp::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::BareUdp(v)
+            }
+            InfoKind::Amt => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(format!(
+                        "invalid IFLA_INFO_DATA for {kind} {payload:?}"
+                    ))?;
+                    let parsed = InfoAmt::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Amt(v)
+            }
+            InfoKind::Rmnet => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(format!(
+                        "invalid IFLA_INFO_DATA for {kind} {payload:?}"
+                    ))?;
+                    let parsed = InfoRmnet::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Rmnet(v)
+            }
+            InfoKind::Wwan => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(format!(
+                        "invalid IFLA_INFO_DATA for {kind} {payload:?}"
+                    ))?;
+                    let parsed = InfoWwan::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Wwan(v)
+            }
             _ => InfoData::Other(payload.to_vec()),
         })
     }
@@ -31,6 +31,7 @@ const MACVTAP: &str = "macvtap";
 const GRETAP: &str = "gretap";
 const IP6GRETAP: &str = "ip6gretap";
 const IPIP: &str = "ipip";
+const IP6TNL: &str = "ip6tnl";
 const SIT: &str = "sit";
 const GRE: &str = "gre";
 const IP6GRE: &str = "ip6gre";
@@ -42,6 +43,17 @@ const WIREGUARD: &str = "wireguard";
 const XFRM: &str = "xfrm";
 const MACSEC: &str = "macsec";
 const HSR: &str = "hsr";
+const GENEVE: &str = "geneve";
+const ERSPAN: &str = "erspan";
+const IP6ERSPAN: &str = "ip6erspan";
+const CAN: &str = "can";
+const VCAN: &str = "vcan";
+const VXCAN: &str = "vxcan";
+const NETKIT: &str = "netkit";
+const BAREUDP: &str = "bareudp";
+const AMT: &str = "amt";
+const RMNET: &str = "rmnet";
+const WWAN: &str = "wwan";
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
@@ -189,6 +201,7 @@ pub enum InfoKind {
     GreTap,
     GreTap6,
     IpTun,
+    Ip6Tun,
     SitTun,
     GreTun,
     GreTun6,
@@ -200,6 +213,17 @@ pub enum InfoKind {
     Xfrm,
     MacSec,
     Hsr,
+    Geneve,
+    Erspan,
+    Ip6Erspan,
+    Can,
+    Vcan,
+    Vxcan,
+    Netkit,
+    BareUdp,
+    Amt,
+    Rmnet,
+    Wwan,
     Other(String),
 }
 
@@ -225,6 +249,7 @@ impl std::fmt::Display for InfoKind {
                 Self::GreTap => GRETAP,
                 Self::GreTap6 => IP6GRETAP,
                 Self::IpTun => IPIP,
+                Self::Ip6Tun => IP6TNL,
                 Self::SitTun => SIT,
                 Self::GreTun => GRE,
                 Self::GreTun6 => IP6GRE,
@@ -236,6 +261,17 @@ impl std::fmt::Display for InfoKind {
                 Self::Xfrm => XFRM,
                 Self::MacSec => MACSEC,
                 Self::Hsr => HSR,
+                Self::Geneve => GENEVE,
+                Self::Erspan => ERSPAN,
+                Self::Ip6Erspan => IP6ERSPAN,
+                Self::Can => CAN,
+                Self::Vcan => VCAN,
+                Self::Vxcan => VXCAN,
+                Self::Netkit => NETKIT,
+                Self::BareUdp => BAREUDP,
+                Self::Amt => AMT,
+                Self::Rmnet => RMNET,
+                Self::Wwan => WWAN,
                 Self::Other(s) => s.as_str(),
             }
         )
@@ -261,6 +297,7 @@ impl Nla for InfoKind {
             Self::GreTap => GRETAP.len(),
             Self::GreTap6 => IP6GRETAP.len(),
             Self::IpTun => IPIP.len(),
+            Self::Ip6Tun => IP6TNL.len(),
             Self::SitTun => SIT.len(),
             Self::GreTun => GRE.len(),
             Self::GreTun6 => IP6GRE.len(),
@@ -272,6 +309,17 @@ impl Nla for InfoKind {
             Self::Xfrm => XFRM.len(),
             Self::MacSec => MACSEC.len(),
             Self::Hsr => HSR.len(),
+            Self::Geneve => GENEVE.len(),
+            Self::Erspan => ERSPAN.len(),
+            Self::Ip6Erspan => IP6ERSPAN.len(),
+            Self::Can => CAN.len(),
+            Self::Vcan => VCAN.len(),
+            Self::Vxcan => VXCAN.len(),
+            Self::Netkit => NETKIT.len(),
+            Self::BareUdp => BAREUDP.len(),
+            Self::Amt => AMT.len(),
+            Self::Rmnet => RMNET.len(),
+            Self::Wwan => WWAN.len(),
             Self::Other(s) => s.len(),
         };
         len + 1
@@ -317,6 +365,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoKind {
             GRETAP => Self::GreTap,
             IP6GRETAP => Self::GreTap6,
             IPIP => Self::IpTun,
+            IP6TNL => Self::Ip6Tun,
             SIT => Self::SitTun,
             GRE => Self::GreTun,
             IP6GRE => Self::GreTun6,
@@ -328,6 +377,17 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoKind {
             MACSEC => Self::MacSec,
             XFRM => Self::Xfrm,
             HSR => Self::Hsr,
+            GENEVE => Self::Geneve,
+            ERSPAN => Self::Erspan,
+            IP6ERSPAN => Self::Ip6Erspan,
+            CAN => Self::Can,
+            VCAN => Self::Vcan,
+            VXCAN => Self::Vxcan,
+            NETKIT => Self::Netkit,
+            BAREUDP => Self::BareUdp,
+            AMT => Self::Amt,
+            RMNET => Self::Rmnet,
+            WWAN => Self::Wwan,
             _ => Self::Other(s),
         })
     }
@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_ip, parse_u32, parse_u32_be, parse_u8},
+    DecodeError, Parseable,
+};
+
+use super::{
+    tunnel_encap::{IFLA_IPTUN_ENCAP_DPORT, IFLA_IPTUN_ENCAP_TYPE},
+    TunnelEncap,
+};
+use crate::IpProtocol;
+
+const IFLA_IPTUN_LINK: u16 = 1;
+const IFLA_IPTUN_LOCAL: u16 = 2;
+const IFLA_IPTUN_REMOTE: u16 = 3;
+const IFLA_IPTUN_TTL: u16 = 4;
+const IFLA_IPTUN_ENCAP_LIMIT: u16 = 6;
+const IFLA_IPTUN_FLOWINFO: u16 = 7;
+const IFLA_IPTUN_FLAGS: u16 = 8;
+const IFLA_IPTUN_PROTO: u16 = 9;
+const IFLA_IPTUN_COLLECT_METADATA: u16 = 19;
+const IFLA_IPTUN_FWMARK: u16 = 20;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum InfoIp6Tunnel {
+    /// Interface index of underlying link
+    Link(u32),
+    Local(Ipv6Addr),
+    Remote(Ipv6Addr),
+    /// Hop limit of outer IPv6 header
+    Ttl(u8),
+    EncapLimit(u8),
+    /// Traffic class and flow label of outer IPv6 header in host byte
+    /// order, e.g. `0x00012345` for flow label `0x12345`.
+    FlowInfo(u32),
+    Flags(Ip6TunnelFlags),
+    /// `IpProtocol::Ipip` for `ip4ip6`, `IpProtocol::Ipv6` for `ip6ip6`
+    /// and `IpProtocol::Hopopts`(0) for any.
+    Protocol(IpProtocol),
+    Encap(TunnelEncap),
+    CollectMetadata,
+    FwMark(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoIp6Tunnel {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::CollectMetadata => 0,
+            Self::Ttl(_) | Self::EncapLimit(_) | Self::Protocol(_) => 1,
+            Self::Link(_)
+            | Self::FlowInfo(_)
+            | Self::Flags(_)
+            | Self::FwMark(_) => 4,
+            Self::Local(_) | Self::Remote(_) => 16,
+            Self::Encap(v) => v.value_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Link(value) | Self::FwMark(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Local(value) | Self::Remote(value) => {
+                buffer.copy_from_slice(&value.octets())
+            }
+            Self::Ttl(value) | Self::EncapLimit(value) => buffer[0] = *value,
+            Self::FlowInfo(value) => BigEndian::write_u32(buffer, *value),
+            Self::Flags(value) => NativeEndian::write_u32(buffer, value.bits()),
+            Self::Protocol(value) => buffer[0] = i32::from(*value) as u8,
+            Self::Encap(v) => v.emit_value(buffer),
+            Self::CollectMetadata => (),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Link(_) => IFLA_IPTUN_LINK,
+            Self::Local(_) => IFLA_IPTUN_LOCAL,
+            Self::Remote(_) => IFLA_IPTUN_REMOTE,
+            Self::Ttl(_) => IFLA_IPTUN_TTL,
+            Self::EncapLimit(_) => IFLA_IPTUN_ENCAP_LIMIT,
+            Self::FlowInfo(_) => IFLA_IPTUN_FLOWINFO,
+            Self::Flags(_) => IFLA_IPTUN_FLAGS,
+            Self::Protocol(_) => IFLA_IPTUN_PROTO,
+            Self::Encap(v) => v.kind(IFLA_IPTUN_ENCAP_TYPE),
+            Self::CollectMetadata => IFLA_IPTUN_COLLECT_METADATA,
+            Self::FwMark(_) => IFLA_IPTUN_FWMARK,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for InfoIp6Tunnel
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_IPTUN_LINK => Self::Link(
+                parse_u32(payload).context("invalid IFLA_IPTUN_LINK value")?,
+            ),
+            IFLA_IPTUN_LOCAL => match parse_ip(payload) {
+                Ok(IpAddr::V6(addr)) => Self::Local(addr),
+                Ok(v) => {
+                    return Err(DecodeError::from(format!(
+                        "Invalid IFLA_IPTUN_LOCAL, \
+                        expecting IPv6 address, but got {v}"
+                    )))
+                }
+                Err(e) => {
+                    return Err(DecodeError::from(format!(
+                        "Invalid IFLA_IPTUN_LOCAL {e}"
+                    )))
+                }
+            },
+            IFLA_IPTUN_REMOTE => match parse_ip(payload) {
+                Ok(IpAddr::V6(addr)) => Self::Remote(addr),
+                Ok(v) => {
+                    return Err(DecodeError::from(format!(
+                        "Invalid IFLA_IPTUN_REMOTE, \
+                        expecting IPv6 address, but got {v}"
+                    )))
+                }
+                Err(e) => {
+                    return Err(DecodeError::from(format!(
+                        "Invalid IFLA_IPTUN_REMOTE {e}"
+                    )))
+                }
+            },
+            IFLA_IPTUN_TTL => Self::Ttl(
+                parse_u8(payload).context("invalid IFLA_IPTUN_TTL value")?,
+            ),
+            IFLA_IPTUN_ENCAP_LIMIT => Self::EncapLimit(
+                parse_u8(payload)
+                    .context("invalid IFLA_IPTUN_ENCAP_LIMIT value")?,
+            ),
+            IFLA_IPTUN_FLOWINFO => Self::FlowInfo(
+                parse_u32_be(payload)
+                    .context("invalid IFLA_IPTUN_FLOWINFO value")?,
+            ),
+            IFLA_IPTUN_FLAGS => Self::Flags(Ip6TunnelFlags::from_bits_retain(
+                parse_u32(payload).context("invalid IFLA_IPTUN_FLAGS value")?,
+            )),
+            IFLA_IPTUN_PROTO => Self::Protocol(IpProtocol::from(
+                parse_u8(payload).context("invalid IFLA_IPTUN_PROTO value")?
+                    as i32,
+            )),
+            kind @ IFLA_IPTUN_ENCAP_TYPE..=IFLA_IPTUN_ENCAP_DPORT => {
+                Self::Encap(TunnelEncap::parse(
+                    kind - IFLA_IPTUN_ENCAP_TYPE,
+                    payload,
+                )?)
+            }
+            IFLA_IPTUN_COLLECT_METADATA => Self::CollectMetadata,
+            IFLA_IPTUN_FWMARK => Self::FwMark(
+                parse_u32(payload)
+                    .context("invalid IFLA_IPTUN_FWMARK value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind} for ip6tnl"))?,
+            ),
+        })
+    }
+}
+
+const IP6_TNL_F_IGN_ENCAP_LIMIT: u32 = 0x1;
+const IP6_TNL_F_USE_ORIG_TCLASS: u32 = 0x2;
+const IP6_TNL_F_USE_ORIG_FLOWLABEL: u32 = 0x4;
+const IP6_TNL_F_MIP6_DEV: u32 = 0x8;
+const IP6_TNL_F_RCV_DSCP_COPY: u32 = 0x10;
+const IP6_TNL_F_USE_ORIG_FWMARK: u32 = 0x20;
+const IP6_TNL_F_ALLOW_LOCAL_REMOTE: u32 = 0x40;
+
+bitflags! {
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[non_exhaustive]
+    pub struct Ip6TunnelFlags: u32 {
+        /// Do not add encapsulation limit option
+        const IgnEncapLimit = IP6_TNL_F_IGN_ENCAP_LIMIT;
+        /// Copy traffic class from inner packet
+        const UseOrigTclass = IP6_TNL_F_USE_ORIG_TCLASS;
+        /// Copy flow label from inner packet
+        const UseOrigFlowlabel = IP6_TNL_F_USE_ORIG_FLOWLABEL;
+        /// Tunnel is used for Mobile IPv6
+        const Mip6Dev = IP6_TNL_F_MIP6_DEV;
+        /// Copy DSCP from outer packet on decapsulation
+        const RcvDscpCopy = IP6_TNL_F_RCV_DSCP_COPY;
+        /// Copy fwmark from inner packet
+        const UseOrigFwmark = IP6_TNL_F_USE_ORIG_FWMARK;
+        /// Allow remote address to be a local one
+        const AllowLocalRemote = IP6_TNL_F_ALLOW_LOCAL_REMOTE;
+        const _ = !0;
+    }
+}
@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr};
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_ip, parse_u32, parse_u8},
+    DecodeError, Parseable,
+};
+
+use super::{
+    tunnel_encap::{IFLA_IPTUN_ENCAP_DPORT, IFLA_IPTUN_ENCAP_TYPE},
+    TunnelEncap,
+};
+use crate::IpProtocol;
+
+const IFLA_IPTUN_LINK: u16 = 1;
+const IFLA_IPTUN_LOCAL: u16 = 2;
+const IFLA_IPTUN_REMOTE: u16 = 3;
+const IFLA_IPTUN_TTL: u16 = 4;
+const IFLA_IPTUN_TOS: u16 = 5;
+const IFLA_IPTUN_PROTO: u16 = 9;
+const IFLA_IPTUN_PMTUDISC: u16 = 10;
+const IFLA_IPTUN_COLLECT_METADATA: u16 = 19;
+const IFLA_IPTUN_FWMARK: u16 = 20;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum InfoIpTunnel {
+    /// Interface index of underlying link
+    Link(u32),
+    Local(Ipv4Addr),
+    Remote(Ipv4Addr),
+    Ttl(u8),
+    Tos(u8),
+    Protocol(IpProtocol),
+    /// Whether to set DF bit on outer IPv4 header
+    PMtuDisc(bool),
+    Encap(TunnelEncap),
+    CollectMetadata,
+    FwMark(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoIpTunnel {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::CollectMetadata => 0,
+            Self::Ttl(_)
+            | Self::Tos(_)
+            | Self::Protocol(_)
+            | Self::PMtuDisc(_) => 1,
+            Self::Link(_)
+            | Self::Local(_)
+            | Self::Remote(_)
+            | Self::FwMark(_) => 4,
+            Self::Encap(v) => v.value_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Link(value) | Self::FwMark(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Local(value) | Self::Remote(value) => {
+                buffer.copy_from_slice(&value.octets())
+            }
+            Self::Ttl(value) | Self::Tos(value) => buffer[0] = *value,
+            Self::Protocol(value) => buffer[0] = i32::from(*value) as u8,
+            Self::PMtuDisc(value) => buffer[0] = *value as u8,
+            Self::Encap(v) => v.emit_value(buffer),
+            Self::CollectMetadata => (),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Link(_) => IFLA_IPTUN_LINK,
+            Self::Local(_) => IFLA_IPTUN_LOCAL,
+            Self::Remote(_) => IFLA_IPTUN_REMOTE,
+            Self::Ttl(_) => IFLA_IPTUN_TTL,
+            Self::Tos(_) => IFLA_IPTUN_TOS,
+            Self::Protocol(_) => IFLA_IPTUN_PROTO,
+            Self::PMtuDisc(_) => IFLA_IPTUN_PMTUDISC,
+            Self::Encap(v) => v.kind(IFLA_IPTUN_ENCAP_TYPE),
+            Self::CollectMetadata => IFLA_IPTUN_COLLECT_METADATA,
+            Self::FwMark(_) => IFLA_IPTUN_FWMARK,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIpTunnel {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_IPTUN_LINK => Self::Link(
+                parse_u32(payload).context("invalid IFLA_IPTUN_LINK value")?,
+            ),
+            IFLA_IPTUN_LOCAL => match parse_ip(payload) {
+                Ok(IpAddr::V4(addr)) => Self::Local(addr),
+                Ok(v) => {
+                    return Err(DecodeError::from(format!(
+                        "Invalid IFLA_IPTUN_LOCAL, \
+                        expecting IPv4 address, but got {v}"
+                    )))
+                }
+                Err(e) => {
+                    return Err(DecodeError::from(format!(
+                        "Invalid IFLA_IPTUN_LOCAL {e}"
+                    )))
+                }
+            },
+            IFLA_IPTUN_REMOTE => match parse_ip(payload) {
+                Ok(IpAddr::V4(addr)) => Self::Remote(addr),
+                Ok(v) => {
+                    return Err(DecodeError::from(format!(
+                        "Invalid IFLA_IPTUN_REMOTE, \
+                        expecting IPv4 address, but got {v}"
+                    )))
+                }
+                Err(e) => {
+                    return Err(DecodeError::from(format!(
+                        "Invalid IFLA_IPTUN_REMOTE {e}"
+                    )))
+                }
+            },
+            IFLA_IPTUN_TTL => Self::Ttl(
+                parse_u8(payload).context("invalid IFLA_IPTUN_TTL value")?,
+            ),
+            IFLA_IPTUN_TOS => Self::Tos(
+                parse_u8(payload).context("invalid IFLA_IPTUN_TOS value")?,
+            ),
+            IFLA_IPTUN_PROTO => Self::Protocol(IpProtocol::from(
+                parse_u8(payload).context("invalid IFLA_IPTUN_PROTO value")?
+                    as i32,
+            )),
+            IFLA_IPTUN_PMTUDISC => Self::PMtuDisc(
+                parse_u8(payload)
+                    .context("invalid IFLA_IPTUN_PMTUDISC value")?
+                    > 0,
+            ),
+            kind @ IFLA_IPTUN_ENCAP_TYPE..=IFLA_IPTUN_ENCAP_DPORT => {
+                Self::Encap(TunnelEncap::parse(
+                    kind - IFLA_IPTUN_ENCAP_TYPE,
+                    payload,
+                )?)
+            }
+            IFLA_IPTUN_COLLECT_METADATA => Self::CollectMetadata,
+            IFLA_IPTUN_FWMARK => Self::FwMark(
+                parse_u32(payload)
+                    .context("invalid IFLA_IPTUN_FWMARK value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind} for ipip"))?,
+            ),
+        })
+    }
+}
@@ -1,32 +1,45 @@
 // SPDX-License-Identifier: MIT
 
+mod amt;
+mod bareudp;
 mod bond;
 mod bond_port;
 mod bridge;
 mod bridge_port;
+mod can;
+mod can_bittiming;
+mod erspan;
+mod geneve;
 mod gre;
 mod gre6;
-mod gre_tap;
-mod gre_tap6;
 mod gtp;
 mod hsr;
 mod info_data;
 mod info_port;
 mod infos;
+mod ip6tnl;
+mod ipip;
 mod ipoib;
 mod ipvlan;
 mod mac_vlan;
 mod macsec;
+mod netkit;
+mod rmnet;
 mod sit;
 mod tun;
+mod tunnel_encap;
 mod veth;
 mod vlan;
 mod vrf;
 mod vti;
+mod vxcan;
 mod vxlan;
+mod wwan;
 mod xfrm;
 mod xstats;
 
+pub use self::amt::{AmtMode, InfoAmt};
+pub use self::bareudp::{BareUdpEtherType, InfoBareUdp};
 pub use self::bond::{BondAdInfo, BondMode, InfoBond};
 pub use self::bond_port::{BondPortState, InfoBondPort, MiiStatus};
 pub use self::bridge::{
@@ -35,29 +48,43 @@ pub use self::bridge::{
 pub use self::bridge_port::{
     BridgePortMulticastRouter, BridgePortState, InfoBridgePort,
 };
-pub use self::gre::InfoGreTun;
-pub use self::gre6::InfoGreTun6;
-pub use self::gre_tap::InfoGreTap;
-pub use self::gre_tap6::InfoGreTap6;
-pub use self::gtp::InfoGtp;
+pub use self::can::{
+    CanCtrlMode, CanCtrlModeExt, CanCtrlModeFlags, CanState, CanTdc, InfoCan,
+};
+pub use self::can_bittiming::{
+    CanBerrCounter, CanBerrCounterBuffer, CanBitTiming, CanBitTimingBuffer,
+    CanBitTimingConst, CanBitTimingConstBuffer,
+};
+pub use self::erspan::{ErspanDirection, InfoErspan, InfoIp6Erspan};
+pub use self::geneve::{GeneveDf, InfoGeneve};
+pub use self::gre::{GreFlags, InfoGreTap, InfoGreTun};
+pub use self::gre6::{InfoGreTap6, InfoGreTun6};
+pub use self::gtp::{GtpRole, InfoGtp};
 pub use self::hsr::{HsrProtocol, InfoHsr};
 pub use self::info_data::InfoData;
 pub use self::info_port::{InfoPortData, InfoPortKind, InfoVrfPort};
 pub use self::infos::{InfoKind, LinkInfo};
+pub use self::ip6tnl::{InfoIp6Tunnel, Ip6TunnelFlags};
+pub use self::ipip::InfoIpTunnel;
 pub use self::ipoib::InfoIpoib;
 pub use self::ipvlan::{InfoIpVlan, InfoIpVtap, IpVlanMode, IpVtapMode};
 pub use self::mac_vlan::{InfoMacVlan, InfoMacVtap, MacVlanMode, MacVtapMode};
 pub use self::macsec::{
     InfoMacSec, MacSecCipherId, MacSecOffload, MacSecValidate,
 };
+pub use self::netkit::{InfoNetkit, NetkitMode, NetkitPolicy, NetkitScrub};
+pub use self::rmnet::{InfoRmnet, RmnetDataFormat, RmnetFlags};
 pub use self::sit::InfoSitTun;
-pub use self::tun::InfoTun;
+pub use self::tun::{InfoTun, TunType};
+pub use self::tunnel_encap::{TunnelEncap, TunnelEncapFlags, TunnelEncapType};
 pub use self::veth::InfoVeth;
 pub use self::vlan::{InfoVlan, VlanQosMapping};
 pub use self::vrf::InfoVrf;
 pub use self::vti::InfoVti;
+pub use self::vxcan::InfoVxcan;
 pub use self::vxlan::InfoVxlan;
+pub use self::wwan::InfoWwan;
 pub use self::xfrm::InfoXfrm;
-pub use self::xstats::LinkXstats;
+pub use self::xstats::{CanDeviceStats, CanDeviceStatsBuffer, LinkXstats};
 
 pub(crate) use self::infos::VecLinkInfo;
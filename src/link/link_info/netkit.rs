@@ -0,0 +1,253 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::super::{LinkMessage, LinkMessageBuffer};
+
+const IFLA_NETKIT_PEER_INFO: u16 = 1;
+const IFLA_NETKIT_PRIMARY: u16 = 2;
+const IFLA_NETKIT_POLICY: u16 = 3;
+const IFLA_NETKIT_PEER_POLICY: u16 = 4;
+const IFLA_NETKIT_MODE: u16 = 5;
+const IFLA_NETKIT_SCRUB: u16 = 6;
+const IFLA_NETKIT_PEER_SCRUB: u16 = 7;
+const IFLA_NETKIT_HEADROOM: u16 = 8;
+const IFLA_NETKIT_TAILROOM: u16 = 9;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum InfoNetkit {
+    /// Only for creating netkit pair
+    Peer(LinkMessage),
+    /// Whether this device is the primary device of the pair which can
+    /// attach BPF programs to both devices.
+    Primary(bool),
+    /// Default action when no BPF program attached to primary device
+    Policy(NetkitPolicy),
+    /// Default action when no BPF program attached to peer device
+    PeerPolicy(NetkitPolicy),
+    Mode(NetkitMode),
+    Scrub(NetkitScrub),
+    PeerScrub(NetkitScrub),
+    /// Needed headroom of packet buffer
+    Headroom(u16),
+    /// Needed tailroom of packet buffer
+    Tailroom(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoNetkit {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Peer(message) => message.buffer_len(),
+            Self::Primary(_) => 1,
+            Self::Headroom(_) | Self::Tailroom(_) => 2,
+            Self::Policy(_)
+            | Self::PeerPolicy(_)
+            | Self::Mode(_)
+            | Self::Scrub(_)
+            | Self::PeerScrub(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Peer(message) => message.emit(buffer),
+            Self::Primary(value) => buffer[0] = *value as u8,
+            Self::Policy(value) | Self::PeerPolicy(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::Mode(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::Scrub(value) | Self::PeerScrub(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::Headroom(value) | Self::Tailroom(value) => {
+                NativeEndian::write_u16(buffer, *value)
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Peer(_) => IFLA_NETKIT_PEER_INFO,
+            Self::Primary(_) => IFLA_NETKIT_PRIMARY,
+            Self::Policy(_) => IFLA_NETKIT_POLICY,
+            Self::PeerPolicy(_) => IFLA_NETKIT_PEER_POLICY,
+            Self::Mode(_) => IFLA_NETKIT_MODE,
+            Self::Scrub(_) => IFLA_NETKIT_SCRUB,
+            Self::PeerScrub(_) => IFLA_NETKIT_PEER_SCRUB,
+            Self::Headroom(_) => IFLA_NETKIT_HEADROOM,
+            Self::Tailroom(_) => IFLA_NETKIT_TAILROOM,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoNetkit {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_NETKIT_PEER_INFO => {
+                let err = "invalid IFLA_NETKIT_PEER_INFO value";
+                let buffer =
+                    LinkMessageBuffer::new_checked(&payload).context(err)?;
+                Self::Peer(LinkMessage::parse(&buffer).context(err)?)
+            }
+            IFLA_NETKIT_PRIMARY => Self::Primary(
+                parse_u8(payload)
+                    .context("invalid IFLA_NETKIT_PRIMARY value")?
+                    > 0,
+            ),
+            IFLA_NETKIT_POLICY => Self::Policy(
+                parse_u32(payload)
+                    .context("invalid IFLA_NETKIT_POLICY value")?
+                    .into(),
+            ),
+            IFLA_NETKIT_PEER_POLICY => Self::PeerPolicy(
+                parse_u32(payload)
+                    .context("invalid IFLA_NETKIT_PEER_POLICY value")?
+                    .into(),
+            ),
+            IFLA_NETKIT_MODE => Self::Mode(
+                parse_u32(payload)
+                    .context("invalid IFLA_NETKIT_MODE value")?
+                    .into(),
+            ),
+            IFLA_NETKIT_SCRUB => Self::Scrub(
+                parse_u32(payload)
+                    .context("invalid IFLA_NETKIT_SCRUB value")?
+                    .into(),
+            ),
+            IFLA_NETKIT_PEER_SCRUB => Self::PeerScrub(
+                parse_u32(payload)
+                    .context("invalid IFLA_NETKIT_PEER_SCRUB value")?
+                    .into(),
+            ),
+            IFLA_NETKIT_HEADROOM => Self::Headroom(
+                parse_u16(payload)
+                    .context("invalid IFLA_NETKIT_HEADROOM value")?,
+            ),
+            IFLA_NETKIT_TAILROOM => Self::Tailroom(
+                parse_u16(payload)
+                    .context("invalid IFLA_NETKIT_TAILROOM value")?,
+            ),
+            unknown_kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!(
+                    "Failed to parse IFLA_INFO_DATA(netkit) NLA type: \
+                    {unknown_kind} as DefaultNla"
+                ))?)
+            }
+        })
+    }
+}
+
+const NETKIT_PASS: u32 = 0;
+const NETKIT_DROP: u32 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum NetkitPolicy {
+    #[default]
+    Pass,
+    Drop,
+    Other(u32),
+}
+
+impl From<u32> for NetkitPolicy {
+    fn from(d: u32) -> Self {
+        match d {
+            NETKIT_PASS => Self::Pass,
+            NETKIT_DROP => Self::Drop,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<NetkitPolicy> for u32 {
+    fn from(d: NetkitPolicy) -> Self {
+        match d {
+            NetkitPolicy::Pass => NETKIT_PASS,
+            NetkitPolicy::Drop => NETKIT_DROP,
+            NetkitPolicy::Other(value) => value,
+        }
+    }
+}
+
+const NETKIT_L2: u32 = 0;
+const NETKIT_L3: u32 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum NetkitMode {
+    /// Device with ethernet header
+    L2,
+    /// Device without link layer header
+    #[default]
+    L3,
+    Other(u32),
+}
+
+impl From<u32> for NetkitMode {
+    fn from(d: u32) -> Self {
+        match d {
+            NETKIT_L2 => Self::L2,
+            NETKIT_L3 => Self::L3,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<NetkitMode> for u32 {
+    fn from(d: NetkitMode) -> Self {
+        match d {
+            NetkitMode::L2 => NETKIT_L2,
+            NetkitMode::L3 => NETKIT_L3,
+            NetkitMode::Other(value) => value,
+        }
+    }
+}
+
+const NETKIT_SCRUB_NONE: u32 = 0;
+const NETKIT_SCRUB_DEFAULT: u32 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum NetkitScrub {
+    /// Keep packet metadata like `skb->mark` and `skb->priority`
+    None,
+    /// Scrub packet metadata when crossing the netkit pair
+    #[default]
+    Default,
+    Other(u32),
+}
+
+impl From<u32> for NetkitScrub {
+    fn from(d: u32) -> Self {
+        match d {
+            NETKIT_SCRUB_NONE => Self::None,
+            NETKIT_SCRUB_DEFAULT => Self::Default,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<NetkitScrub> for u32 {
+    fn from(d: NetkitScrub) -> Self {
+        match d {
+            NetkitScrub::None => NETKIT_SCRUB_NONE,
+            NetkitScrub::Default => NETKIT_SCRUB_DEFAULT,
+            NetkitScrub::Other(value) => value,
+        }
+    }
+}
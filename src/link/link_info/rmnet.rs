@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u16,
+    traits::Parseable,
+    DecodeError,
+};
+
+const IFLA_RMNET_MUX_ID: u16 = 1;
+const IFLA_RMNET_FLAGS: u16 = 2;
+
+const RMNET_FLAGS_LEN: usize = 8;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum InfoRmnet {
+    /// QMAP multiplexing ID
+    MuxId(u16),
+    Flags(RmnetDataFormat),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoRmnet {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::MuxId(_) => 2,
+            Self::Flags(_) => RMNET_FLAGS_LEN,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::MuxId(value) => NativeEndian::write_u16(buffer, *value),
+            Self::Flags(value) => {
+                NativeEndian::write_u32(buffer, value.flags.bits());
+                NativeEndian::write_u32(&mut buffer[4..], value.mask.bits());
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::MuxId(_) => IFLA_RMNET_MUX_ID,
+            Self::Flags(_) => IFLA_RMNET_FLAGS,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoRmnet {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_RMNET_MUX_ID => Self::MuxId(
+                parse_u16(payload)
+                    .context("invalid IFLA_RMNET_MUX_ID value")?,
+            ),
+            IFLA_RMNET_FLAGS => {
+                if payload.len() < RMNET_FLAGS_LEN {
+                    return Err(format!(
+                        "invalid IFLA_RMNET_FLAGS value {payload:?}"
+                    )
+                    .into());
+                }
+                Self::Flags(RmnetDataFormat {
+                    flags: RmnetFlags::from_bits_retain(
+                        NativeEndian::read_u32(&payload[..4]),
+                    ),
+                    mask: RmnetFlags::from_bits_retain(NativeEndian::read_u32(
+                        &payload[4..8],
+                    )),
+                })
+            }
+            unknown_kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!(
+                    "Failed to parse IFLA_INFO_DATA(rmnet) NLA type: \
+                    {unknown_kind} as DefaultNla"
+                ))?)
+            }
+        })
+    }
+}
+
+/// Data format of rmnet port(`struct ifla_rmnet_flags`). Only flags set in
+/// `mask` are changed to the state in `flags` when configuring.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct RmnetDataFormat {
+    pub flags: RmnetFlags,
+    pub mask: RmnetFlags,
+}
+
+const RMNET_FLAGS_INGRESS_DEAGGREGATION: u32 = 1 << 0;
+const RMNET_FLAGS_INGRESS_MAP_COMMANDS: u32 = 1 << 1;
+const RMNET_FLAGS_INGRESS_MAP_CKSUMV4: u32 = 1 << 2;
+const RMNET_FLAGS_EGRESS_MAP_CKSUMV4: u32 = 1 << 3;
+const RMNET_FLAGS_INGRESS_MAP_CKSUMV5: u32 = 1 << 4;
+const RMNET_FLAGS_EGRESS_MAP_CKSUMV5: u32 = 1 << 5;
+
+bitflags! {
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[non_exhaustive]
+    pub struct RmnetFlags: u32 {
+        /// Split aggregated QMAP frames received from the modem
+        const IngressDeaggregation = RMNET_FLAGS_INGRESS_DEAGGREGATION;
+        /// Handle QMAP flow control commands from the modem
+        const IngressMapCommands = RMNET_FLAGS_INGRESS_MAP_COMMANDS;
+        /// Ingress checksum offload with MAPv4 header
+        const IngressMapCksumV4 = RMNET_FLAGS_INGRESS_MAP_CKSUMV4;
+        /// Egress checksum offload with MAPv4 header
+        const EgressMapCksumV4 = RMNET_FLAGS_EGRESS_MAP_CKSUMV4;
+        /// Ingress checksum offload with MAPv5 header
+        const IngressMapCksumV5 = RMNET_FLAGS_INGRESS_MAP_CKSUMV5;
+        /// Egress checksum offload with MAPv5 header
+        const EgressMapCksumV5 = RMNET_FLAGS_EGRESS_MAP_CKSUMV5;
+        const _ = !0;
+    }
+}
@@ -6,27 +6,36 @@ use netlink_packet_utils::{
     DecodeError, Parseable,
 };
 
+use super::{
+    tunnel_encap::{IFLA_IPTUN_ENCAP_DPORT, IFLA_IPTUN_ENCAP_TYPE},
+    TunnelEncap,
+};
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum InfoSitTun {
+    Encap(TunnelEncap),
     Other(DefaultNla),
 }
 
 impl Nla for InfoSitTun {
     fn value_len(&self) -> usize {
         match self {
+            Self::Encap(v) => v.value_len(),
             Self::Other(nla) => nla.value_len(),
         }
     }
 
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
+            Self::Encap(v) => v.emit_value(buffer),
             Self::Other(nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
         match self {
+            Self::Encap(v) => v.kind(IFLA_IPTUN_ENCAP_TYPE),
             Self::Other(nla) => nla.kind(),
         }
     }
@@ -34,8 +43,14 @@ impl Nla for InfoSitTun {
 
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoSitTun {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        #[allow(clippy::match_single_binding)]
+        let payload = buf.value();
         Ok(match buf.kind() {
+            kind @ IFLA_IPTUN_ENCAP_TYPE..=IFLA_IPTUN_ENCAP_DPORT => {
+                Self::Encap(TunnelEncap::parse(
+                    kind - IFLA_IPTUN_ENCAP_TYPE,
+                    payload,
+                )?)
+            }
             kind => Self::Other(
                 DefaultNla::parse(buf)
                     .context(format!("unknown NLA type {kind} for sit"))?,
@@ -1,32 +1,91 @@
 // SPDX-License-Identifier: MIT
 
 use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u8},
     DecodeError, Parseable,
 };
 
+const IFLA_TUN_OWNER: u16 = 1;
+const IFLA_TUN_GROUP: u16 = 2;
+const IFLA_TUN_TYPE: u16 = 3;
+const IFLA_TUN_PI: u16 = 4;
+const IFLA_TUN_VNET_HDR: u16 = 5;
+const IFLA_TUN_PERSIST: u16 = 6;
+const IFLA_TUN_MULTI_QUEUE: u16 = 7;
+const IFLA_TUN_NUM_QUEUES: u16 = 8;
+const IFLA_TUN_NUM_DISABLED_QUEUES: u16 = 9;
+
+const IFF_TUN: u8 = 0x0001;
+const IFF_TAP: u8 = 0x0002;
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum InfoTun {
+    /// User ID of owner, only available when owner is set
+    Owner(u32),
+    /// Group ID of owner, only available when group is set
+    Group(u32),
+    Type(TunType),
+    /// Whether packet information header is prepended
+    Pi(bool),
+    /// Whether `virtio_net_hdr` is prepended
+    VnetHdr(bool),
+    Persist(bool),
+    MultiQueue(bool),
+    /// Number of attached queues, only available for multi queue device
+    NumQueues(u32),
+    /// Number of disabled queues, only available for multi queue device
+    NumDisabledQueues(u32),
     Other(DefaultNla),
 }
 
 impl Nla for InfoTun {
     fn value_len(&self) -> usize {
         match self {
+            Self::Type(_)
+            | Self::Pi(_)
+            | Self::VnetHdr(_)
+            | Self::Persist(_)
+            | Self::MultiQueue(_) => 1,
+            Self::Owner(_)
+            | Self::Group(_)
+            | Self::NumQueues(_)
+            | Self::NumDisabledQueues(_) => 4,
             Self::Other(nla) => nla.value_len(),
         }
     }
 
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
+            Self::Owner(value)
+            | Self::Group(value)
+            | Self::NumQueues(value)
+            | Self::NumDisabledQueues(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Type(value) => buffer[0] = (*value).into(),
+            Self::Pi(value)
+            | Self::VnetHdr(value)
+            | Self::Persist(value)
+            | Self::MultiQueue(value) => buffer[0] = *value as u8,
             Self::Other(nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
         match self {
+            Self::Owner(_) => IFLA_TUN_OWNER,
+            Self::Group(_) => IFLA_TUN_GROUP,
+            Self::Type(_) => IFLA_TUN_TYPE,
+            Self::Pi(_) => IFLA_TUN_PI,
+            Self::VnetHdr(_) => IFLA_TUN_VNET_HDR,
+            Self::Persist(_) => IFLA_TUN_PERSIST,
+            Self::MultiQueue(_) => IFLA_TUN_MULTI_QUEUE,
+            Self::NumQueues(_) => IFLA_TUN_NUM_QUEUES,
+            Self::NumDisabledQueues(_) => IFLA_TUN_NUM_DISABLED_QUEUES,
             Self::Other(nla) => nla.kind(),
         }
     }
@@ -34,11 +93,77 @@ impl Nla for InfoTun {
 
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoTun {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        #[allow(clippy::match_single_binding)]
+        let payload = buf.value();
         Ok(match buf.kind() {
+            IFLA_TUN_OWNER => Self::Owner(
+                parse_u32(payload).context("invalid IFLA_TUN_OWNER value")?,
+            ),
+            IFLA_TUN_GROUP => Self::Group(
+                parse_u32(payload).context("invalid IFLA_TUN_GROUP value")?,
+            ),
+            IFLA_TUN_TYPE => Self::Type(
+                parse_u8(payload)
+                    .context("invalid IFLA_TUN_TYPE value")?
+                    .into(),
+            ),
+            IFLA_TUN_PI => Self::Pi(
+                parse_u8(payload).context("invalid IFLA_TUN_PI value")? > 0,
+            ),
+            IFLA_TUN_VNET_HDR => Self::VnetHdr(
+                parse_u8(payload).context("invalid IFLA_TUN_VNET_HDR value")?
+                    > 0,
+            ),
+            IFLA_TUN_PERSIST => Self::Persist(
+                parse_u8(payload).context("invalid IFLA_TUN_PERSIST value")?
+                    > 0,
+            ),
+            IFLA_TUN_MULTI_QUEUE => Self::MultiQueue(
+                parse_u8(payload)
+                    .context("invalid IFLA_TUN_MULTI_QUEUE value")?
+                    > 0,
+            ),
+            IFLA_TUN_NUM_QUEUES => Self::NumQueues(
+                parse_u32(payload)
+                    .context("invalid IFLA_TUN_NUM_QUEUES value")?,
+            ),
+            IFLA_TUN_NUM_DISABLED_QUEUES => Self::NumDisabledQueues(
+                parse_u32(payload)
+                    .context("invalid IFLA_TUN_NUM_DISABLED_QUEUES value")?,
+            ),
             kind => Self::Other(DefaultNla::parse(buf).context(format!(
-                "unknown NLA type {kind} for IFLA_INFO_DATA(vrf)"
+                "unknown NLA type {kind} for IFLA_INFO_DATA(tun)"
             ))?),
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum TunType {
+    /// Layer 3 device without ethernet header
+    #[default]
+    Tun,
+    /// Layer 2 device with ethernet header
+    Tap,
+    Other(u8),
+}
+
+impl From<u8> for TunType {
+    fn from(d: u8) -> Self {
+        match d {
+            IFF_TUN => Self::Tun,
+            IFF_TAP => Self::Tap,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TunType> for u8 {
+    fn from(d: TunType) -> Self {
+        match d {
+            TunType::Tun => IFF_TUN,
+            TunType::Tap => IFF_TAP,
+            TunType::Other(value) => value,
+        }
+    }
+}
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    parsers::{parse_u16, parse_u16_be},
+    DecodeError,
+};
+
+// The `IFLA_GRE_ENCAP_*` and `IFLA_IPTUN_ENCAP_*` attributes are both in the
+// order of type, flags, source port and destination port.
+pub(crate) const IFLA_GRE_ENCAP_TYPE: u16 = 14;
+pub(crate) const IFLA_GRE_ENCAP_DPORT: u16 = 17;
+pub(crate) const IFLA_IPTUN_ENCAP_TYPE: u16 = 15;
+pub(crate) const IFLA_IPTUN_ENCAP_DPORT: u16 = 18;
+
+const ENCAP_TYPE: u16 = 0;
+const ENCAP_FLAGS: u16 = 1;
+const ENCAP_SPORT: u16 = 2;
+const ENCAP_DPORT: u16 = 3;
+
+const TUNNEL_ENCAP_NONE: u16 = 0;
+const TUNNEL_ENCAP_FOU: u16 = 1;
+const TUNNEL_ENCAP_GUE: u16 = 2;
+const TUNNEL_ENCAP_MPLS: u16 = 3;
+
+const TUNNEL_ENCAP_FLAG_CSUM: u16 = 1 << 0;
+const TUNNEL_ENCAP_FLAG_CSUM6: u16 = 1 << 1;
+const TUNNEL_ENCAP_FLAG_REMCSUM: u16 = 1 << 2;
+
+/// UDP encapsulation(FOU/GUE) of IP tunnels, shared by the
+/// `IFLA_GRE_ENCAP_*` and `IFLA_IPTUN_ENCAP_*` attributes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TunnelEncap {
+    Type(TunnelEncapType),
+    Flags(TunnelEncapFlags),
+    SourcePort(u16),
+    DestinationPort(u16),
+}
+
+impl TunnelEncap {
+    pub(crate) fn value_len(&self) -> usize {
+        match self {
+            Self::Type(_)
+            | Self::Flags(_)
+            | Self::SourcePort(_)
+            | Self::DestinationPort(_) => 2,
+        }
+    }
+
+    pub(crate) fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Type(value) => {
+                NativeEndian::write_u16(buffer, (*value).into())
+            }
+            Self::Flags(value) => NativeEndian::write_u16(buffer, value.bits()),
+            Self::SourcePort(value) | Self::DestinationPort(value) => {
+                BigEndian::write_u16(buffer, *value)
+            }
+        }
+    }
+
+    /// The NLA kind, `base` is the kind of `IFLA_GRE_ENCAP_TYPE` or
+    /// `IFLA_IPTUN_ENCAP_TYPE`.
+    pub(crate) fn kind(&self, base: u16) -> u16 {
+        base + match self {
+            Self::Type(_) => ENCAP_TYPE,
+            Self::Flags(_) => ENCAP_FLAGS,
+            Self::SourcePort(_) => ENCAP_SPORT,
+            Self::DestinationPort(_) => ENCAP_DPORT,
+        }
+    }
+
+    /// Parse the attribute at `offset` from the `*_ENCAP_TYPE` attribute.
+    pub(crate) fn parse(
+        offset: u16,
+        payload: &[u8],
+    ) -> Result<Self, DecodeError> {
+        Ok(match offset {
+            ENCAP_TYPE => Self::Type(
+                parse_u16(payload)
+                    .context("invalid tunnel ENCAP_TYPE value")?
+                    .into(),
+            ),
+            ENCAP_FLAGS => Self::Flags(TunnelEncapFlags::from_bits_retain(
+                parse_u16(payload)
+                    .context("invalid tunnel ENCAP_FLAGS value")?,
+            )),
+            ENCAP_SPORT => Self::SourcePort(
+                parse_u16_be(payload)
+                    .context("invalid tunnel ENCAP_SPORT value")?,
+            ),
+            ENCAP_DPORT => Self::DestinationPort(
+                parse_u16_be(payload)
+                    .context("invalid tunnel ENCAP_DPORT value")?,
+            ),
+            _ => {
+                return Err(DecodeError::from(format!(
+                    "invalid tunnel encap attribute offset {offset}"
+                )))
+            }
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum TunnelEncapType {
+    #[default]
+    None,
+    /// Foo over UDP
+    Fou,
+    /// Generic UDP Encapsulation
+    Gue,
+    Mpls,
+    Other(u16),
+}
+
+impl From<u16> for TunnelEncapType {
+    fn from(d: u16) -> Self {
+        match d {
+            TUNNEL_ENCAP_NONE => Self::None,
+            TUNNEL_ENCAP_FOU => Self::Fou,
+            TUNNEL_ENCAP_GUE => Self::Gue,
+            TUNNEL_ENCAP_MPLS => Self::Mpls,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TunnelEncapType> for u16 {
+    fn from(d: TunnelEncapType) -> Self {
+        match d {
+            TunnelEncapType::None => TUNNEL_ENCAP_NONE,
+            TunnelEncapType::Fou => TUNNEL_ENCAP_FOU,
+            TunnelEncapType::Gue => TUNNEL_ENCAP_GUE,
+            TunnelEncapType::Mpls => TUNNEL_ENCAP_MPLS,
+            TunnelEncapType::Other(value) => value,
+        }
+    }
+}
+
+bitflags! {
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[non_exhaustive]
+    pub struct TunnelEncapFlags: u16 {
+        /// UDP checksum on IPv4 outer header
+        const Csum = TUNNEL_ENCAP_FLAG_CSUM;
+        /// UDP checksum on IPv6 outer header
+        const Csum6 = TUNNEL_ENCAP_FLAG_CSUM6;
+        /// Remote checksum offload
+        const RemCsum = TUNNEL_ENCAP_FLAG_REMCSUM;
+        const _ = !0;
+    }
+}
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::super::{LinkMessage, LinkMessageBuffer};
+
+const VXCAN_INFO_PEER: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+// Like veth, the `struct rtnl_link_ops vxcan_link_ops` does not have
+// `fill_info` as in kernel 6.5.8, hence only for creating vxcan pair.
+pub enum InfoVxcan {
+    Peer(LinkMessage),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoVxcan {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Peer(message) => message.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Peer(message) => message.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Peer(_) => VXCAN_INFO_PEER,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVxcan {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            VXCAN_INFO_PEER => {
+                let err = "failed to parse vxcan link info";
+                let buffer =
+                    LinkMessageBuffer::new_checked(&payload).context(err)?;
+                Self::Peer(LinkMessage::parse(&buffer).context(err)?)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
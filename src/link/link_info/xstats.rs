@@ -1,7 +1,8 @@
 // SPDX-License-Identifier: MIT
 
+use anyhow::Context;
 use netlink_packet_utils::{
-    nla::NlaBuffer, DecodeError, Emitable, ParseableParametrized,
+    nla::NlaBuffer, DecodeError, Emitable, Parseable, ParseableParametrized,
 };
 
 use crate::link::InfoKind;
@@ -11,18 +12,21 @@ use crate::link::InfoKind;
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum LinkXstats {
+    Can(CanDeviceStats),
     Other(Vec<u8>),
 }
 
 impl Emitable for LinkXstats {
     fn buffer_len(&self) -> usize {
         match self {
+            Self::Can(v) => v.buffer_len(),
             Self::Other(v) => v.len(),
         }
     }
 
     fn emit(&self, buffer: &mut [u8]) {
         match self {
+            Self::Can(v) => v.emit(buffer),
             Self::Other(v) => buffer.copy_from_slice(v.as_slice()),
         }
     }
@@ -33,8 +37,76 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
 {
     fn parse_with_param(
         buf: &NlaBuffer<&'a T>,
-        _kind: &InfoKind,
+        kind: &InfoKind,
     ) -> Result<Self, DecodeError> {
-        Ok(Self::Other(buf.value().to_vec()))
+        let payload = buf.value();
+        Ok(match kind {
+            InfoKind::Can => Self::Can(
+                CanDeviceStats::parse(
+                    &CanDeviceStatsBuffer::new_checked(payload)
+                        .context("invalid IFLA_INFO_XSTATS for can")?,
+                )
+                .context("invalid IFLA_INFO_XSTATS for can")?,
+            ),
+            _ => Self::Other(payload.to_vec()),
+        })
+    }
+}
+
+const CAN_DEVICE_STATS_LEN: usize = 24;
+
+buffer!(CanDeviceStatsBuffer(CAN_DEVICE_STATS_LEN) {
+    bus_error: (u32, 0..4),
+    error_warning: (u32, 4..8),
+    error_passive: (u32, 8..12),
+    bus_off: (u32, 12..16),
+    arbitration_lost: (u32, 16..20),
+    restarts: (u32, 20..24),
+});
+
+/// CAN device statistics(`struct can_device_stats`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct CanDeviceStats {
+    /// Bus errors
+    pub bus_error: u32,
+    /// Changes to error warning state
+    pub error_warning: u32,
+    /// Changes to error passive state
+    pub error_passive: u32,
+    /// Changes to bus off state
+    pub bus_off: u32,
+    /// Arbitration lost errors
+    pub arbitration_lost: u32,
+    /// CAN controller re-starts
+    pub restarts: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<CanDeviceStatsBuffer<T>> for CanDeviceStats {
+    fn parse(buf: &CanDeviceStatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            bus_error: buf.bus_error(),
+            error_warning: buf.error_warning(),
+            error_passive: buf.error_passive(),
+            bus_off: buf.bus_off(),
+            arbitration_lost: buf.arbitration_lost(),
+            restarts: buf.restarts(),
+        })
+    }
+}
+
+impl Emitable for CanDeviceStats {
+    fn buffer_len(&self) -> usize {
+        CAN_DEVICE_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = CanDeviceStatsBuffer::new(buffer);
+        buffer.set_bus_error(self.bus_error);
+        buffer.set_error_warning(self.error_warning);
+        buffer.set_error_passive(self.error_passive);
+        buffer.set_bus_off(self.bus_off);
+        buffer.set_arbitration_lost(self.arbitration_lost);
+        buffer.set_restarts(self.restarts);
     }
 }
@@ -38,15 +38,24 @@ pub use self::ext_mask::LinkExtentMask;
 pub use self::header::{LinkHeader, LinkMessageBuffer};
 pub use self::link_flag::LinkFlags;
 pub use self::link_info::{
-    BondAdInfo, BondMode, BondPortState, BridgeId, BridgeIdBuffer,
-    BridgePortMulticastRouter, BridgePortState, BridgeQuerierState,
-    HsrProtocol, InfoBond, InfoBondPort, InfoBridge, InfoBridgePort, InfoData,
-    InfoGreTap, InfoGreTap6, InfoGreTun, InfoGreTun6, InfoGtp, InfoHsr,
-    InfoIpVlan, InfoIpVtap, InfoIpoib, InfoKind, InfoMacSec, InfoMacVlan,
-    InfoMacVtap, InfoPortData, InfoPortKind, InfoSitTun, InfoTun, InfoVeth,
-    InfoVlan, InfoVrf, InfoVrfPort, InfoVti, InfoVxlan, InfoXfrm, IpVlanMode,
-    IpVtapMode, LinkInfo, LinkXstats, MacSecCipherId, MacSecOffload,
-    MacSecValidate, MacVlanMode, MacVtapMode, MiiStatus, VlanQosMapping,
+    AmtMode, BareUdpEtherType, BondAdInfo, BondMode, BondPortState, BridgeId,
+    BridgeIdBuffer, BridgePortMulticastRouter, BridgePortState,
+    BridgeQuerierState, CanBerrCounter, CanBerrCounterBuffer, CanBitTiming,
+    CanBitTimingBuffer, CanBitTimingConst, CanBitTimingConstBuffer,
+    CanCtrlMode, CanCtrlModeExt, CanCtrlModeFlags, CanDeviceStats,
+    CanDeviceStatsBuffer, CanState, CanTdc, ErspanDirection, GeneveDf,
+    GreFlags, GtpRole, HsrProtocol, InfoAmt, InfoBareUdp, InfoBond,
+    InfoBondPort, InfoBridge, InfoBridgePort, InfoCan, InfoData, InfoErspan,
+    InfoGeneve, InfoGreTap, InfoGreTap6, InfoGreTun, InfoGreTun6, InfoGtp,
+    InfoHsr, InfoIp6Erspan, InfoIp6Tunnel, InfoIpTunnel, InfoIpVlan,
+    InfoIpVtap, InfoIpoib, InfoKind, InfoMacSec, InfoMacVlan, InfoMacVtap,
+    InfoNetkit, InfoPortData, InfoPortKind, InfoRmnet, InfoSitTun, InfoTun,
+    InfoVeth, InfoVlan, InfoVrf, InfoVrfPort, InfoVti, InfoVxcan, InfoVxlan,
+    InfoWwan, InfoXfrm, Ip6TunnelFlags, IpVlanMode, IpVtapMode, LinkInfo,
+    LinkXstats, MacSecCipherId, MacSecOffload, MacSecValidate, MacVlanMode,
+    MacVtapMode, MiiStatus, NetkitMode, NetkitPolicy, NetkitScrub,
+    RmnetDataFormat, RmnetFlags, TunType, TunnelEncap, TunnelEncapFlags,
+    TunnelEncapType, VlanQosMapping,
 };
 pub use self::link_layer_type::LinkLayerType;
 pub use self::link_state::State;
@@ -69,3 +78,6 @@ pub use self::stats64::{Stats64, Stats64Buffer};
 pub use self::vlan_protocol::VlanProtocol;
 pub use self::wireless::LinkWirelessEvent;
 pub use self::xdp::{LinkXdp, XdpAttached};
+
+pub(crate) use self::buffer_tool::expand_buffer_if_small;
+pub(crate) use self::stats64::LINK_STATS64_LEN;
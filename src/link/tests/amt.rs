@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::{
+    AmtMode, InfoAmt, InfoData, InfoKind, LinkAttribute, LinkInfo, LinkMessage,
+    LinkMessageBuffer,
+};
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request equivalent to
+// command:
+//      ip link add amt0 type amt mode gateway local 192.0.2.1 \
+//          discovery 198.51.100.1 gateway_port 2268 dev eth1
+// with eth1 as interface index 2.
+#[test]
+fn test_create_amt_gateway() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x61, 0x6d, 0x74, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x38, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x61, 0x6d, 0x74, 0x00, 0x2c, 0x00, 0x02, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00, 0xc0, 0x00, 0x02, 0x01,
+        0x08, 0x00, 0x07, 0x00, 0xc6, 0x33, 0x64, 0x01, 0x06, 0x00, 0x03, 0x00,
+        0x08, 0xdc, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x02, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("amt0".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Amt),
+                LinkInfo::Data(InfoData::Amt(vec![
+                    InfoAmt::Mode(AmtMode::Gateway),
+                    InfoAmt::LocalIp(Ipv4Addr::from_str("192.0.2.1").unwrap()),
+                    InfoAmt::DiscoveryIp(
+                        Ipv4Addr::from_str("198.51.100.1").unwrap(),
+                    ),
+                    InfoAmt::GatewayPort(2268),
+                    InfoAmt::Link(2),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request equivalent to
+// command:
+//      ip link add amt1 type amt mode relay local 198.51.100.1 \
+//          relay_port 2268 max_tunnels 128 dev eth1
+// with eth1 as interface index 2.
+#[test]
+fn test_create_amt_relay() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x61, 0x6d, 0x74, 0x31,
+        0x00, 0x00, 0x00, 0x00, 0x38, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x61, 0x6d, 0x74, 0x00, 0x2c, 0x00, 0x02, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00, 0xc6, 0x33, 0x64, 0x01,
+        0x06, 0x00, 0x02, 0x00, 0x08, 0xdc, 0x00, 0x00, 0x08, 0x00, 0x08, 0x00,
+        0x80, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x02, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("amt1".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Amt),
+                LinkInfo::Data(InfoData::Amt(vec![
+                    InfoAmt::Mode(AmtMode::Relay),
+                    InfoAmt::LocalIp(
+                        Ipv4Addr::from_str("198.51.100.1").unwrap(),
+                    ),
+                    InfoAmt::RelayPort(2268),
+                    InfoAmt::MaxTunnels(128),
+                    InfoAmt::Link(2),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::{
+    BareUdpEtherType, InfoBareUdp, InfoData, InfoKind, LinkAttribute,
+    LinkFlags, LinkHeader, LinkInfo, LinkLayerType, LinkMessage,
+    LinkMessageBuffer,
+};
+use crate::AddressFamily;
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request sent by
+// iproute2 6.1 with IFLA_INFO_KIND changed to NULL terminated
+// string(see `test_crate_veth()`), command:
+//      ip link add bareudp0 type bareudp dstport 6635 ethertype mpls_uc \
+//          srcportmin 40000 multiproto
+#[test]
+fn test_create_bareudp_mpls() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x03, 0x00, 0x62, 0x61, 0x72, 0x65,
+        0x75, 0x64, 0x70, 0x30, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x12, 0x00,
+        0x0c, 0x00, 0x01, 0x00, 0x62, 0x61, 0x72, 0x65, 0x75, 0x64, 0x70, 0x00,
+        0x20, 0x00, 0x02, 0x00, 0x06, 0x00, 0x01, 0x00, 0x19, 0xeb, 0x00, 0x00,
+        0x06, 0x00, 0x02, 0x00, 0x88, 0x47, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00,
+        0x40, 0x9c, 0x00, 0x00, 0x04, 0x00, 0x04, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("bareudp0".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::BareUdp),
+                LinkInfo::Data(InfoData::BareUdp(vec![
+                    InfoBareUdp::Port(6635),
+                    InfoBareUdp::EtherType(BareUdpEtherType::MplsUnicast),
+                    InfoBareUdp::SrcPortMin(40000),
+                    InfoBareUdp::MultiProtoMode,
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request sent by
+// iproute2 6.1 with IFLA_INFO_KIND changed to NULL terminated
+// string(see `test_crate_veth()`), command:
+//      ip link add bareudp1 type bareudp dstport 6081 ethertype ipv4
+#[test]
+fn test_create_bareudp_ipv4() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x03, 0x00, 0x62, 0x61, 0x72, 0x65,
+        0x75, 0x64, 0x70, 0x31, 0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x12, 0x00,
+        0x0c, 0x00, 0x01, 0x00, 0x62, 0x61, 0x72, 0x65, 0x75, 0x64, 0x70, 0x00,
+        0x14, 0x00, 0x02, 0x00, 0x06, 0x00, 0x01, 0x00, 0x17, 0xc1, 0x00, 0x00,
+        0x06, 0x00, 0x02, 0x00, 0x08, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("bareudp1".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::BareUdp),
+                LinkInfo::Data(InfoData::BareUdp(vec![
+                    InfoBareUdp::Port(6081),
+                    InfoBareUdp::EtherType(BareUdpEtherType::Ipv4),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of kernel reply to
+// `ip -d link show bareudp0` for interface created by
+// `test_create_bareudp_mpls()`, following the layout of `bareudp_setup()`
+// and `bareudp_fill_info()` in Linux 6.18, with attributes other than
+// `IFLA_IFNAME` and `IFLA_LINKINFO` removed.
+#[test]
+fn test_get_bareudp_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0xfe, 0xff, 0x03, 0x00, 0x00, 0x00, 0x90, 0x10, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x03, 0x00, 0x62, 0x61, 0x72, 0x65,
+        0x75, 0x64, 0x70, 0x30, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x12, 0x00,
+        0x0c, 0x00, 0x01, 0x00, 0x62, 0x61, 0x72, 0x65, 0x75, 0x64, 0x70, 0x00,
+        0x20, 0x00, 0x02, 0x00, 0x06, 0x00, 0x01, 0x00, 0x19, 0xeb, 0x00, 0x00,
+        0x06, 0x00, 0x02, 0x00, 0x88, 0x47, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00,
+        0x40, 0x9c, 0x00, 0x00, 0x04, 0x00, 0x04, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 3,
+            link_layer_type: LinkLayerType::None,
+            flags: LinkFlags::Pointopoint
+                | LinkFlags::Noarp
+                | LinkFlags::Multicast,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![
+            LinkAttribute::IfName("bareudp0".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::BareUdp),
+                LinkInfo::Data(InfoData::BareUdp(vec![
+                    InfoBareUdp::Port(6635),
+                    InfoBareUdp::EtherType(BareUdpEtherType::MplsUnicast),
+                    InfoBareUdp::SrcPortMin(40000),
+                    InfoBareUdp::MultiProtoMode,
+                ])),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    CanBerrCounter, CanBitTiming, CanBitTimingConst, CanCtrlMode,
+    CanCtrlModeExt, CanCtrlModeFlags, CanDeviceStats, CanState, CanTdc,
+    InfoCan, InfoData, InfoKind, InfoVxcan, LinkAttribute, LinkHeader,
+    LinkInfo, LinkLayerType, LinkMessage, LinkMessageBuffer, LinkXstats,
+};
+use crate::AddressFamily;
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request equivalent to
+// command:
+//      ip link set can0 type can restart-ms 100 termination 120 \
+//          bitrate 500000 sample-point 0.875 dbitrate 2000000 fd on
+#[test]
+fn test_set_can_bittiming() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x63, 0x61, 0x6e, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x74, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x63, 0x61, 0x6e, 0x00, 0x68, 0x00, 0x02, 0x00, 0x08, 0x00, 0x06, 0x00,
+        0x64, 0x00, 0x00, 0x00, 0x06, 0x00, 0x0b, 0x00, 0x78, 0x00, 0x00, 0x00,
+        0x24, 0x00, 0x01, 0x00, 0x20, 0xa1, 0x07, 0x00, 0x6b, 0x03, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x24, 0x00, 0x09, 0x00, 0x80, 0x84, 0x1e, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x05, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("can0".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Can),
+                LinkInfo::Data(InfoData::Can(vec![
+                    InfoCan::RestartMs(100),
+                    InfoCan::Termination(120),
+                    InfoCan::BitTiming(CanBitTiming {
+                        bitrate: 500000,
+                        sample_point: 875,
+                        ..Default::default()
+                    }),
+                    InfoCan::DataBitTiming(CanBitTiming {
+                        bitrate: 2000000,
+                        ..Default::default()
+                    }),
+                    InfoCan::CtrlMode(CanCtrlMode {
+                        mask: CanCtrlModeFlags::Fd,
+                        flags: CanCtrlModeFlags::Fd,
+                    }),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` reply of
+// `ip -d link show can0` against a M_CAN controller configured with
+// `bitrate 500000 sample-point 0.875 restart-ms 100`, with attributes other
+// than `IFLA_IFNAME` and `IFLA_LINKINFO` removed.
+#[test]
+fn test_get_can_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x18, 0x01, 0x03, 0x00, 0x00, 0x00, 0xc1, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x63, 0x61, 0x6e, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0xe8, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x63, 0x61, 0x6e, 0x00, 0x1c, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x02, 0x00,
+        0x24, 0x00, 0x01, 0x00, 0x20, 0xa1, 0x07, 0x00, 0x6b, 0x03, 0x00, 0x00,
+        0x19, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x34, 0x00, 0x02, 0x00, 0x6d, 0x5f, 0x63, 0x61, 0x6e, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+        0x80, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x00, 0x5a, 0x62, 0x02,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x08, 0x00, 0x06, 0x00,
+        0x64, 0x00, 0x00, 0x00, 0x08, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0f, 0x00, 0x00, 0x12, 0x7a, 0x00, 0x24, 0x00, 0x10, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0x7f, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x04, 0x00, 0x7f, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x11, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0xff, 0x07, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 3,
+            link_layer_type: LinkLayerType::Can,
+            flags: LinkFlags::Up
+                | LinkFlags::Running
+                | LinkFlags::Noarp
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![
+            LinkAttribute::IfName("can0".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Can),
+                LinkInfo::Xstats(LinkXstats::Can(CanDeviceStats::default())),
+                LinkInfo::Data(InfoData::Can(vec![
+                    InfoCan::BitTiming(CanBitTiming {
+                        bitrate: 500000,
+                        sample_point: 875,
+                        tq: 25,
+                        prop_seg: 34,
+                        phase_seg1: 35,
+                        phase_seg2: 10,
+                        sjw: 10,
+                        brp: 1,
+                    }),
+                    InfoCan::BitTimingConst(CanBitTimingConst {
+                        name: "m_can".to_string(),
+                        tseg1_min: 2,
+                        tseg1_max: 256,
+                        tseg2_min: 2,
+                        tseg2_max: 128,
+                        sjw_max: 128,
+                        brp_min: 1,
+                        brp_max: 512,
+                        brp_inc: 1,
+                    }),
+                    InfoCan::Clock(40000000),
+                    InfoCan::State(CanState::ErrorActive),
+                    InfoCan::CtrlMode(CanCtrlMode {
+                        mask: CanCtrlModeFlags::empty(),
+                        flags: CanCtrlModeFlags::Fd,
+                    }),
+                    InfoCan::RestartMs(100),
+                    InfoCan::BerrCounter(CanBerrCounter { txerr: 0, rxerr: 0 }),
+                    InfoCan::BitRateMax(8000000),
+                    InfoCan::Tdc(vec![
+                        CanTdc::TdcvMin(1),
+                        CanTdc::TdcvMax(127),
+                        CanTdc::TdcoMin(0),
+                        CanTdc::TdcoMax(127),
+                    ]),
+                    InfoCan::CtrlModeExt(vec![CanCtrlModeExt::Supported(
+                        CanCtrlModeFlags::from_bits_retain(0x7ff),
+                    )]),
+                ])),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request equivalent to
+// command:
+//      ip link add vxcan0 type vxcan peer name vxcan1
+#[test]
+fn test_create_vxcan() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x03, 0x00, 0x76, 0x78, 0x63, 0x61,
+        0x6e, 0x30, 0x00, 0x00, 0x34, 0x00, 0x12, 0x00, 0x0a, 0x00, 0x01, 0x00,
+        0x76, 0x78, 0x63, 0x61, 0x6e, 0x00, 0x00, 0x00, 0x24, 0x00, 0x02, 0x00,
+        0x20, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x03, 0x00,
+        0x76, 0x78, 0x63, 0x61, 0x6e, 0x31, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("vxcan0".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Vxcan),
+                LinkInfo::Data(InfoData::Vxcan(InfoVxcan::Peer(LinkMessage {
+                    attributes: vec![LinkAttribute::IfName(
+                        "vxcan1".to_string(),
+                    )],
+                    ..Default::default()
+                }))),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
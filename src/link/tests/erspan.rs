@@ -0,0 +1,268 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::{
+    ErspanDirection, GreFlags, InfoData, InfoErspan, InfoIp6Erspan, InfoKind,
+    Ip6TunnelFlags, LinkAttribute, LinkInfo, LinkMessage, LinkMessageBuffer,
+    TunnelEncap, TunnelEncapFlags, TunnelEncapType,
+};
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request sent by
+// iproute2 6.1 with IFLA_INFO_KIND changed to NULL terminated string(see
+// `test_crate_veth()`), command:
+//      ip link add ers1 type erspan local 192.0.2.1 remote 192.0.2.2 key 10 \
+//          erspan_ver 1 erspan 100
+#[test]
+fn test_create_erspan_v1() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x65, 0x72, 0x73, 0x31,
+        0x00, 0x00, 0x00, 0x00, 0x94, 0x00, 0x12, 0x00, 0x0b, 0x00, 0x01, 0x00,
+        0x65, 0x72, 0x73, 0x70, 0x61, 0x6e, 0x00, 0x00, 0x84, 0x00, 0x02, 0x00,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x0a, 0x06, 0x00, 0x02, 0x00, 0x20, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x20, 0x00, 0x00, 0x00, 0x08, 0x00, 0x06, 0x00,
+        0xc0, 0x00, 0x02, 0x01, 0x08, 0x00, 0x07, 0x00, 0xc0, 0x00, 0x02, 0x02,
+        0x05, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x16, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x15, 0x00, 0x64, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("ers1".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Erspan),
+                LinkInfo::Data(InfoData::Erspan(vec![
+                    InfoErspan::IKey(10),
+                    InfoErspan::OKey(10),
+                    InfoErspan::IFlags(GreFlags::Key),
+                    InfoErspan::OFlags(GreFlags::Key),
+                    InfoErspan::Local(Ipv4Addr::from_str("192.0.2.1").unwrap()),
+                    InfoErspan::Remote(
+                        Ipv4Addr::from_str("192.0.2.2").unwrap(),
+                    ),
+                    InfoErspan::PMtuDisc(true),
+                    InfoErspan::Tos(0),
+                    InfoErspan::Ttl(0),
+                    InfoErspan::FwMark(0),
+                    InfoErspan::Version(1),
+                    InfoErspan::Index(100),
+                    InfoErspan::Encap(TunnelEncap::Type(TunnelEncapType::None)),
+                    InfoErspan::Encap(TunnelEncap::Flags(
+                        TunnelEncapFlags::empty(),
+                    )),
+                    InfoErspan::Encap(TunnelEncap::SourcePort(0)),
+                    InfoErspan::Encap(TunnelEncap::DestinationPort(0)),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request sent by
+// iproute2 6.1 with IFLA_INFO_KIND changed to NULL terminated string(see
+// `test_crate_veth()`), command:
+//      ip link add ers2 type erspan local 192.0.2.1 remote 192.0.2.2 key 20 \
+//          erspan_ver 2 erspan_dir egress erspan_hwid 7
+#[test]
+fn test_create_erspan_v2() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x65, 0x72, 0x73, 0x32,
+        0x00, 0x00, 0x00, 0x00, 0x9c, 0x00, 0x12, 0x00, 0x0b, 0x00, 0x01, 0x00,
+        0x65, 0x72, 0x73, 0x70, 0x61, 0x6e, 0x00, 0x00, 0x8c, 0x00, 0x02, 0x00,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x14, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x14, 0x06, 0x00, 0x02, 0x00, 0x20, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x20, 0x00, 0x00, 0x00, 0x08, 0x00, 0x06, 0x00,
+        0xc0, 0x00, 0x02, 0x01, 0x08, 0x00, 0x07, 0x00, 0xc0, 0x00, 0x02, 0x02,
+        0x05, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x16, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x05, 0x00, 0x17, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x18, 0x00, 0x07, 0x00, 0x00, 0x00, 0x06, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("ers2".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Erspan),
+                LinkInfo::Data(InfoData::Erspan(vec![
+                    InfoErspan::IKey(20),
+                    InfoErspan::OKey(20),
+                    InfoErspan::IFlags(GreFlags::Key),
+                    InfoErspan::OFlags(GreFlags::Key),
+                    InfoErspan::Local(Ipv4Addr::from_str("192.0.2.1").unwrap()),
+                    InfoErspan::Remote(
+                        Ipv4Addr::from_str("192.0.2.2").unwrap(),
+                    ),
+                    InfoErspan::PMtuDisc(true),
+                    InfoErspan::Tos(0),
+                    InfoErspan::Ttl(0),
+                    InfoErspan::FwMark(0),
+                    InfoErspan::Version(2),
+                    InfoErspan::Direction(ErspanDirection::Egress),
+                    InfoErspan::HardwareId(7),
+                    InfoErspan::Encap(TunnelEncap::Type(TunnelEncapType::None)),
+                    InfoErspan::Encap(TunnelEncap::Flags(
+                        TunnelEncapFlags::empty(),
+                    )),
+                    InfoErspan::Encap(TunnelEncap::SourcePort(0)),
+                    InfoErspan::Encap(TunnelEncap::DestinationPort(0)),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request sent by
+// iproute2 6.1 with IFLA_INFO_KIND changed to NULL terminated string(see
+// `test_crate_veth()`), command:
+//      ip link add ers6 type ip6erspan local 2001:db8::1 remote 2001:db8::2 \
+//          key 30 erspan_ver 1 erspan 200
+#[test]
+fn test_create_ip6erspan_v1() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x65, 0x72, 0x73, 0x36,
+        0x00, 0x00, 0x00, 0x00, 0xb8, 0x00, 0x12, 0x00, 0x0e, 0x00, 0x01, 0x00,
+        0x69, 0x70, 0x36, 0x65, 0x72, 0x73, 0x70, 0x61, 0x6e, 0x00, 0x00, 0x00,
+        0xa4, 0x00, 0x02, 0x00, 0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x1e,
+        0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x1e, 0x06, 0x00, 0x02, 0x00,
+        0x20, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x20, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x06, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x14, 0x00, 0x07, 0x00,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x05, 0x00, 0x08, 0x00, 0x40, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x0b, 0x00, 0x04, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x16, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x15, 0x00, 0xc8, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x0f, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x06, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("ers6".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Ip6Erspan),
+                LinkInfo::Data(InfoData::Ip6Erspan(vec![
+                    InfoIp6Erspan::IKey(30),
+                    InfoIp6Erspan::OKey(30),
+                    InfoIp6Erspan::IFlags(GreFlags::Key),
+                    InfoIp6Erspan::OFlags(GreFlags::Key),
+                    InfoIp6Erspan::Local(
+                        Ipv6Addr::from_str("2001:db8::1").unwrap(),
+                    ),
+                    InfoIp6Erspan::Remote(
+                        Ipv6Addr::from_str("2001:db8::2").unwrap(),
+                    ),
+                    InfoIp6Erspan::Ttl(64),
+                    InfoIp6Erspan::EncapLimit(4),
+                    InfoIp6Erspan::FlowInfo(0),
+                    InfoIp6Erspan::Flags(Ip6TunnelFlags::empty()),
+                    InfoIp6Erspan::FwMark(0),
+                    InfoIp6Erspan::Version(1),
+                    InfoIp6Erspan::Index(200),
+                    InfoIp6Erspan::Encap(TunnelEncap::Type(
+                        TunnelEncapType::None,
+                    )),
+                    InfoIp6Erspan::Encap(TunnelEncap::Flags(
+                        TunnelEncapFlags::Csum6,
+                    )),
+                    InfoIp6Erspan::Encap(TunnelEncap::SourcePort(0)),
+                    InfoIp6Erspan::Encap(TunnelEncap::DestinationPort(0)),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request sent by
+// iproute2 6.1 with IFLA_INFO_KIND changed to NULL terminated string(see
+// `test_crate_veth()`), command:
+//      ip link add ers6 type ip6erspan erspan_ver 2 external
+#[test]
+fn test_create_ip6erspan_external() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x65, 0x72, 0x73, 0x36,
+        0x00, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x12, 0x00, 0x0e, 0x00, 0x01, 0x00,
+        0x69, 0x70, 0x36, 0x65, 0x72, 0x73, 0x70, 0x61, 0x6e, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0x04, 0x00, 0x12, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("ers6".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Ip6Erspan),
+                LinkInfo::Data(InfoData::Ip6Erspan(vec![
+                    InfoIp6Erspan::CollectMetadata,
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
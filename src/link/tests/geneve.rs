@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::{
+    GeneveDf, InfoData, InfoGeneve, InfoKind, LinkAttribute, LinkInfo,
+    LinkMessage, LinkMessageBuffer,
+};
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request equivalent to
+// command:
+//      ip link add gnv0 type geneve id 10 remote 192.0.2.1 ttl 64 \
+//          df inherit dstport 6081 udpcsum
+#[test]
+fn test_create_geneve_ipv4_remote() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x67, 0x6e, 0x76, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x44, 0x00, 0x12, 0x00, 0x0b, 0x00, 0x01, 0x00,
+        0x67, 0x65, 0x6e, 0x65, 0x76, 0x65, 0x00, 0x00, 0x34, 0x00, 0x02, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0xc0, 0x00, 0x02, 0x01, 0x05, 0x00, 0x03, 0x00, 0x40, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x0d, 0x00, 0x02, 0x00, 0x00, 0x00, 0x06, 0x00, 0x05, 0x00,
+        0x17, 0xc1, 0x00, 0x00, 0x05, 0x00, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("gnv0".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Geneve),
+                LinkInfo::Data(InfoData::Geneve(vec![
+                    InfoGeneve::Id(10),
+                    InfoGeneve::Remote(
+                        Ipv4Addr::from_str("192.0.2.1").unwrap(),
+                    ),
+                    InfoGeneve::Ttl(64),
+                    InfoGeneve::Df(GeneveDf::Inherit),
+                    InfoGeneve::Port(6081),
+                    InfoGeneve::UDPCsum(true),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request equivalent to
+// command:
+//      ip link add gnv1 type geneve id 20 remote 2001:db8::1 \
+//          flowlabel 0x12345 ttl inherit dstport 6081 \
+//          udp6zerocsumtx udp6zerocsumrx
+// with source UDP port range 10000-20000 appended.
+#[test]
+fn test_create_geneve_ipv6_remote() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x67, 0x6e, 0x76, 0x31,
+        0x00, 0x00, 0x00, 0x00, 0x60, 0x00, 0x12, 0x00, 0x0b, 0x00, 0x01, 0x00,
+        0x67, 0x65, 0x6e, 0x65, 0x76, 0x65, 0x00, 0x00, 0x50, 0x00, 0x02, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x07, 0x00,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x0b, 0x00, 0x00, 0x01, 0x23, 0x45,
+        0x05, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x06, 0x00, 0x05, 0x00,
+        0x17, 0xc1, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0f, 0x00,
+        0x27, 0x10, 0x4e, 0x20,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("gnv1".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Geneve),
+                LinkInfo::Data(InfoData::Geneve(vec![
+                    InfoGeneve::Id(20),
+                    InfoGeneve::Remote6(
+                        Ipv6Addr::from_str("2001:db8::1").unwrap(),
+                    ),
+                    InfoGeneve::Label(0x12345),
+                    InfoGeneve::TtlInherit(true),
+                    InfoGeneve::Port(6081),
+                    InfoGeneve::UDPZeroCsumTX(true),
+                    InfoGeneve::UDPZeroCsumRX(true),
+                    InfoGeneve::PortRange((10000, 20000)),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request equivalent to
+// command:
+//      ip link add gnv2 type geneve external innerprotoinherit dstport 6081
+#[test]
+fn test_create_geneve_external() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x67, 0x6e, 0x76, 0x32,
+        0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x12, 0x00, 0x0b, 0x00, 0x01, 0x00,
+        0x67, 0x65, 0x6e, 0x65, 0x76, 0x65, 0x00, 0x00, 0x14, 0x00, 0x02, 0x00,
+        0x04, 0x00, 0x06, 0x00, 0x04, 0x00, 0x0e, 0x00, 0x06, 0x00, 0x05, 0x00,
+        0x17, 0xc1, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("gnv2".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Geneve),
+                LinkInfo::Data(InfoData::Geneve(vec![
+                    InfoGeneve::CollectMetadata,
+                    InfoGeneve::InnerProtoInherit,
+                    InfoGeneve::Port(6081),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::{
+    GreFlags, InfoData, InfoGreTun, InfoGreTun6, InfoKind, Ip6TunnelFlags,
+    LinkAttribute, LinkInfo, LinkMessage, LinkMessageBuffer, TunnelEncap,
+    TunnelEncapFlags, TunnelEncapType,
+};
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request holding only
+// the IFLA_GRE_ENCAP_* attributes generated by command below, the other
+// IFLA_GRE_* attributes iproute2 always sends are omitted:
+//      ip link add gre1 type gre encap gue encap-sport 4000 \
+//          encap-dport 6080 encap-csum encap-remcsum
+#[test]
+fn test_create_gre_encap_gue() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x67, 0x72, 0x65, 0x31,
+        0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x67, 0x72, 0x65, 0x00, 0x24, 0x00, 0x02, 0x00, 0x06, 0x00, 0x0e, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x06, 0x00, 0x0f, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x10, 0x00, 0x0f, 0xa0, 0x00, 0x00, 0x06, 0x00, 0x11, 0x00,
+        0x17, 0xc0, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("gre1".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::GreTun),
+                LinkInfo::Data(InfoData::GreTun(vec![
+                    InfoGreTun::Encap(TunnelEncap::Type(TunnelEncapType::Gue)),
+                    InfoGreTun::Encap(TunnelEncap::Flags(
+                        TunnelEncapFlags::Csum | TunnelEncapFlags::RemCsum,
+                    )),
+                    InfoGreTun::Encap(TunnelEncap::SourcePort(4000)),
+                    InfoGreTun::Encap(TunnelEncap::DestinationPort(6080)),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request sent by
+// iproute2 6.1 with IFLA_INFO_KIND changed to NULL terminated string(see
+// `test_crate_veth()`), command:
+//      ip link add gre1 type gre local 192.0.2.1 remote 192.0.2.2 \
+//          ikey 10 okey 20 nopmtudisc ignore-df
+#[test]
+fn test_create_gre() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x67, 0x72, 0x65, 0x31,
+        0x00, 0x00, 0x00, 0x00, 0x88, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x67, 0x72, 0x65, 0x00, 0x7c, 0x00, 0x02, 0x00, 0x08, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x0a, 0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x14,
+        0x06, 0x00, 0x02, 0x00, 0x20, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00,
+        0x20, 0x00, 0x00, 0x00, 0x08, 0x00, 0x06, 0x00, 0xc0, 0x00, 0x02, 0x01,
+        0x08, 0x00, 0x07, 0x00, 0xc0, 0x00, 0x02, 0x02, 0x05, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x13, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("gre1".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::GreTun),
+                LinkInfo::Data(InfoData::GreTun(vec![
+                    InfoGreTun::IKey(10),
+                    InfoGreTun::OKey(20),
+                    InfoGreTun::IFlags(GreFlags::Key),
+                    InfoGreTun::OFlags(GreFlags::Key),
+                    InfoGreTun::Local(Ipv4Addr::from_str("192.0.2.1").unwrap()),
+                    InfoGreTun::Remote(
+                        Ipv4Addr::from_str("192.0.2.2").unwrap(),
+                    ),
+                    InfoGreTun::PMtuDisc(false),
+                    InfoGreTun::IgnoreDf(true),
+                    InfoGreTun::Tos(0),
+                    InfoGreTun::Ttl(0),
+                    InfoGreTun::FwMark(0),
+                    InfoGreTun::Encap(TunnelEncap::Type(TunnelEncapType::None)),
+                    InfoGreTun::Encap(TunnelEncap::Flags(
+                        TunnelEncapFlags::empty(),
+                    )),
+                    InfoGreTun::Encap(TunnelEncap::SourcePort(0)),
+                    InfoGreTun::Encap(TunnelEncap::DestinationPort(0)),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request sent by
+// iproute2 6.1 with IFLA_INFO_KIND changed to NULL terminated string(see
+// `test_crate_veth()`), command:
+//      ip link add gre6 type ip6gre local 2001:db8::1 remote 2001:db8::2 \
+//          key 30 hoplimit 32 flowlabel 0x12345 tclass 0x10
+#[test]
+fn test_create_ip6gre() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x67, 0x72, 0x65, 0x36,
+        0x00, 0x00, 0x00, 0x00, 0xac, 0x00, 0x12, 0x00, 0x0b, 0x00, 0x01, 0x00,
+        0x69, 0x70, 0x36, 0x67, 0x72, 0x65, 0x00, 0x00, 0x9c, 0x00, 0x02, 0x00,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x1e, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x1e, 0x06, 0x00, 0x02, 0x00, 0x20, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x20, 0x00, 0x00, 0x00, 0x14, 0x00, 0x06, 0x00,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x14, 0x00, 0x07, 0x00, 0x20, 0x01, 0x0d, 0xb8,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+        0x05, 0x00, 0x08, 0x00, 0x20, 0x00, 0x00, 0x00, 0x05, 0x00, 0x0b, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0c, 0x00, 0x01, 0x01, 0x23, 0x45,
+        0x08, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x14, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x16, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x0f, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x06, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("gre6".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::GreTun6),
+                LinkInfo::Data(InfoData::GreTun6(vec![
+                    InfoGreTun6::IKey(30),
+                    InfoGreTun6::OKey(30),
+                    InfoGreTun6::IFlags(GreFlags::Key),
+                    InfoGreTun6::OFlags(GreFlags::Key),
+                    InfoGreTun6::Local(
+                        Ipv6Addr::from_str("2001:db8::1").unwrap(),
+                    ),
+                    InfoGreTun6::Remote(
+                        Ipv6Addr::from_str("2001:db8::2").unwrap(),
+                    ),
+                    InfoGreTun6::Ttl(32),
+                    InfoGreTun6::EncapLimit(4),
+                    InfoGreTun6::FlowInfo(0x01012345),
+                    InfoGreTun6::Flags(Ip6TunnelFlags::empty()),
+                    InfoGreTun6::FwMark(0),
+                    InfoGreTun6::Version(1),
+                    InfoGreTun6::Encap(TunnelEncap::Type(
+                        TunnelEncapType::None,
+                    )),
+                    InfoGreTun6::Encap(TunnelEncap::Flags(
+                        TunnelEncapFlags::Csum6,
+                    )),
+                    InfoGreTun6::Encap(TunnelEncap::SourcePort(0)),
+                    InfoGreTun6::Encap(TunnelEncap::DestinationPort(0)),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
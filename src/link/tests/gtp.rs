@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::{
+    GtpRole, InfoData, InfoGtp, InfoKind, LinkAttribute, LinkInfo, LinkMessage,
+    LinkMessageBuffer,
+};
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request creating
+// SGSN GTP device with kernel created sockets bound to 192.0.2.1, PDP hash
+// size 2048 and restart counter 5.
+#[test]
+fn test_create_gtp_kernel_sockets() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x67, 0x74, 0x70, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x38, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x67, 0x74, 0x70, 0x00, 0x2c, 0x00, 0x02, 0x00, 0x05, 0x00, 0x05, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x07, 0x00, 0xc0, 0x00, 0x02, 0x01, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x08, 0x00, 0x00, 0x05, 0x00, 0x06, 0x00, 0x05, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("gtp0".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Gtp),
+                LinkInfo::Data(InfoData::Gtp(vec![
+                    InfoGtp::CreateSockets(true),
+                    InfoGtp::Role(GtpRole::Sgsn),
+                    InfoGtp::Local(Ipv4Addr::from_str("192.0.2.1").unwrap()),
+                    InfoGtp::PdpHashsize(2048),
+                    InfoGtp::RestartCount(5),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request creating
+// GGSN GTP device with kernel created sockets bound to 2001:db8::1.
+#[test]
+fn test_create_gtp_kernel_sockets_ipv6() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x67, 0x74, 0x70, 0x31,
+        0x00, 0x00, 0x00, 0x00, 0x34, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x67, 0x74, 0x70, 0x00, 0x28, 0x00, 0x02, 0x00, 0x05, 0x00, 0x05, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x08, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("gtp1".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Gtp),
+                LinkInfo::Data(InfoData::Gtp(vec![
+                    InfoGtp::CreateSockets(true),
+                    InfoGtp::Role(GtpRole::Ggsn),
+                    InfoGtp::Local6(Ipv6Addr::from_str("2001:db8::1").unwrap()),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request creating
+// GGSN GTP device using user space created GTPv0 socket(fd 3) and GTPv1-U
+// socket(fd 4).
+#[test]
+fn test_create_gtp_user_sockets() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x67, 0x74, 0x70, 0x32,
+        0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x67, 0x74, 0x70, 0x00, 0x1c, 0x00, 0x02, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("gtp2".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Gtp),
+                LinkInfo::Data(InfoData::Gtp(vec![
+                    InfoGtp::Fd0(3),
+                    InfoGtp::Fd1(4),
+                    InfoGtp::Role(GtpRole::Ggsn),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::{
+    InfoData, InfoIp6Tunnel, InfoKind, Ip6TunnelFlags, LinkAttribute, LinkInfo,
+    LinkMessage, LinkMessageBuffer,
+};
+use crate::IpProtocol;
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request equivalent to
+// command:
+//      ip link add tun6 type ip6tnl mode ipip6 local 2001:db8::2 \
+//          remote 2001:db8::1 dev eth0 hoplimit 64 encaplimit 4 \
+//          flowlabel 0x12345 dsfield inherit fwmark 0
+#[test]
+fn test_create_ip4ip6() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x74, 0x75, 0x6e, 0x36,
+        0x00, 0x00, 0x00, 0x00, 0x74, 0x00, 0x12, 0x00, 0x0b, 0x00, 0x01, 0x00,
+        0x69, 0x70, 0x36, 0x74, 0x6e, 0x6c, 0x00, 0x00, 0x64, 0x00, 0x02, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x14, 0x00, 0x02, 0x00,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x14, 0x00, 0x03, 0x00, 0x20, 0x01, 0x0d, 0xb8,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x05, 0x00, 0x04, 0x00, 0x40, 0x00, 0x00, 0x00, 0x05, 0x00, 0x06, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x08, 0x00, 0x07, 0x00, 0x00, 0x01, 0x23, 0x45,
+        0x08, 0x00, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x08, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("tun6".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Ip6Tun),
+                LinkInfo::Data(InfoData::Ip6Tun(vec![
+                    InfoIp6Tunnel::Link(2),
+                    InfoIp6Tunnel::Local(
+                        Ipv6Addr::from_str("2001:db8::2").unwrap(),
+                    ),
+                    InfoIp6Tunnel::Remote(
+                        Ipv6Addr::from_str("2001:db8::1").unwrap(),
+                    ),
+                    InfoIp6Tunnel::Ttl(64),
+                    InfoIp6Tunnel::EncapLimit(4),
+                    InfoIp6Tunnel::FlowInfo(0x12345),
+                    InfoIp6Tunnel::Flags(Ip6TunnelFlags::UseOrigTclass),
+                    InfoIp6Tunnel::Protocol(IpProtocol::Ipip),
+                    InfoIp6Tunnel::FwMark(0),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::{
+    InfoData, InfoIpTunnel, InfoKind, LinkAttribute, LinkInfo, LinkMessage,
+    LinkMessageBuffer, TunnelEncap, TunnelEncapFlags, TunnelEncapType,
+};
+use crate::IpProtocol;
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request equivalent to
+// command:
+//      ip link add tun4 type ipip local 192.0.2.2 remote 192.0.2.1 dev eth0 \
+//          ttl 64 tos 0 pmtudisc fwmark 0x10
+#[test]
+fn test_create_ipip() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x74, 0x75, 0x6e, 0x34,
+        0x00, 0x00, 0x00, 0x00, 0x54, 0x00, 0x12, 0x00, 0x09, 0x00, 0x01, 0x00,
+        0x69, 0x70, 0x69, 0x70, 0x00, 0x00, 0x00, 0x00, 0x44, 0x00, 0x02, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0xc0, 0x00, 0x02, 0x02, 0x08, 0x00, 0x03, 0x00, 0xc0, 0x00, 0x02, 0x01,
+        0x05, 0x00, 0x04, 0x00, 0x40, 0x00, 0x00, 0x00, 0x05, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x14, 0x00,
+        0x10, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("tun4".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::IpTun),
+                LinkInfo::Data(InfoData::IpTun(vec![
+                    InfoIpTunnel::Link(2),
+                    InfoIpTunnel::Local(
+                        Ipv4Addr::from_str("192.0.2.2").unwrap(),
+                    ),
+                    InfoIpTunnel::Remote(
+                        Ipv4Addr::from_str("192.0.2.1").unwrap(),
+                    ),
+                    InfoIpTunnel::Ttl(64),
+                    InfoIpTunnel::Tos(0),
+                    InfoIpTunnel::Protocol(IpProtocol::Ipip),
+                    InfoIpTunnel::PMtuDisc(true),
+                    InfoIpTunnel::FwMark(0x10),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request equivalent to
+// command:
+//      ip link add tun4e type ipip external
+#[test]
+fn test_create_ipip_external() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x03, 0x00, 0x74, 0x75, 0x6e, 0x34,
+        0x65, 0x00, 0x00, 0x00, 0x18, 0x00, 0x12, 0x00, 0x09, 0x00, 0x01, 0x00,
+        0x69, 0x70, 0x69, 0x70, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0x04, 0x00, 0x13, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("tun4e".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::IpTun),
+                LinkInfo::Data(InfoData::IpTun(vec![
+                    InfoIpTunnel::CollectMetadata,
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request holding only
+// the IFLA_IPTUN_LOCAL, IFLA_IPTUN_REMOTE and IFLA_IPTUN_ENCAP_* attributes
+// generated by command below, the other IFLA_IPTUN_* attributes iproute2
+// always sends are omitted:
+//      ip link add tun4f type ipip local 192.0.2.2 remote 192.0.2.1 \
+//          encap fou encap-sport auto encap-dport 5555 encap-csum
+#[test]
+fn test_create_ipip_encap_fou() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x03, 0x00, 0x74, 0x75, 0x6e, 0x34,
+        0x66, 0x00, 0x00, 0x00, 0x44, 0x00, 0x12, 0x00, 0x09, 0x00, 0x01, 0x00,
+        0x69, 0x70, 0x69, 0x70, 0x00, 0x00, 0x00, 0x00, 0x34, 0x00, 0x02, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0xc0, 0x00, 0x02, 0x02, 0x08, 0x00, 0x03, 0x00,
+        0xc0, 0x00, 0x02, 0x01, 0x06, 0x00, 0x0f, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x10, 0x00, 0x01, 0x00, 0x00, 0x00, 0x06, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x12, 0x00, 0x15, 0xb3, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("tun4f".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::IpTun),
+                LinkInfo::Data(InfoData::IpTun(vec![
+                    InfoIpTunnel::Local(
+                        Ipv4Addr::from_str("192.0.2.2").unwrap(),
+                    ),
+                    InfoIpTunnel::Remote(
+                        Ipv4Addr::from_str("192.0.2.1").unwrap(),
+                    ),
+                    InfoIpTunnel::Encap(TunnelEncap::Type(
+                        TunnelEncapType::Fou,
+                    )),
+                    InfoIpTunnel::Encap(TunnelEncap::Flags(
+                        TunnelEncapFlags::Csum,
+                    )),
+                    InfoIpTunnel::Encap(TunnelEncap::SourcePort(0)),
+                    InfoIpTunnel::Encap(TunnelEncap::DestinationPort(5555)),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -1,12 +1,30 @@
 // SPDX-License-Identifier: MIT
 
+#[cfg(test)]
+mod amt;
+#[cfg(test)]
+mod bareudp;
 #[cfg(test)]
 mod bond;
 #[cfg(test)]
 mod bridge;
 #[cfg(test)]
+mod can;
+#[cfg(test)]
+mod erspan;
+#[cfg(test)]
+mod geneve;
+#[cfg(test)]
+mod gre;
+#[cfg(test)]
+mod gtp;
+#[cfg(test)]
 mod hsr;
 #[cfg(test)]
+mod ip6tnl;
+#[cfg(test)]
+mod ipip;
+#[cfg(test)]
 mod ipvlan;
 #[cfg(test)]
 mod ipvtap;
@@ -21,12 +39,18 @@ mod macvtap;
 #[cfg(test)]
 mod message;
 #[cfg(test)]
+mod netkit;
+#[cfg(test)]
 mod prop_list;
 #[cfg(test)]
+mod rmnet;
+#[cfg(test)]
 mod sriov;
 #[cfg(test)]
 mod statistics;
 #[cfg(test)]
+mod tun;
+#[cfg(test)]
 mod veth;
 #[cfg(test)]
 mod vlan;
@@ -35,6 +59,8 @@ mod vrf;
 #[cfg(test)]
 mod vxlan;
 #[cfg(test)]
+mod wwan;
+#[cfg(test)]
 mod xdp;
 #[cfg(test)]
 mod xfrm;
@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    InfoData, InfoKind, InfoNetkit, LinkAttribute, LinkHeader, LinkInfo,
+    LinkLayerType, LinkMessage, LinkMessageBuffer, NetkitMode, NetkitPolicy,
+    NetkitScrub,
+};
+use crate::AddressFamily;
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request equivalent to
+// command:
+//      ip link add nk0 type netkit mode l2 forward scrub default \
+//          peer blackhole scrub none name nk1
+#[test]
+fn test_create_netkit() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x6e, 0x6b, 0x30, 0x00,
+        0x58, 0x00, 0x12, 0x00, 0x0b, 0x00, 0x01, 0x00, 0x6e, 0x65, 0x74, 0x6b,
+        0x69, 0x74, 0x00, 0x00, 0x48, 0x00, 0x02, 0x00, 0x1c, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x6e, 0x6b, 0x31, 0x00,
+        0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("nk0".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Netkit),
+                LinkInfo::Data(InfoData::Netkit(vec![
+                    InfoNetkit::Peer(LinkMessage {
+                        attributes: vec![LinkAttribute::IfName(
+                            "nk1".to_string(),
+                        )],
+                        ..Default::default()
+                    }),
+                    InfoNetkit::Mode(NetkitMode::L2),
+                    InfoNetkit::Policy(NetkitPolicy::Pass),
+                    InfoNetkit::PeerPolicy(NetkitPolicy::Drop),
+                    InfoNetkit::Scrub(NetkitScrub::Default),
+                    InfoNetkit::PeerScrub(NetkitScrub::None),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` reply of
+// `ip -d link show nk0` for the primary device created above, with attributes
+// other than `IFLA_IFNAME` and `IFLA_LINKINFO` removed.
+#[test]
+fn test_get_netkit_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x01, 0x00, 0x04, 0x00, 0x00, 0x00, 0x02, 0x10, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x6e, 0x6b, 0x30, 0x00,
+        0x54, 0x00, 0x12, 0x00, 0x0b, 0x00, 0x01, 0x00, 0x6e, 0x65, 0x74, 0x6b,
+        0x69, 0x74, 0x00, 0x00, 0x44, 0x00, 0x02, 0x00, 0x05, 0x00, 0x02, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x04, 0x00, 0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 4,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::Broadcast | LinkFlags::Multicast,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![
+            LinkAttribute::IfName("nk0".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Netkit),
+                LinkInfo::Data(InfoData::Netkit(vec![
+                    InfoNetkit::Primary(true),
+                    InfoNetkit::Policy(NetkitPolicy::Pass),
+                    InfoNetkit::PeerPolicy(NetkitPolicy::Drop),
+                    InfoNetkit::Mode(NetkitMode::L2),
+                    InfoNetkit::Scrub(NetkitScrub::Default),
+                    InfoNetkit::PeerScrub(NetkitScrub::None),
+                    InfoNetkit::Headroom(0),
+                    InfoNetkit::Tailroom(0),
+                ])),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    InfoData, InfoKind, InfoRmnet, LinkAttribute, LinkHeader, LinkInfo,
+    LinkLayerType, LinkMessage, LinkMessageBuffer, RmnetDataFormat, RmnetFlags,
+};
+use crate::AddressFamily;
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request equivalent to
+// command:
+//      ip link add link wwan0 name rmnet0 type rmnet mux_id 1 \
+//          ingress-deaggregation on ingress-chksumv5 on egress-chksumv5 on
+// with wwan0 as interface index 2.
+#[test]
+fn test_create_rmnet() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x03, 0x00, 0x72, 0x6d, 0x6e, 0x65,
+        0x74, 0x30, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x28, 0x00, 0x12, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x72, 0x6d, 0x6e, 0x65,
+        0x74, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x00, 0x06, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x00, 0x31, 0x00, 0x00, 0x00,
+        0x31, 0x00, 0x00, 0x00,
+    ];
+
+    let flags = RmnetFlags::IngressDeaggregation
+        | RmnetFlags::IngressMapCksumV5
+        | RmnetFlags::EgressMapCksumV5;
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("rmnet0".to_string()),
+            LinkAttribute::Link(2),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Rmnet),
+                LinkInfo::Data(InfoData::Rmnet(vec![
+                    InfoRmnet::MuxId(1),
+                    InfoRmnet::Flags(RmnetDataFormat { flags, mask: flags }),
+                ])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` reply of
+// `ip -d link show rmnet0` for interface created by
+// `ip link add link wwan0 name rmnet0 type rmnet mux_id 1`, with attributes
+// other than `IFLA_IFNAME` and `IFLA_LINKINFO` removed.
+#[test]
+fn test_get_rmnet_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x07, 0x02, 0x03, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x03, 0x00, 0x72, 0x6d, 0x6e, 0x65,
+        0x74, 0x30, 0x00, 0x00, 0x28, 0x00, 0x12, 0x00, 0x0a, 0x00, 0x01, 0x00,
+        0x72, 0x6d, 0x6e, 0x65, 0x74, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x00,
+        0x06, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 3,
+            link_layer_type: LinkLayerType::Rawip,
+            flags: LinkFlags::Noarp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![
+            LinkAttribute::IfName("rmnet0".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Rmnet),
+                LinkInfo::Data(InfoData::Rmnet(vec![
+                    InfoRmnet::MuxId(1),
+                    InfoRmnet::Flags(RmnetDataFormat {
+                        flags: RmnetFlags::IngressDeaggregation,
+                        mask: RmnetFlags::all(),
+                    }),
+                ])),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    InfoData, InfoKind, InfoTun, LinkAttribute, LinkHeader, LinkInfo,
+    LinkLayerType, LinkMessage, LinkMessageBuffer, TunType,
+};
+use crate::AddressFamily;
+
+// Setup:
+//      ip tuntap add tap0 mode tap user 0 group 0 vnet_hdr
+// wireshark capture(netlink message header removed) of nlmon against command
+// `ip -d link show tap0` with attributes other than `IFLA_IFNAME` and
+// `IFLA_LINKINFO` removed.
+#[test]
+fn test_tap_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x10, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x74, 0x61, 0x70, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x48, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x74, 0x75, 0x6e, 0x00, 0x3c, 0x00, 0x02, 0x00, 0x05, 0x00, 0x03, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x05, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 2,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::Broadcast | LinkFlags::Multicast,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![
+            LinkAttribute::IfName("tap0".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Tun),
+                LinkInfo::Data(InfoData::Tun(vec![
+                    InfoTun::Type(TunType::Tap),
+                    InfoTun::Owner(0),
+                    InfoTun::Group(0),
+                    InfoTun::Pi(false),
+                    InfoTun::VnetHdr(true),
+                    InfoTun::Persist(true),
+                    InfoTun::MultiQueue(false),
+                ])),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip tuntap add tun0 mode tun multi_queue pi
+// wireshark capture(netlink message header removed) of nlmon against command
+// `ip -d link show tun0` with attributes other than `IFLA_IFNAME` and
+// `IFLA_LINKINFO` removed.
+#[test]
+fn test_multi_queue_tun_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0xfe, 0xff, 0x03, 0x00, 0x00, 0x00, 0x90, 0x10, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x74, 0x75, 0x6e, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x48, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x74, 0x75, 0x6e, 0x00, 0x3c, 0x00, 0x02, 0x00, 0x05, 0x00, 0x03, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x06, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x07, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 3,
+            link_layer_type: LinkLayerType::None,
+            flags: LinkFlags::Pointopoint
+                | LinkFlags::Noarp
+                | LinkFlags::Multicast,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![
+            LinkAttribute::IfName("tun0".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Tun),
+                LinkInfo::Data(InfoData::Tun(vec![
+                    InfoTun::Type(TunType::Tun),
+                    InfoTun::Pi(true),
+                    InfoTun::VnetHdr(false),
+                    InfoTun::Persist(true),
+                    InfoTun::MultiQueue(true),
+                    InfoTun::NumQueues(0),
+                    InfoTun::NumDisabledQueues(0),
+                ])),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
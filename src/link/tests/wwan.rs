@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::{
+    InfoData, InfoKind, InfoWwan, LinkAttribute, LinkInfo, LinkMessage,
+    LinkMessageBuffer,
+};
+
+// Raw netlink message(header removed) of `RTM_NEWLINK` request equivalent to
+// command:
+//      ip link add wwan0mux1 type wwan linkid 1
+#[test]
+fn test_create_wwan() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x03, 0x00, 0x77, 0x77, 0x61, 0x6e,
+        0x30, 0x6d, 0x75, 0x78, 0x31, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x12, 0x00,
+        0x09, 0x00, 0x01, 0x00, 0x77, 0x77, 0x61, 0x6e, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x02, 0x00, 0x08, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        attributes: vec![
+            LinkAttribute::IfName("wwan0mux1".to_string()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Wwan),
+                LinkInfo::Data(InfoData::Wwan(vec![InfoWwan::LinkId(1)])),
+            ]),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,328 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32, parse_u8},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use super::{
+    entry::VecMdbEntry, router::VecMdbRouterPort, MdbEntry, MdbEntryInfo,
+    MdbEntryInfoBuffer, MdbEntryState, MdbFilterMode, MdbRouterPort,
+};
+use crate::{
+    ip::{emit_ip_addr, ip_addr_len, parse_ip_addr},
+    route::RouteProtocol,
+};
+
+const MDBA_MDB: u16 = 1;
+const MDBA_ROUTER: u16 = 2;
+pub(crate) const MDBA_SET_ENTRY: u16 = 1;
+const MDBA_SET_ENTRY_ATTRS: u16 = 2;
+
+/// Netlink attributes of `RTM_NEWMDB`, `RTM_DELMDB` and `RTM_GETMDB`
+/// messages.
+///
+/// The kernel reuses the same attribute types for dumps(`MDBA_MDB`,
+/// `MDBA_ROUTER`) and for requests modifying or querying a single entry
+/// (`MDBA_SET_ENTRY`, `MDBA_SET_ENTRY_ATTRS`), they are told apart by the
+/// payload of type 1 attribute.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum MdbAttribute {
+    Mdb(Vec<MdbEntry>),
+    Router(Vec<MdbRouterPort>),
+    /// Entry to add, delete or get. Only valid in `RTM_NEWMDB`, `RTM_DELMDB`
+    /// and `RTM_GETMDB` requests, also known as `MDBA_GET_ENTRY`.
+    SetEntry(MdbEntryInfo),
+    /// Extra attributes of [MdbAttribute::SetEntry], also known as
+    /// `MDBA_GET_ENTRY_ATTRS`.
+    SetEntryAttributes(Vec<MdbSetEntryAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Mdb(v) => v.as_slice().buffer_len(),
+            Self::Router(v) => v.as_slice().buffer_len(),
+            Self::SetEntry(v) => v.buffer_len(),
+            Self::SetEntryAttributes(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Mdb(v) => v.as_slice().emit(buffer),
+            Self::Router(v) => v.as_slice().emit(buffer),
+            Self::SetEntry(v) => v.emit(buffer),
+            Self::SetEntryAttributes(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Mdb(_) => MDBA_MDB,
+            Self::Router(_) => MDBA_ROUTER,
+            Self::SetEntry(_) => MDBA_SET_ENTRY,
+            Self::SetEntryAttributes(_) => MDBA_SET_ENTRY_ATTRS | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+/// The `has_set_entry` indicates whether the message holds a
+/// `MDBA_SET_ENTRY` attribute, which is `struct br_mdb_entry` with fixed
+/// length.
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, bool>
+    for MdbAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        has_set_entry: bool,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_SET_ENTRY if has_set_entry => Self::SetEntry(
+                MdbEntryInfo::parse(
+                    &MdbEntryInfoBuffer::new_checked(payload)
+                        .context("invalid MDBA_SET_ENTRY value")?,
+                )
+                .context("invalid MDBA_SET_ENTRY value")?,
+            ),
+            MDBA_SET_ENTRY_ATTRS if has_set_entry => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        nla.context("invalid MDBA_SET_ENTRY_ATTRS value")?;
+                    nlas.push(MdbSetEntryAttribute::parse(&nla)?);
+                }
+                Self::SetEntryAttributes(nlas)
+            }
+            MDBA_MDB => Self::Mdb(
+                VecMdbEntry::parse(buf).context("invalid MDBA_MDB value")?.0,
+            ),
+            MDBA_ROUTER => Self::Router(
+                VecMdbRouterPort::parse(buf)
+                    .context("invalid MDBA_ROUTER value")?
+                    .0,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+const MDBE_ATTR_SOURCE: u16 = 1;
+const MDBE_ATTR_SRC_LIST: u16 = 2;
+const MDBE_ATTR_GROUP_MODE: u16 = 3;
+const MDBE_ATTR_RTPROT: u16 = 4;
+const MDBE_ATTR_DST: u16 = 5;
+const MDBE_ATTR_DST_PORT: u16 = 6;
+const MDBE_ATTR_VNI: u16 = 7;
+const MDBE_ATTR_IFINDEX: u16 = 8;
+const MDBE_ATTR_SRC_VNI: u16 = 9;
+const MDBE_ATTR_STATE_MASK: u16 = 10;
+
+/// Nested attributes of `MDBA_SET_ENTRY_ATTRS`
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum MdbSetEntryAttribute {
+    /// Source address of (S, G) entry
+    Source(IpAddr),
+    /// Source list of (*, G) entry
+    SourceList(Vec<IpAddr>),
+    GroupMode(MdbFilterMode),
+    Protocol(RouteProtocol),
+    /// Remote VTEP address of VXLAN MDB entry
+    Destination(IpAddr),
+    /// Remote UDP port of VXLAN MDB entry
+    DestinationPort(u16),
+    /// VNI of VXLAN MDB entry
+    Vni(u32),
+    /// Outgoing interface index of VXLAN MDB entry
+    Ifindex(u32),
+    /// Source VNI of VXLAN MDB entry
+    SourceVni(u32),
+    /// Only match entries in specified state, only valid in `RTM_DELMDB`
+    /// bulk delete request.
+    StateMask(MdbEntryState),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbSetEntryAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::GroupMode(_) | Self::Protocol(_) | Self::StateMask(_) => 1,
+            Self::DestinationPort(_) => 2,
+            Self::Vni(_) | Self::Ifindex(_) | Self::SourceVni(_) => 4,
+            Self::Source(v) | Self::Destination(v) => ip_addr_len(v),
+            Self::SourceList(v) => {
+                v.iter().map(|a| MdbSetSourceEntry(*a).buffer_len()).sum()
+            }
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::GroupMode(v) => buffer[0] = (*v).into(),
+            Self::Protocol(v) => buffer[0] = (*v).into(),
+            Self::StateMask(v) => buffer[0] = (*v).into(),
+            Self::DestinationPort(v) => NativeEndian::write_u16(buffer, *v),
+            Self::Vni(v) | Self::Ifindex(v) | Self::SourceVni(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Source(v) | Self::Destination(v) => emit_ip_addr(v, buffer),
+            Self::SourceList(v) => v
+                .iter()
+                .map(|a| MdbSetSourceEntry(*a))
+                .collect::<Vec<_>>()
+                .as_slice()
+                .emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Source(_) => MDBE_ATTR_SOURCE,
+            Self::SourceList(_) => MDBE_ATTR_SRC_LIST | NLA_F_NESTED,
+            Self::GroupMode(_) => MDBE_ATTR_GROUP_MODE,
+            Self::Protocol(_) => MDBE_ATTR_RTPROT,
+            Self::Destination(_) => MDBE_ATTR_DST,
+            Self::DestinationPort(_) => MDBE_ATTR_DST_PORT,
+            Self::Vni(_) => MDBE_ATTR_VNI,
+            Self::Ifindex(_) => MDBE_ATTR_IFINDEX,
+            Self::SourceVni(_) => MDBE_ATTR_SRC_VNI,
+            Self::StateMask(_) => MDBE_ATTR_STATE_MASK,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbSetEntryAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBE_ATTR_SOURCE => Self::Source(
+                parse_ip_addr(payload)
+                    .context("invalid MDBE_ATTR_SOURCE value")?,
+            ),
+            MDBE_ATTR_SRC_LIST => {
+                let mut addresses = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        nla.context("invalid MDBE_ATTR_SRC_LIST value")?;
+                    addresses.push(MdbSetSourceEntry::parse(&nla)?.0);
+                }
+                Self::SourceList(addresses)
+            }
+            MDBE_ATTR_GROUP_MODE => Self::GroupMode(
+                parse_u8(payload)
+                    .context("invalid MDBE_ATTR_GROUP_MODE value")?
+                    .into(),
+            ),
+            MDBE_ATTR_RTPROT => Self::Protocol(
+                parse_u8(payload)
+                    .context("invalid MDBE_ATTR_RTPROT value")?
+                    .into(),
+            ),
+            MDBE_ATTR_DST => Self::Destination(
+                parse_ip_addr(payload)
+                    .context("invalid MDBE_ATTR_DST value")?,
+            ),
+            MDBE_ATTR_DST_PORT => Self::DestinationPort(
+                parse_u16(payload)
+                    .context("invalid MDBE_ATTR_DST_PORT value")?,
+            ),
+            MDBE_ATTR_VNI => Self::Vni(
+                parse_u32(payload).context("invalid MDBE_ATTR_VNI value")?,
+            ),
+            MDBE_ATTR_IFINDEX => Self::Ifindex(
+                parse_u32(payload)
+                    .context("invalid MDBE_ATTR_IFINDEX value")?,
+            ),
+            MDBE_ATTR_SRC_VNI => Self::SourceVni(
+                parse_u32(payload)
+                    .context("invalid MDBE_ATTR_SRC_VNI value")?,
+            ),
+            MDBE_ATTR_STATE_MASK => Self::StateMask(
+                parse_u8(payload)
+                    .context("invalid MDBE_ATTR_STATE_MASK value")?
+                    .into(),
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+const MDBE_SRC_LIST_ENTRY: u16 = 1;
+const MDBE_SRCATTR_ADDRESS: u16 = 1;
+
+// The `MDBE_SRC_LIST_ENTRY` holding a single `MDBE_SRCATTR_ADDRESS`
+struct MdbSetSourceEntry(IpAddr);
+
+struct MdbSetSourceAddress(IpAddr);
+
+impl Nla for MdbSetSourceAddress {
+    fn value_len(&self) -> usize {
+        ip_addr_len(&self.0)
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        emit_ip_addr(&self.0, buffer)
+    }
+
+    fn kind(&self) -> u16 {
+        MDBE_SRCATTR_ADDRESS
+    }
+}
+
+impl Nla for MdbSetSourceEntry {
+    fn value_len(&self) -> usize {
+        MdbSetSourceAddress(self.0).buffer_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        MdbSetSourceAddress(self.0).emit(buffer)
+    }
+
+    fn kind(&self) -> u16 {
+        MDBE_SRC_LIST_ENTRY | NLA_F_NESTED
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbSetSourceEntry
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        for nla in NlasIterator::new(buf.value()) {
+            let nla = nla.context("invalid MDBE_SRC_LIST_ENTRY value")?;
+            if nla.kind() == MDBE_SRCATTR_ADDRESS {
+                return Ok(Self(
+                    parse_ip_addr(nla.value())
+                        .context("invalid MDBE_SRCATTR_ADDRESS value")?,
+                ));
+            }
+        }
+        Err(DecodeError::from(format!(
+            "MDBE_SRCATTR_ADDRESS not found in MDBE_SRC_LIST_ENTRY {:?}",
+            buf.value()
+        )))
+    }
+}
@@ -0,0 +1,318 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder};
+use netlink_packet_utils::{
+    nla::{Nla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::MdbEntryAttribute;
+use crate::ip::{IPV4_ADDR_LEN, IPV6_ADDR_LEN};
+
+const MDBA_MDB_ENTRY: u16 = 1;
+const MDBA_MDB_ENTRY_INFO: u16 = 1;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct VecMdbEntry(pub(crate) Vec<MdbEntry>);
+
+// Whether specified payload is stream of nested `MDBA_MDB_ENTRY` attributes.
+pub(crate) fn is_mdb_entries(payload: &[u8]) -> bool {
+    NlasIterator::new(payload).all(|nla| {
+        nla.map(|nla| nla.kind() == MDBA_MDB_ENTRY)
+            .unwrap_or_default()
+    })
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecMdbEntry {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut entries = vec![];
+        for nla in NlasIterator::new(buf.value()) {
+            let nla = &nla.context(format!(
+                "invalid MDBA_MDB value: {:?}",
+                buf.value()
+            ))?;
+            if nla.kind() == MDBA_MDB_ENTRY {
+                entries.push(MdbEntry::parse(nla)?);
+            } else {
+                log::warn!(
+                    "BUG: Expecting MDBA_MDB_ENTRY in MDBA_MDB, but got {}",
+                    nla.kind()
+                );
+            }
+        }
+        Ok(Self(entries))
+    }
+}
+
+/// Multicast group entry(`MDBA_MDB_ENTRY`) holding the ports joined the
+/// group.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct MdbEntry(pub Vec<MdbPortEntry>);
+
+impl Nla for MdbEntry {
+    fn value_len(&self) -> usize {
+        self.0.as_slice().buffer_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.0.as_slice().emit(buffer)
+    }
+
+    fn kind(&self) -> u16 {
+        MDBA_MDB_ENTRY
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbEntry {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut entries = vec![];
+        for nla in NlasIterator::new(buf.value()) {
+            let nla = &nla.context(format!(
+                "invalid MDBA_MDB_ENTRY value: {:?}",
+                buf.value()
+            ))?;
+            if nla.kind() == MDBA_MDB_ENTRY_INFO {
+                entries.push(MdbPortEntry::parse(nla)?);
+            } else {
+                log::warn!(
+                    "BUG: Expecting MDBA_MDB_ENTRY_INFO in MDBA_MDB_ENTRY, \
+                    but got {}",
+                    nla.kind()
+                );
+            }
+        }
+        Ok(Self(entries))
+    }
+}
+
+/// Port membership of multicast group(`MDBA_MDB_ENTRY_INFO`), the
+/// `struct br_mdb_entry` followed by `MDBA_MDB_EATTR_*` attributes.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct MdbPortEntry {
+    pub info: MdbEntryInfo,
+    pub attributes: Vec<MdbEntryAttribute>,
+}
+
+impl Nla for MdbPortEntry {
+    fn value_len(&self) -> usize {
+        self.info.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.info.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.info.buffer_len()..]);
+    }
+
+    fn kind(&self) -> u16 {
+        MDBA_MDB_ENTRY_INFO
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbPortEntry {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        let info = MdbEntryInfo::parse(
+            &MdbEntryInfoBuffer::new_checked(payload)
+                .context(format!("invalid MDBA_MDB_ENTRY_INFO {payload:?}"))?,
+        )?;
+        let mut attributes = vec![];
+        for nla in NlasIterator::new(&payload[MDB_ENTRY_INFO_LEN..]) {
+            let nla = &nla.context(format!(
+                "invalid MDBA_MDB_ENTRY_INFO attributes {payload:?}"
+            ))?;
+            attributes.push(MdbEntryAttribute::parse(nla)?);
+        }
+        Ok(Self { info, attributes })
+    }
+}
+
+const MDB_TEMPORARY: u8 = 0;
+const MDB_PERMANENT: u8 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum MdbEntryState {
+    /// Entry learned by IGMP/MLD snooping or added as `temp`, will expire
+    #[default]
+    Temporary,
+    Permanent,
+    Other(u8),
+}
+
+impl From<u8> for MdbEntryState {
+    fn from(d: u8) -> Self {
+        match d {
+            MDB_TEMPORARY => Self::Temporary,
+            MDB_PERMANENT => Self::Permanent,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<MdbEntryState> for u8 {
+    fn from(v: MdbEntryState) -> u8 {
+        match v {
+            MdbEntryState::Temporary => MDB_TEMPORARY,
+            MdbEntryState::Permanent => MDB_PERMANENT,
+            MdbEntryState::Other(d) => d,
+        }
+    }
+}
+
+const MDB_FLAGS_OFFLOAD: u8 = 1 << 0;
+const MDB_FLAGS_FAST_LEAVE: u8 = 1 << 1;
+const MDB_FLAGS_STAR_EXCL: u8 = 1 << 2;
+const MDB_FLAGS_BLOCKED: u8 = 1 << 3;
+const MDB_FLAGS_OFFLOAD_FAILED: u8 = 1 << 4;
+
+bitflags! {
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[non_exhaustive]
+    pub struct MdbEntryFlags: u8 {
+        const Offload = MDB_FLAGS_OFFLOAD;
+        const FastLeave = MDB_FLAGS_FAST_LEAVE;
+        const StarExcl = MDB_FLAGS_STAR_EXCL;
+        const Blocked = MDB_FLAGS_BLOCKED;
+        const OffloadFailed = MDB_FLAGS_OFFLOAD_FAILED;
+        const _ = !0;
+    }
+}
+
+const ETH_P_IP: u16 = 0x0800;
+const ETH_P_IPV6: u16 = 0x86DD;
+const ETH_ALEN: usize = 6;
+const MDB_ADDR_LEN: usize = 16;
+
+/// Multicast group address of `struct br_mdb_entry`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum MdbAddress {
+    Inet(Ipv4Addr),
+    Inet6(Ipv6Addr),
+    /// Layer 2 multicast group address
+    Mac([u8; ETH_ALEN]),
+    Other {
+        /// Ethernet protocol in host byte order
+        protocol: u16,
+        address: [u8; MDB_ADDR_LEN],
+    },
+}
+
+impl Default for MdbAddress {
+    fn default() -> Self {
+        Self::Inet(Ipv4Addr::UNSPECIFIED)
+    }
+}
+
+impl MdbAddress {
+    fn protocol(&self) -> u16 {
+        match self {
+            Self::Inet(_) => ETH_P_IP,
+            Self::Inet6(_) => ETH_P_IPV6,
+            Self::Mac(_) => 0,
+            Self::Other { protocol, .. } => *protocol,
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Inet(v) => {
+                buffer[..IPV4_ADDR_LEN].copy_from_slice(&v.octets())
+            }
+            Self::Inet6(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Mac(v) => buffer[..ETH_ALEN].copy_from_slice(v),
+            Self::Other { address, .. } => buffer.copy_from_slice(address),
+        }
+    }
+
+    fn parse(protocol: u16, raw: &[u8]) -> Self {
+        match protocol {
+            ETH_P_IP => {
+                Self::Inet(Ipv4Addr::new(raw[0], raw[1], raw[2], raw[3]))
+            }
+            ETH_P_IPV6 => {
+                let mut data = [0u8; IPV6_ADDR_LEN];
+                data.copy_from_slice(raw);
+                Self::Inet6(Ipv6Addr::from(data))
+            }
+            0 => {
+                let mut data = [0u8; ETH_ALEN];
+                data.copy_from_slice(&raw[..ETH_ALEN]);
+                Self::Mac(data)
+            }
+            _ => {
+                let mut address = [0u8; MDB_ADDR_LEN];
+                address.copy_from_slice(raw);
+                Self::Other { protocol, address }
+            }
+        }
+    }
+}
+
+pub(crate) const MDB_ENTRY_INFO_LEN: usize = 28;
+
+buffer!(MdbEntryInfoBuffer(MDB_ENTRY_INFO_LEN) {
+    ifindex: (u32, 0..4),
+    state: (u8, 4),
+    flags: (u8, 5),
+    vid: (u16, 6..8),
+    address: (slice, 8..24),
+    protocol: (slice, 24..26),
+    reserved: (u16, 26..MDB_ENTRY_INFO_LEN),
+});
+
+/// Multicast database entry(`struct br_mdb_entry`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct MdbEntryInfo {
+    /// Interface index of the bridge port, or the bridge itself for host
+    /// joined group
+    pub ifindex: u32,
+    pub state: MdbEntryState,
+    pub flags: MdbEntryFlags,
+    /// VLAN ID, 0 means no VLAN
+    pub vid: u16,
+    pub address: MdbAddress,
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<MdbEntryInfoBuffer<&'a T>>
+    for MdbEntryInfo
+{
+    fn parse(buf: &MdbEntryInfoBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            ifindex: buf.ifindex(),
+            state: buf.state().into(),
+            flags: MdbEntryFlags::from_bits_retain(buf.flags()),
+            vid: buf.vid(),
+            address: MdbAddress::parse(
+                BigEndian::read_u16(buf.protocol()),
+                buf.address(),
+            ),
+        })
+    }
+}
+
+impl Emitable for MdbEntryInfo {
+    fn buffer_len(&self) -> usize {
+        MDB_ENTRY_INFO_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = MdbEntryInfoBuffer::new(buffer);
+        packet.set_ifindex(self.ifindex);
+        packet.set_state(self.state.into());
+        packet.set_flags(self.flags.bits());
+        packet.set_vid(self.vid);
+        packet.address_mut().fill(0);
+        self.address.emit(packet.address_mut());
+        BigEndian::write_u16(packet.protocol_mut(), self.address.protocol());
+        packet.set_reserved(0);
+    }
+}
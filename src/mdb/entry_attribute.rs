@@ -0,0 +1,291 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::{
+    ip::{emit_ip_addr, ip_addr_len, parse_ip_addr},
+    route::RouteProtocol,
+};
+
+const MDBA_MDB_EATTR_TIMER: u16 = 1;
+const MDBA_MDB_EATTR_SRC_LIST: u16 = 2;
+const MDBA_MDB_EATTR_GROUP_MODE: u16 = 3;
+const MDBA_MDB_EATTR_SOURCE: u16 = 4;
+const MDBA_MDB_EATTR_RTPROT: u16 = 5;
+const MDBA_MDB_EATTR_DST: u16 = 6;
+const MDBA_MDB_EATTR_DST_PORT: u16 = 7;
+const MDBA_MDB_EATTR_VNI: u16 = 8;
+const MDBA_MDB_EATTR_IFINDEX: u16 = 9;
+const MDBA_MDB_EATTR_SRC_VNI: u16 = 10;
+
+/// Attributes following `struct br_mdb_entry` in `MDBA_MDB_ENTRY_INFO`.
+/// Timers are in `clock_t`(USER_HZ) unit.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum MdbEntryAttribute {
+    /// Remaining time before the entry expires
+    Timer(u32),
+    /// Source list of IGMPv3/MLDv2 group
+    SourceList(Vec<MdbSourceEntry>),
+    GroupMode(MdbFilterMode),
+    /// Source address of (S, G) entry
+    Source(IpAddr),
+    Protocol(RouteProtocol),
+    /// Remote VTEP address of VXLAN MDB entry
+    Destination(IpAddr),
+    /// Remote UDP port of VXLAN MDB entry
+    DestinationPort(u16),
+    /// VNI of VXLAN MDB entry
+    Vni(u32),
+    /// Outgoing interface index of VXLAN MDB entry
+    Ifindex(u32),
+    /// Source VNI of VXLAN MDB entry
+    SourceVni(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbEntryAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::GroupMode(_) | Self::Protocol(_) => 1,
+            Self::DestinationPort(_) => 2,
+            Self::Timer(_)
+            | Self::Vni(_)
+            | Self::Ifindex(_)
+            | Self::SourceVni(_) => 4,
+            Self::Source(v) | Self::Destination(v) => ip_addr_len(v),
+            Self::SourceList(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::GroupMode(v) => buffer[0] = (*v).into(),
+            Self::Protocol(v) => buffer[0] = (*v).into(),
+            Self::DestinationPort(v) => NativeEndian::write_u16(buffer, *v),
+            Self::Timer(v)
+            | Self::Vni(v)
+            | Self::Ifindex(v)
+            | Self::SourceVni(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Source(v) | Self::Destination(v) => emit_ip_addr(v, buffer),
+            Self::SourceList(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Timer(_) => MDBA_MDB_EATTR_TIMER,
+            Self::SourceList(_) => MDBA_MDB_EATTR_SRC_LIST | NLA_F_NESTED,
+            Self::GroupMode(_) => MDBA_MDB_EATTR_GROUP_MODE,
+            Self::Source(_) => MDBA_MDB_EATTR_SOURCE,
+            Self::Protocol(_) => MDBA_MDB_EATTR_RTPROT,
+            Self::Destination(_) => MDBA_MDB_EATTR_DST,
+            Self::DestinationPort(_) => MDBA_MDB_EATTR_DST_PORT,
+            Self::Vni(_) => MDBA_MDB_EATTR_VNI,
+            Self::Ifindex(_) => MDBA_MDB_EATTR_IFINDEX,
+            Self::SourceVni(_) => MDBA_MDB_EATTR_SRC_VNI,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbEntryAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_EATTR_TIMER => Self::Timer(
+                parse_u32(payload)
+                    .context("invalid MDBA_MDB_EATTR_TIMER value")?,
+            ),
+            MDBA_MDB_EATTR_SRC_LIST => {
+                let mut entries = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        nla.context("invalid MDBA_MDB_EATTR_SRC_LIST value")?;
+                    entries.push(MdbSourceEntry::parse(&nla)?);
+                }
+                Self::SourceList(entries)
+            }
+            MDBA_MDB_EATTR_GROUP_MODE => Self::GroupMode(
+                parse_u8(payload)
+                    .context("invalid MDBA_MDB_EATTR_GROUP_MODE value")?
+                    .into(),
+            ),
+            MDBA_MDB_EATTR_SOURCE => Self::Source(
+                parse_ip_addr(payload)
+                    .context("invalid MDBA_MDB_EATTR_SOURCE value")?,
+            ),
+            MDBA_MDB_EATTR_RTPROT => Self::Protocol(
+                parse_u8(payload)
+                    .context("invalid MDBA_MDB_EATTR_RTPROT value")?
+                    .into(),
+            ),
+            MDBA_MDB_EATTR_DST => Self::Destination(
+                parse_ip_addr(payload)
+                    .context("invalid MDBA_MDB_EATTR_DST value")?,
+            ),
+            MDBA_MDB_EATTR_DST_PORT => Self::DestinationPort(
+                parse_u16(payload)
+                    .context("invalid MDBA_MDB_EATTR_DST_PORT value")?,
+            ),
+            MDBA_MDB_EATTR_VNI => Self::Vni(
+                parse_u32(payload)
+                    .context("invalid MDBA_MDB_EATTR_VNI value")?,
+            ),
+            MDBA_MDB_EATTR_IFINDEX => Self::Ifindex(
+                parse_u32(payload)
+                    .context("invalid MDBA_MDB_EATTR_IFINDEX value")?,
+            ),
+            MDBA_MDB_EATTR_SRC_VNI => Self::SourceVni(
+                parse_u32(payload)
+                    .context("invalid MDBA_MDB_EATTR_SRC_VNI value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+const MCAST_EXCLUDE: u8 = 0;
+const MCAST_INCLUDE: u8 = 1;
+
+/// Source filter mode of IGMPv3/MLDv2 group
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum MdbFilterMode {
+    #[default]
+    Exclude,
+    Include,
+    Other(u8),
+}
+
+impl From<u8> for MdbFilterMode {
+    fn from(d: u8) -> Self {
+        match d {
+            MCAST_EXCLUDE => Self::Exclude,
+            MCAST_INCLUDE => Self::Include,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<MdbFilterMode> for u8 {
+    fn from(v: MdbFilterMode) -> u8 {
+        match v {
+            MdbFilterMode::Exclude => MCAST_EXCLUDE,
+            MdbFilterMode::Include => MCAST_INCLUDE,
+            MdbFilterMode::Other(d) => d,
+        }
+    }
+}
+
+const MDBA_MDB_SRCLIST_ENTRY: u16 = 1;
+
+/// Source list entry(`MDBA_MDB_SRCLIST_ENTRY`) of IGMPv3/MLDv2 group
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct MdbSourceEntry(pub Vec<MdbSourceAttribute>);
+
+impl Nla for MdbSourceEntry {
+    fn value_len(&self) -> usize {
+        self.0.as_slice().buffer_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.0.as_slice().emit(buffer)
+    }
+
+    fn kind(&self) -> u16 {
+        MDBA_MDB_SRCLIST_ENTRY | NLA_F_NESTED
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbSourceEntry
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla in NlasIterator::new(buf.value()) {
+            let nla = &nla.context(format!(
+                "invalid MDBA_MDB_SRCLIST_ENTRY value {:?}",
+                buf.value()
+            ))?;
+            nlas.push(MdbSourceAttribute::parse(nla)?);
+        }
+        Ok(Self(nlas))
+    }
+}
+
+const MDBA_MDB_SRCATTR_ADDRESS: u16 = 1;
+const MDBA_MDB_SRCATTR_TIMER: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum MdbSourceAttribute {
+    Address(IpAddr),
+    /// Remaining time before the source expires in `clock_t`(USER_HZ) unit
+    Timer(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbSourceAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Address(v) => ip_addr_len(v),
+            Self::Timer(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Address(v) => emit_ip_addr(v, buffer),
+            Self::Timer(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Address(_) => MDBA_MDB_SRCATTR_ADDRESS,
+            Self::Timer(_) => MDBA_MDB_SRCATTR_TIMER,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbSourceAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_SRCATTR_ADDRESS => Self::Address(
+                parse_ip_addr(payload)
+                    .context("invalid MDBA_MDB_SRCATTR_ADDRESS value")?,
+            ),
+            MDBA_MDB_SRCATTR_TIMER => Self::Timer(
+                parse_u32(payload)
+                    .context("invalid MDBA_MDB_SRCATTR_TIMER value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::AddressFamily;
+
+const MDB_HEADER_LEN: usize = 8;
+
+buffer!(MdbMessageBuffer(MDB_HEADER_LEN) {
+    family: (u8, 0),
+    ifindex: (u32, 4..MDB_HEADER_LEN),
+    payload: (slice, MDB_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> MdbMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+/// High level representation of `RTM_NEWMDB`, `RTM_DELMDB`, `RTM_GETMDB`
+/// messages headers(`struct br_port_msg`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct MdbHeader {
+    pub family: AddressFamily,
+    /// Interface index of the bridge(or VXLAN) device
+    pub ifindex: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<MdbMessageBuffer<T>> for MdbHeader {
+    fn parse(buf: &MdbMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family().into(),
+            ifindex: buf.ifindex(),
+        })
+    }
+}
+
+impl Emitable for MdbHeader {
+    fn buffer_len(&self) -> usize {
+        MDB_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = MdbMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_ifindex(self.ifindex);
+    }
+}
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use super::{
+    attribute::MDBA_SET_ENTRY, entry::is_mdb_entries, MdbAttribute, MdbHeader,
+    MdbMessageBuffer,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct MdbMessage {
+    pub header: MdbHeader,
+    pub attributes: Vec<MdbAttribute>,
+}
+
+impl Emitable for MdbMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<MdbMessageBuffer<&'a T>>
+    for MdbMessage
+{
+    fn parse(buf: &MdbMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: MdbHeader::parse(buf)
+                .context("failed to parse MDB message header")?,
+            attributes: Vec::<MdbAttribute>::parse(buf)
+                .context("failed to parse MDB message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<MdbMessageBuffer<&'a T>>
+    for Vec<MdbAttribute>
+{
+    fn parse(buf: &MdbMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        // The MDBA_SET_ENTRY holds `struct br_mdb_entry` while MDBA_MDB
+        // sharing the same type always holds nested MDBA_MDB_ENTRY
+        // attributes. The `struct br_mdb_entry` starts with u32 ifindex,
+        // which cannot be read as NLA header of MDBA_MDB_ENTRY as its
+        // higher 16 bits(the NLA type) are zero.
+        let has_set_entry = buf.attributes().any(|nla| {
+            nla.map(|nla| {
+                nla.kind() == MDBA_SET_ENTRY && !is_mdb_entries(nla.value())
+            })
+            .unwrap_or_default()
+        });
+        let mut attributes = vec![];
+        for nla_buf in buf.attributes() {
+            attributes.push(MdbAttribute::parse_with_param(
+                &nla_buf?,
+                has_set_entry,
+            )?);
+        }
+        Ok(attributes)
+    }
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod entry;
+mod entry_attribute;
+mod header;
+mod message;
+mod router;
+#[cfg(test)]
+mod tests;
+
+pub use self::attribute::{MdbAttribute, MdbSetEntryAttribute};
+pub use self::entry::{
+    MdbAddress, MdbEntry, MdbEntryFlags, MdbEntryInfo, MdbEntryInfoBuffer,
+    MdbEntryState, MdbPortEntry,
+};
+pub use self::entry_attribute::{
+    MdbEntryAttribute, MdbFilterMode, MdbSourceAttribute, MdbSourceEntry,
+};
+pub use self::header::{MdbHeader, MdbMessageBuffer};
+pub use self::message::MdbMessage;
+pub use self::router::{MdbRouterPort, MdbRouterPortAttribute};
+
+pub(crate) use self::router::VecMdbRouterPort;
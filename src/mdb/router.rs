@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::link::BridgePortMulticastRouter;
+
+const MDBA_ROUTER_PORT: u16 = 1;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct VecMdbRouterPort(pub(crate) Vec<MdbRouterPort>);
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for VecMdbRouterPort
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut ports = vec![];
+        for nla in NlasIterator::new(buf.value()) {
+            let nla = &nla.context(format!(
+                "invalid MDBA_ROUTER value: {:?}",
+                buf.value()
+            ))?;
+            if nla.kind() == MDBA_ROUTER_PORT {
+                ports.push(MdbRouterPort::parse(nla)?);
+            } else {
+                log::warn!(
+                    "BUG: Expecting MDBA_ROUTER_PORT in MDBA_ROUTER, \
+                    but got {}",
+                    nla.kind()
+                );
+            }
+        }
+        Ok(Self(ports))
+    }
+}
+
+const IFINDEX_LEN: usize = 4;
+
+/// Multicast router port(`MDBA_ROUTER_PORT`) of the bridge
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct MdbRouterPort {
+    /// Interface index of the bridge port
+    pub ifindex: u32,
+    pub attributes: Vec<MdbRouterPortAttribute>,
+}
+
+impl Nla for MdbRouterPort {
+    fn value_len(&self) -> usize {
+        IFINDEX_LEN + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u32(buffer, self.ifindex);
+        self.attributes.as_slice().emit(&mut buffer[IFINDEX_LEN..]);
+    }
+
+    fn kind(&self) -> u16 {
+        MDBA_ROUTER_PORT
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbRouterPort
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        if payload.len() < IFINDEX_LEN {
+            return Err(DecodeError::from(format!(
+                "invalid MDBA_ROUTER_PORT value {payload:?}"
+            )));
+        }
+        let ifindex = parse_u32(&payload[..IFINDEX_LEN])
+            .context("invalid MDBA_ROUTER_PORT ifindex")?;
+        let mut attributes = vec![];
+        for nla in NlasIterator::new(&payload[IFINDEX_LEN..]) {
+            let nla = &nla.context(format!(
+                "invalid MDBA_ROUTER_PORT attributes {payload:?}"
+            ))?;
+            attributes.push(MdbRouterPortAttribute::parse(nla)?);
+        }
+        Ok(Self {
+            ifindex,
+            attributes,
+        })
+    }
+}
+
+const MDBA_ROUTER_PATTR_TIMER: u16 = 1;
+const MDBA_ROUTER_PATTR_TYPE: u16 = 2;
+const MDBA_ROUTER_PATTR_INET_TIMER: u16 = 3;
+const MDBA_ROUTER_PATTR_INET6_TIMER: u16 = 4;
+const MDBA_ROUTER_PATTR_VID: u16 = 5;
+
+/// Attributes of multicast router port. Timers are in `clock_t`(USER_HZ)
+/// unit.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum MdbRouterPortAttribute {
+    Timer(u32),
+    Type(BridgePortMulticastRouter),
+    /// IPv4 multicast router timer
+    InetTimer(u32),
+    /// IPv6 multicast router timer
+    Inet6Timer(u32),
+    /// VLAN ID of per-VLAN multicast router
+    Vid(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbRouterPortAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Type(_) => 1,
+            Self::Vid(_) => 2,
+            Self::Timer(_) | Self::InetTimer(_) | Self::Inet6Timer(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Type(v) => buffer[0] = (*v).into(),
+            Self::Vid(v) => NativeEndian::write_u16(buffer, *v),
+            Self::Timer(v) | Self::InetTimer(v) | Self::Inet6Timer(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Timer(_) => MDBA_ROUTER_PATTR_TIMER,
+            Self::Type(_) => MDBA_ROUTER_PATTR_TYPE,
+            Self::InetTimer(_) => MDBA_ROUTER_PATTR_INET_TIMER,
+            Self::Inet6Timer(_) => MDBA_ROUTER_PATTR_INET6_TIMER,
+            Self::Vid(_) => MDBA_ROUTER_PATTR_VID,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbRouterPortAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_ROUTER_PATTR_TIMER => Self::Timer(
+                parse_u32(payload)
+                    .context("invalid MDBA_ROUTER_PATTR_TIMER value")?,
+            ),
+            MDBA_ROUTER_PATTR_TYPE => Self::Type(
+                parse_u8(payload)
+                    .context("invalid MDBA_ROUTER_PATTR_TYPE value")?
+                    .into(),
+            ),
+            MDBA_ROUTER_PATTR_INET_TIMER => Self::InetTimer(
+                parse_u32(payload)
+                    .context("invalid MDBA_ROUTER_PATTR_INET_TIMER value")?,
+            ),
+            MDBA_ROUTER_PATTR_INET6_TIMER => Self::Inet6Timer(
+                parse_u32(payload)
+                    .context("invalid MDBA_ROUTER_PATTR_INET6_TIMER value")?,
+            ),
+            MDBA_ROUTER_PATTR_VID => Self::Vid(
+                parse_u16(payload)
+                    .context("invalid MDBA_ROUTER_PATTR_VID value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
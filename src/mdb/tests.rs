@@ -0,0 +1,344 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::{
+    link::BridgePortMulticastRouter,
+    mdb::{
+        MdbAddress, MdbAttribute, MdbEntry, MdbEntryAttribute, MdbEntryFlags,
+        MdbEntryInfo, MdbEntryState, MdbFilterMode, MdbHeader, MdbMessage,
+        MdbMessageBuffer, MdbPortEntry, MdbRouterPort, MdbRouterPortAttribute,
+        MdbSetEntryAttribute, MdbSourceAttribute, MdbSourceEntry,
+    },
+    route::RouteProtocol,
+    AddressFamily,
+};
+
+// Setup:
+//      ip link add br0 type bridge mcast_snooping 1 mcast_igmp_version 3
+//      ip link add d0 type veth peer name d1
+//      ip link set d0 master br0
+//      ip link set br0 up
+//      ip link set d0 up
+//      bridge link set dev d0 mcast_router 2
+//      bridge mdb add dev br0 port d0 grp 239.1.1.3 permanent \
+//          filter_mode include source_list 192.0.2.20
+//      bridge mdb add dev br0 port d0 grp 239.1.1.1 permanent
+//      bridge mdb add dev br0 port d0 grp 239.1.1.2 src 192.0.2.10 permanent
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      bridge mdb show
+#[test]
+fn test_mdb_dump_ipv4_with_router_port() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x1c, 0x01, 0x01, 0x00,
+        0x44, 0x00, 0x01, 0x00, 0x40, 0x00, 0x01, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0xef, 0x01, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00,
+        0xc0, 0x00, 0x02, 0x0a, 0x05, 0x00, 0x05, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x01, 0x00,
+        0x38, 0x00, 0x01, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0xef, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x05, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x44, 0x00, 0x01, 0x00,
+        0x40, 0x00, 0x01, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0xef, 0x01, 0x01, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0xc0, 0x00, 0x02, 0x14,
+        0x05, 0x00, 0x05, 0x00, 0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x03, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x54, 0x00, 0x01, 0x00, 0x50, 0x00, 0x01, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0xef, 0x01, 0x01, 0x03,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x05, 0x00, 0x04, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0xc0, 0x00, 0x02, 0x14,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x03, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x02, 0x00, 0x28, 0x00, 0x01, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = MdbMessage {
+        header: MdbHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 2,
+        },
+        attributes: vec![
+            MdbAttribute::Mdb(vec![
+                MdbEntry(vec![MdbPortEntry {
+                    info: MdbEntryInfo {
+                        ifindex: 4,
+                        state: MdbEntryState::Permanent,
+                        flags: MdbEntryFlags::empty(),
+                        vid: 0,
+                        address: MdbAddress::Inet(
+                            Ipv4Addr::from_str("239.1.1.2").unwrap(),
+                        ),
+                    },
+                    attributes: vec![
+                        MdbEntryAttribute::Timer(0),
+                        MdbEntryAttribute::Source(
+                            Ipv4Addr::from_str("192.0.2.10").unwrap().into(),
+                        ),
+                        MdbEntryAttribute::Protocol(RouteProtocol::Static),
+                        MdbEntryAttribute::GroupMode(MdbFilterMode::Include),
+                    ],
+                }]),
+                MdbEntry(vec![MdbPortEntry {
+                    info: MdbEntryInfo {
+                        ifindex: 4,
+                        state: MdbEntryState::Permanent,
+                        flags: MdbEntryFlags::empty(),
+                        vid: 0,
+                        address: MdbAddress::Inet(
+                            Ipv4Addr::from_str("239.1.1.1").unwrap(),
+                        ),
+                    },
+                    attributes: vec![
+                        MdbEntryAttribute::Timer(0),
+                        MdbEntryAttribute::Protocol(RouteProtocol::Static),
+                        MdbEntryAttribute::GroupMode(MdbFilterMode::Exclude),
+                    ],
+                }]),
+                MdbEntry(vec![MdbPortEntry {
+                    info: MdbEntryInfo {
+                        ifindex: 4,
+                        state: MdbEntryState::Permanent,
+                        flags: MdbEntryFlags::empty(),
+                        vid: 0,
+                        address: MdbAddress::Inet(
+                            Ipv4Addr::from_str("239.1.1.3").unwrap(),
+                        ),
+                    },
+                    attributes: vec![
+                        MdbEntryAttribute::Timer(0),
+                        MdbEntryAttribute::Source(
+                            Ipv4Addr::from_str("192.0.2.20").unwrap().into(),
+                        ),
+                        MdbEntryAttribute::Protocol(RouteProtocol::Static),
+                        MdbEntryAttribute::GroupMode(MdbFilterMode::Include),
+                    ],
+                }]),
+                MdbEntry(vec![MdbPortEntry {
+                    info: MdbEntryInfo {
+                        ifindex: 4,
+                        state: MdbEntryState::Permanent,
+                        flags: MdbEntryFlags::empty(),
+                        vid: 0,
+                        address: MdbAddress::Inet(
+                            Ipv4Addr::from_str("239.1.1.3").unwrap(),
+                        ),
+                    },
+                    attributes: vec![
+                        MdbEntryAttribute::Timer(0),
+                        MdbEntryAttribute::Protocol(RouteProtocol::Static),
+                        MdbEntryAttribute::SourceList(vec![MdbSourceEntry(
+                            vec![
+                                MdbSourceAttribute::Address(
+                                    Ipv4Addr::from_str("192.0.2.20")
+                                        .unwrap()
+                                        .into(),
+                                ),
+                                MdbSourceAttribute::Timer(0),
+                            ],
+                        )]),
+                        MdbEntryAttribute::GroupMode(MdbFilterMode::Include),
+                    ],
+                }]),
+            ]),
+            MdbAttribute::Router(vec![MdbRouterPort {
+                ifindex: 4,
+                attributes: vec![
+                    MdbRouterPortAttribute::Timer(0),
+                    MdbRouterPortAttribute::Type(
+                        BridgePortMulticastRouter::Perm,
+                    ),
+                    MdbRouterPortAttribute::InetTimer(0),
+                    MdbRouterPortAttribute::Inet6Timer(0),
+                ],
+            }]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        MdbMessage::parse(&MdbMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add br0 type bridge mcast_snooping 1
+//      ip link set br0 type bridge vlan_filtering 1
+//      ip link add d0 type veth peer name d1
+//      ip link set d0 master br0
+//      ip link set br0 up
+//      ip link set d0 up
+//      bridge mdb add dev br0 port d0 grp ff0e::1 permanent vid 1
+//      bridge mdb add dev br0 port d0 grp 01:00:5e:01:01:05 permanent vid 1
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      bridge mdb show
+#[test]
+fn test_mdb_dump_ipv6_and_l2() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x6c, 0x00, 0x01, 0x00,
+        0x34, 0x00, 0x01, 0x00, 0x30, 0x00, 0x01, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x5e, 0x01, 0x01, 0x05, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x05, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x34, 0x00, 0x01, 0x00, 0x30, 0x00, 0x01, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0xff, 0x0e, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x86, 0xdd, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x05, 0x00, 0x04, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = MdbMessage {
+        header: MdbHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 2,
+        },
+        attributes: vec![MdbAttribute::Mdb(vec![
+            MdbEntry(vec![MdbPortEntry {
+                info: MdbEntryInfo {
+                    ifindex: 4,
+                    state: MdbEntryState::Permanent,
+                    flags: MdbEntryFlags::empty(),
+                    vid: 1,
+                    address: MdbAddress::Mac([
+                        0x01, 0x00, 0x5e, 0x01, 0x01, 0x05,
+                    ]),
+                },
+                attributes: vec![
+                    MdbEntryAttribute::Timer(0),
+                    MdbEntryAttribute::Protocol(RouteProtocol::Static),
+                ],
+            }]),
+            MdbEntry(vec![MdbPortEntry {
+                info: MdbEntryInfo {
+                    ifindex: 4,
+                    state: MdbEntryState::Permanent,
+                    flags: MdbEntryFlags::empty(),
+                    vid: 1,
+                    address: MdbAddress::Inet6(
+                        Ipv6Addr::from_str("ff0e::1").unwrap(),
+                    ),
+                },
+                attributes: vec![
+                    MdbEntryAttribute::Timer(0),
+                    MdbEntryAttribute::Protocol(RouteProtocol::Static),
+                ],
+            }]),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        MdbMessage::parse(&MdbMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_NEWMDB` request equivalent to
+// command:
+//      bridge mdb add dev br0 port d0 grp 239.1.1.2 src 192.0.2.10 permanent
+#[test]
+fn test_mdb_add_source_specific_entry() {
+    let raw = vec![
+        0x07, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x20, 0x00, 0x01, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0xef, 0x01, 0x01, 0x02,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0xc0, 0x00, 0x02, 0x0a,
+    ];
+
+    let expected = MdbMessage {
+        header: MdbHeader {
+            family: AddressFamily::Bridge,
+            ifindex: 2,
+        },
+        attributes: vec![
+            MdbAttribute::SetEntry(MdbEntryInfo {
+                ifindex: 4,
+                state: MdbEntryState::Permanent,
+                flags: MdbEntryFlags::empty(),
+                vid: 0,
+                address: MdbAddress::Inet(
+                    Ipv4Addr::from_str("239.1.1.2").unwrap(),
+                ),
+            }),
+            MdbAttribute::SetEntryAttributes(vec![
+                MdbSetEntryAttribute::Source(
+                    Ipv4Addr::from_str("192.0.2.10").unwrap().into(),
+                ),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        MdbMessage::parse(&MdbMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Same as `test_mdb_add_source_specific_entry` but with 4 bytes appended to
+// `struct br_mdb_entry` in case kernel extends it in the future.
+#[test]
+fn test_mdb_add_entry_with_extended_entry_info() {
+    let raw = vec![
+        0x07, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x24, 0x00, 0x01, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0xef, 0x01, 0x01, 0x02,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0xc0, 0x00, 0x02, 0x0a,
+    ];
+
+    let expected = MdbMessage {
+        header: MdbHeader {
+            family: AddressFamily::Bridge,
+            ifindex: 2,
+        },
+        attributes: vec![
+            MdbAttribute::SetEntry(MdbEntryInfo {
+                ifindex: 4,
+                state: MdbEntryState::Permanent,
+                flags: MdbEntryFlags::empty(),
+                vid: 0,
+                address: MdbAddress::Inet(
+                    Ipv4Addr::from_str("239.1.1.2").unwrap(),
+                ),
+            }),
+            MdbAttribute::SetEntryAttributes(vec![
+                MdbSetEntryAttribute::Source(
+                    Ipv4Addr::from_str("192.0.2.10").unwrap().into(),
+                ),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        MdbMessage::parse(&MdbMessageBuffer::new(&raw)).unwrap()
+    );
+}
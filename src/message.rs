@@ -11,14 +11,24 @@ use netlink_packet_core::{
 
 use crate::{
     address::{AddressHeader, AddressMessage, AddressMessageBuffer},
+    address_label::{AddressLabelMessage, AddressLabelMessageBuffer},
+    bridge_vlan::{BridgeVlanMessage, BridgeVlanMessageBuffer},
+    cache_report::{CacheReportMessage, CacheReportMessageBuffer},
+    dcb::{DcbMessage, DcbMessageBuffer},
     link::{LinkMessage, LinkMessageBuffer},
+    mdb::{MdbMessage, MdbMessageBuffer},
+    nduseropt::{NdUserOptionMessage, NdUserOptionMessageBuffer},
     neighbour::{NeighbourMessage, NeighbourMessageBuffer},
     neighbour_table::{NeighbourTableMessage, NeighbourTableMessageBuffer},
+    netconf::{NetconfMessage, NetconfMessageBuffer},
+    nexthop::{NexthopMessage, NexthopMessageBuffer},
     nsid::{NsidMessage, NsidMessageBuffer},
     prefix::{PrefixMessage, PrefixMessageBuffer},
     route::{RouteHeader, RouteMessage, RouteMessageBuffer},
     rule::{RuleMessage, RuleMessageBuffer},
-    tc::{TcMessage, TcMessageBuffer},
+    stats::{StatsMessage, StatsMessageBuffer},
+    tc::{TcActionMessage, TcActionMessageBuffer, TcMessage, TcMessageBuffer},
+    tunnel::{TunnelMessage, TunnelMessageBuffer},
 };
 
 const RTM_NEWLINK: u16 = 16;
@@ -46,38 +56,55 @@ const RTM_GETTCLASS: u16 = 42;
 const RTM_NEWTFILTER: u16 = 44;
 const RTM_DELTFILTER: u16 = 45;
 const RTM_GETTFILTER: u16 = 46;
-// const RTM_NEWACTION: u16 = 48;
-// const RTM_DELACTION: u16 = 49;
-// const RTM_GETACTION: u16 = 50;
+const RTM_NEWACTION: u16 = 48;
+const RTM_DELACTION: u16 = 49;
+const RTM_GETACTION: u16 = 50;
 const RTM_NEWPREFIX: u16 = 52;
-// const RTM_GETMULTICAST: u16 = 58;
-// const RTM_GETANYCAST: u16 = 62;
+const RTM_NEWMULTICAST: u16 = 56;
+const RTM_DELMULTICAST: u16 = 57;
+const RTM_GETMULTICAST: u16 = 58;
+const RTM_NEWANYCAST: u16 = 60;
+const RTM_DELANYCAST: u16 = 61;
+const RTM_GETANYCAST: u16 = 62;
 const RTM_NEWNEIGHTBL: u16 = 64;
 const RTM_GETNEIGHTBL: u16 = 66;
 const RTM_SETNEIGHTBL: u16 = 67;
-// const RTM_NEWNDUSEROPT: u16 = 68;
-// const RTM_NEWADDRLABEL: u16 = 72;
-// const RTM_DELADDRLABEL: u16 = 73;
-// const RTM_GETADDRLABEL: u16 = 74;
-// const RTM_GETDCB: u16 = 78;
-// const RTM_SETDCB: u16 = 79;
-// const RTM_NEWNETCONF: u16 = 80;
-// const RTM_DELNETCONF: u16 = 81;
-// const RTM_GETNETCONF: u16 = 82;
-// const RTM_NEWMDB: u16 = 84;
-// const RTM_DELMDB: u16 = 85;
-// const RTM_GETMDB: u16 = 86;
+const RTM_NEWNDUSEROPT: u16 = 68;
+const RTM_NEWADDRLABEL: u16 = 72;
+const RTM_DELADDRLABEL: u16 = 73;
+const RTM_GETADDRLABEL: u16 = 74;
+const RTM_GETDCB: u16 = 78;
+const RTM_SETDCB: u16 = 79;
+const RTM_NEWNETCONF: u16 = 80;
+const RTM_DELNETCONF: u16 = 81;
+const RTM_GETNETCONF: u16 = 82;
+const RTM_NEWMDB: u16 = 84;
+const RTM_DELMDB: u16 = 85;
+const RTM_GETMDB: u16 = 86;
 const RTM_NEWNSID: u16 = 88;
 const RTM_DELNSID: u16 = 89;
 const RTM_GETNSID: u16 = 90;
-// const RTM_NEWSTATS: u16 = 92;
-// const RTM_GETSTATS: u16 = 94;
-// const RTM_NEWCACHEREPORT: u16 = 96;
+pub(crate) const RTM_NEWSTATS: u16 = 92;
+pub(crate) const RTM_SETSTATS: u16 = 93;
+pub(crate) const RTM_GETSTATS: u16 = 94;
+const RTM_NEWCACHEREPORT: u16 = 96;
 const RTM_NEWCHAIN: u16 = 100;
 const RTM_DELCHAIN: u16 = 101;
 const RTM_GETCHAIN: u16 = 102;
+const RTM_NEWNEXTHOP: u16 = 104;
+const RTM_DELNEXTHOP: u16 = 105;
+const RTM_GETNEXTHOP: u16 = 106;
 const RTM_NEWLINKPROP: u16 = 108;
 const RTM_DELLINKPROP: u16 = 109;
+pub(crate) const RTM_NEWVLAN: u16 = 112;
+pub(crate) const RTM_DELVLAN: u16 = 113;
+pub(crate) const RTM_GETVLAN: u16 = 114;
+const RTM_NEWNEXTHOPBUCKET: u16 = 116;
+const RTM_DELNEXTHOPBUCKET: u16 = 117;
+const RTM_GETNEXTHOPBUCKET: u16 = 118;
+const RTM_NEWTUNNEL: u16 = 120;
+const RTM_DELTUNNEL: u16 = 121;
+const RTM_GETTUNNEL: u16 = 122;
 
 buffer!(RouteNetlinkMessageBuffer);
 
@@ -119,7 +146,9 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
             }
 
             // Address messages
-            RTM_NEWADDR | RTM_GETADDR | RTM_DELADDR => {
+            RTM_NEWADDR | RTM_GETADDR | RTM_DELADDR | RTM_NEWMULTICAST
+            | RTM_DELMULTICAST | RTM_GETMULTICAST | RTM_NEWANYCAST
+            | RTM_DELANYCAST | RTM_GETANYCAST => {
                 let msg = match AddressMessageBuffer::new_checked(&buf.inner())
                 {
                     Ok(buf) => AddressMessage::parse(&buf)
@@ -145,6 +174,24 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
                     RTM_NEWADDR => RouteNetlinkMessage::NewAddress(msg),
                     RTM_GETADDR => RouteNetlinkMessage::GetAddress(msg),
                     RTM_DELADDR => RouteNetlinkMessage::DelAddress(msg),
+                    RTM_NEWMULTICAST => {
+                        RouteNetlinkMessage::NewMulticastAddress(msg)
+                    }
+                    RTM_DELMULTICAST => {
+                        RouteNetlinkMessage::DelMulticastAddress(msg)
+                    }
+                    RTM_GETMULTICAST => {
+                        RouteNetlinkMessage::GetMulticastAddress(msg)
+                    }
+                    RTM_NEWANYCAST => {
+                        RouteNetlinkMessage::NewAnycastAddress(msg)
+                    }
+                    RTM_DELANYCAST => {
+                        RouteNetlinkMessage::DelAnycastAddress(msg)
+                    }
+                    RTM_GETANYCAST => {
+                        RouteNetlinkMessage::GetAnycastAddress(msg)
+                    }
                     _ => unreachable!(),
                 }
             }
@@ -291,6 +338,22 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
                 }
             }
 
+            // TC action messages
+            RTM_NEWACTION | RTM_DELACTION | RTM_GETACTION => {
+                let err = "invalid tc action message";
+                let msg = TcActionMessage::parse(
+                    &TcActionMessageBuffer::new_checked(&buf.inner())
+                        .context(err)?,
+                )
+                .context(err)?;
+                match message_type {
+                    RTM_NEWACTION => RouteNetlinkMessage::NewTrafficAction(msg),
+                    RTM_DELACTION => RouteNetlinkMessage::DelTrafficAction(msg),
+                    RTM_GETACTION => RouteNetlinkMessage::GetTrafficAction(msg),
+                    _ => unreachable!(),
+                }
+            }
+
             // ND ID Messages
             RTM_NEWNSID | RTM_GETNSID | RTM_DELNSID => {
                 let err = "invalid nsid message";
@@ -307,6 +370,188 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
                 }
             }
 
+            // Nexthop Messages
+            RTM_NEWNEXTHOP | RTM_GETNEXTHOP | RTM_DELNEXTHOP => {
+                let err = "invalid nexthop message";
+                let msg = NexthopMessage::parse(
+                    &NexthopMessageBuffer::new_checked(&buf.inner())
+                        .context(err)?,
+                )
+                .context(err)?;
+                match message_type {
+                    RTM_NEWNEXTHOP => RouteNetlinkMessage::NewNexthop(msg),
+                    RTM_DELNEXTHOP => RouteNetlinkMessage::DelNexthop(msg),
+                    RTM_GETNEXTHOP => RouteNetlinkMessage::GetNexthop(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Nexthop bucket Messages
+            RTM_NEWNEXTHOPBUCKET | RTM_GETNEXTHOPBUCKET
+            | RTM_DELNEXTHOPBUCKET => {
+                let err = "invalid nexthop bucket message";
+                let msg = NexthopMessage::parse(
+                    &NexthopMessageBuffer::new_checked(&buf.inner())
+                        .context(err)?,
+                )
+                .context(err)?;
+                match message_type {
+                    RTM_NEWNEXTHOPBUCKET => {
+                        RouteNetlinkMessage::NewNexthopBucket(msg)
+                    }
+                    RTM_DELNEXTHOPBUCKET => {
+                        RouteNetlinkMessage::DelNexthopBucket(msg)
+                    }
+                    RTM_GETNEXTHOPBUCKET => {
+                        RouteNetlinkMessage::GetNexthopBucket(msg)
+                    }
+                    _ => unreachable!(),
+                }
+            }
+
+            // Bridge multicast database Messages
+            RTM_NEWMDB | RTM_DELMDB | RTM_GETMDB => {
+                let err = "invalid MDB message";
+                let msg = MdbMessage::parse(
+                    &MdbMessageBuffer::new_checked(&buf.inner())
+                        .context(err)?,
+                )
+                .context(err)?;
+                match message_type {
+                    RTM_NEWMDB => RouteNetlinkMessage::NewMdb(msg),
+                    RTM_DELMDB => RouteNetlinkMessage::DelMdb(msg),
+                    RTM_GETMDB => RouteNetlinkMessage::GetMdb(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Netconf Messages
+            RTM_NEWNETCONF | RTM_DELNETCONF | RTM_GETNETCONF => {
+                let err = "invalid netconf message";
+                let msg = NetconfMessage::parse(
+                    &NetconfMessageBuffer::new_checked(&buf.inner())
+                        .context(err)?,
+                )
+                .context(err)?;
+                match message_type {
+                    RTM_NEWNETCONF => RouteNetlinkMessage::NewNetconf(msg),
+                    RTM_DELNETCONF => RouteNetlinkMessage::DelNetconf(msg),
+                    RTM_GETNETCONF => RouteNetlinkMessage::GetNetconf(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Link statistics Messages
+            RTM_NEWSTATS | RTM_GETSTATS | RTM_SETSTATS => {
+                let err = "invalid stats message";
+                let msg = StatsMessage::parse_with_param(
+                    &StatsMessageBuffer::new_checked(&buf.inner())
+                        .context(err)?,
+                    message_type,
+                )
+                .context(err)?;
+                match message_type {
+                    RTM_NEWSTATS => RouteNetlinkMessage::NewStats(msg),
+                    RTM_GETSTATS => RouteNetlinkMessage::GetStats(msg),
+                    RTM_SETSTATS => RouteNetlinkMessage::SetStats(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Bridge VLAN Messages
+            RTM_NEWVLAN | RTM_DELVLAN | RTM_GETVLAN => {
+                let err = "invalid bridge VLAN message";
+                let msg = BridgeVlanMessage::parse_with_param(
+                    &BridgeVlanMessageBuffer::new_checked(&buf.inner())
+                        .context(err)?,
+                    message_type,
+                )
+                .context(err)?;
+                match message_type {
+                    RTM_NEWVLAN => RouteNetlinkMessage::NewBridgeVlan(msg),
+                    RTM_DELVLAN => RouteNetlinkMessage::DelBridgeVlan(msg),
+                    RTM_GETVLAN => RouteNetlinkMessage::GetBridgeVlan(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Tunnel Messages
+            RTM_NEWTUNNEL | RTM_DELTUNNEL | RTM_GETTUNNEL => {
+                let err = "invalid tunnel message";
+                let msg = TunnelMessage::parse(
+                    &TunnelMessageBuffer::new_checked(&buf.inner())
+                        .context(err)?,
+                )
+                .context(err)?;
+                match message_type {
+                    RTM_NEWTUNNEL => RouteNetlinkMessage::NewTunnel(msg),
+                    RTM_DELTUNNEL => RouteNetlinkMessage::DelTunnel(msg),
+                    RTM_GETTUNNEL => RouteNetlinkMessage::GetTunnel(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Address label Messages
+            RTM_NEWADDRLABEL | RTM_DELADDRLABEL | RTM_GETADDRLABEL => {
+                let err = "invalid address label message";
+                let msg = AddressLabelMessage::parse(
+                    &AddressLabelMessageBuffer::new_checked(&buf.inner())
+                        .context(err)?,
+                )
+                .context(err)?;
+                match message_type {
+                    RTM_NEWADDRLABEL => {
+                        RouteNetlinkMessage::NewAddressLabel(msg)
+                    }
+                    RTM_DELADDRLABEL => {
+                        RouteNetlinkMessage::DelAddressLabel(msg)
+                    }
+                    RTM_GETADDRLABEL => {
+                        RouteNetlinkMessage::GetAddressLabel(msg)
+                    }
+                    _ => unreachable!(),
+                }
+            }
+
+            // ND user option Messages
+            RTM_NEWNDUSEROPT => {
+                let err = "invalid nduseropt message";
+                RouteNetlinkMessage::NewNdUserOption(
+                    NdUserOptionMessage::parse(
+                        &NdUserOptionMessageBuffer::new_checked(&buf.inner())
+                            .context(err)?,
+                    )
+                    .context(err)?,
+                )
+            }
+
+            // Cache report Messages
+            RTM_NEWCACHEREPORT => {
+                let err = "invalid cache report message";
+                RouteNetlinkMessage::NewCacheReport(
+                    CacheReportMessage::parse(
+                        &CacheReportMessageBuffer::new_checked(&buf.inner())
+                            .context(err)?,
+                    )
+                    .context(err)?,
+                )
+            }
+
+            // DCB Messages
+            RTM_GETDCB | RTM_SETDCB => {
+                let err = "invalid DCB message";
+                let msg = DcbMessage::parse(
+                    &DcbMessageBuffer::new_checked(&buf.inner())
+                        .context(err)?,
+                )
+                .context(err)?;
+                match message_type {
+                    RTM_GETDCB => RouteNetlinkMessage::GetDcb(msg),
+                    RTM_SETDCB => RouteNetlinkMessage::SetDcb(msg),
+                    _ => unreachable!(),
+                }
+            }
+
             _ => {
                 return Err(
                     format!("Unknown message type: {message_type}").into()
@@ -329,6 +574,12 @@ pub enum RouteNetlinkMessage {
     NewAddress(AddressMessage),
     DelAddress(AddressMessage),
     GetAddress(AddressMessage),
+    NewMulticastAddress(AddressMessage),
+    DelMulticastAddress(AddressMessage),
+    GetMulticastAddress(AddressMessage),
+    NewAnycastAddress(AddressMessage),
+    DelAnycastAddress(AddressMessage),
+    GetAnycastAddress(AddressMessage),
     NewNeighbour(NeighbourMessage),
     GetNeighbour(NeighbourMessage),
     DelNeighbour(NeighbourMessage),
@@ -351,12 +602,43 @@ pub enum RouteNetlinkMessage {
     NewTrafficChain(TcMessage),
     DelTrafficChain(TcMessage),
     GetTrafficChain(TcMessage),
+    NewTrafficAction(TcActionMessage),
+    DelTrafficAction(TcActionMessage),
+    GetTrafficAction(TcActionMessage),
     NewNsId(NsidMessage),
     DelNsId(NsidMessage),
     GetNsId(NsidMessage),
     NewRule(RuleMessage),
     DelRule(RuleMessage),
     GetRule(RuleMessage),
+    NewNexthop(NexthopMessage),
+    DelNexthop(NexthopMessage),
+    GetNexthop(NexthopMessage),
+    NewNexthopBucket(NexthopMessage),
+    DelNexthopBucket(NexthopMessage),
+    GetNexthopBucket(NexthopMessage),
+    NewMdb(MdbMessage),
+    DelMdb(MdbMessage),
+    GetMdb(MdbMessage),
+    NewNetconf(NetconfMessage),
+    DelNetconf(NetconfMessage),
+    GetNetconf(NetconfMessage),
+    NewStats(StatsMessage),
+    GetStats(StatsMessage),
+    SetStats(StatsMessage),
+    NewBridgeVlan(BridgeVlanMessage),
+    DelBridgeVlan(BridgeVlanMessage),
+    GetBridgeVlan(BridgeVlanMessage),
+    NewTunnel(TunnelMessage),
+    DelTunnel(TunnelMessage),
+    GetTunnel(TunnelMessage),
+    NewAddressLabel(AddressLabelMessage),
+    DelAddressLabel(AddressLabelMessage),
+    GetAddressLabel(AddressLabelMessage),
+    NewNdUserOption(NdUserOptionMessage),
+    NewCacheReport(CacheReportMessage),
+    GetDcb(DcbMessage),
+    SetDcb(DcbMessage),
 }
 
 impl RouteNetlinkMessage {
@@ -388,6 +670,30 @@ impl RouteNetlinkMessage {
         matches!(self, RouteNetlinkMessage::GetAddress(_))
     }
 
+    pub fn is_new_multicast_address(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewMulticastAddress(_))
+    }
+
+    pub fn is_del_multicast_address(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelMulticastAddress(_))
+    }
+
+    pub fn is_get_multicast_address(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetMulticastAddress(_))
+    }
+
+    pub fn is_new_anycast_address(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewAnycastAddress(_))
+    }
+
+    pub fn is_del_anycast_address(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelAnycastAddress(_))
+    }
+
+    pub fn is_get_anycast_address(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetAnycastAddress(_))
+    }
+
     pub fn is_get_neighbour(&self) -> bool {
         matches!(self, RouteNetlinkMessage::GetNeighbour(_))
     }
@@ -472,6 +778,18 @@ impl RouteNetlinkMessage {
         matches!(self, RouteNetlinkMessage::GetTrafficChain(_))
     }
 
+    pub fn is_new_action(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewTrafficAction(_))
+    }
+
+    pub fn is_del_action(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelTrafficAction(_))
+    }
+
+    pub fn is_get_action(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetTrafficAction(_))
+    }
+
     pub fn is_new_nsid(&self) -> bool {
         matches!(self, RouteNetlinkMessage::NewNsId(_))
     }
@@ -496,6 +814,118 @@ impl RouteNetlinkMessage {
         matches!(self, RouteNetlinkMessage::DelRule(_))
     }
 
+    pub fn is_new_nexthop(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNexthop(_))
+    }
+
+    pub fn is_del_nexthop(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelNexthop(_))
+    }
+
+    pub fn is_get_nexthop(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetNexthop(_))
+    }
+
+    pub fn is_new_nexthop_bucket(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNexthopBucket(_))
+    }
+
+    pub fn is_del_nexthop_bucket(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelNexthopBucket(_))
+    }
+
+    pub fn is_get_nexthop_bucket(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetNexthopBucket(_))
+    }
+
+    pub fn is_new_mdb(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewMdb(_))
+    }
+
+    pub fn is_del_mdb(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelMdb(_))
+    }
+
+    pub fn is_get_mdb(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetMdb(_))
+    }
+
+    pub fn is_new_netconf(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNetconf(_))
+    }
+
+    pub fn is_del_netconf(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelNetconf(_))
+    }
+
+    pub fn is_get_netconf(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetNetconf(_))
+    }
+
+    pub fn is_new_stats(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewStats(_))
+    }
+
+    pub fn is_get_stats(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetStats(_))
+    }
+
+    pub fn is_set_stats(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::SetStats(_))
+    }
+
+    pub fn is_new_bridge_vlan(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewBridgeVlan(_))
+    }
+
+    pub fn is_del_bridge_vlan(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelBridgeVlan(_))
+    }
+
+    pub fn is_get_bridge_vlan(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetBridgeVlan(_))
+    }
+
+    pub fn is_new_tunnel(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewTunnel(_))
+    }
+
+    pub fn is_del_tunnel(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelTunnel(_))
+    }
+
+    pub fn is_get_tunnel(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetTunnel(_))
+    }
+
+    pub fn is_new_address_label(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewAddressLabel(_))
+    }
+
+    pub fn is_del_address_label(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelAddressLabel(_))
+    }
+
+    pub fn is_get_address_label(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetAddressLabel(_))
+    }
+
+    pub fn is_new_nd_user_option(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNdUserOption(_))
+    }
+
+    pub fn is_new_cache_report(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewCacheReport(_))
+    }
+
+    pub fn is_get_dcb(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetDcb(_))
+    }
+
+    pub fn is_set_dcb(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::SetDcb(_))
+    }
+
     pub fn message_type(&self) -> u16 {
         use self::RouteNetlinkMessage::*;
 
@@ -509,6 +939,12 @@ impl RouteNetlinkMessage {
             NewAddress(_) => RTM_NEWADDR,
             DelAddress(_) => RTM_DELADDR,
             GetAddress(_) => RTM_GETADDR,
+            NewMulticastAddress(_) => RTM_NEWMULTICAST,
+            DelMulticastAddress(_) => RTM_DELMULTICAST,
+            GetMulticastAddress(_) => RTM_GETMULTICAST,
+            NewAnycastAddress(_) => RTM_NEWANYCAST,
+            DelAnycastAddress(_) => RTM_DELANYCAST,
+            GetAnycastAddress(_) => RTM_GETANYCAST,
             GetNeighbour(_) => RTM_GETNEIGH,
             NewNeighbour(_) => RTM_NEWNEIGH,
             DelNeighbour(_) => RTM_DELNEIGH,
@@ -531,12 +967,43 @@ impl RouteNetlinkMessage {
             NewTrafficChain(_) => RTM_NEWCHAIN,
             DelTrafficChain(_) => RTM_DELCHAIN,
             GetTrafficChain(_) => RTM_GETCHAIN,
+            NewTrafficAction(_) => RTM_NEWACTION,
+            DelTrafficAction(_) => RTM_DELACTION,
+            GetTrafficAction(_) => RTM_GETACTION,
             GetNsId(_) => RTM_GETNSID,
             NewNsId(_) => RTM_NEWNSID,
             DelNsId(_) => RTM_DELNSID,
             GetRule(_) => RTM_GETRULE,
             NewRule(_) => RTM_NEWRULE,
             DelRule(_) => RTM_DELRULE,
+            NewNexthop(_) => RTM_NEWNEXTHOP,
+            DelNexthop(_) => RTM_DELNEXTHOP,
+            GetNexthop(_) => RTM_GETNEXTHOP,
+            NewNexthopBucket(_) => RTM_NEWNEXTHOPBUCKET,
+            DelNexthopBucket(_) => RTM_DELNEXTHOPBUCKET,
+            GetNexthopBucket(_) => RTM_GETNEXTHOPBUCKET,
+            NewMdb(_) => RTM_NEWMDB,
+            DelMdb(_) => RTM_DELMDB,
+            GetMdb(_) => RTM_GETMDB,
+            NewNetconf(_) => RTM_NEWNETCONF,
+            DelNetconf(_) => RTM_DELNETCONF,
+            GetNetconf(_) => RTM_GETNETCONF,
+            NewStats(_) => RTM_NEWSTATS,
+            GetStats(_) => RTM_GETSTATS,
+            SetStats(_) => RTM_SETSTATS,
+            NewBridgeVlan(_) => RTM_NEWVLAN,
+            DelBridgeVlan(_) => RTM_DELVLAN,
+            GetBridgeVlan(_) => RTM_GETVLAN,
+            NewTunnel(_) => RTM_NEWTUNNEL,
+            DelTunnel(_) => RTM_DELTUNNEL,
+            GetTunnel(_) => RTM_GETTUNNEL,
+            NewAddressLabel(_) => RTM_NEWADDRLABEL,
+            DelAddressLabel(_) => RTM_DELADDRLABEL,
+            GetAddressLabel(_) => RTM_GETADDRLABEL,
+            NewNdUserOption(_) => RTM_NEWNDUSEROPT,
+            NewCacheReport(_) => RTM_NEWCACHEREPORT,
+            GetDcb(_) => RTM_GETDCB,
+            SetDcb(_) => RTM_SETDCB,
         }
     }
 }
@@ -557,6 +1024,12 @@ impl Emitable for RouteNetlinkMessage {
             | NewAddress(ref msg)
             | DelAddress(ref msg)
             | GetAddress(ref msg)
+            | NewMulticastAddress(ref msg)
+            | DelMulticastAddress(ref msg)
+            | GetMulticastAddress(ref msg)
+            | NewAnycastAddress(ref msg)
+            | DelAnycastAddress(ref msg)
+            | GetAnycastAddress(ref msg)
             => msg.buffer_len(),
 
             | NewNeighbour(ref msg)
@@ -590,6 +1063,11 @@ impl Emitable for RouteNetlinkMessage {
             | GetTrafficChain(ref msg)
             => msg.buffer_len(),
 
+            | NewTrafficAction(ref msg)
+            | DelTrafficAction(ref msg)
+            | GetTrafficAction(ref msg)
+            => msg.buffer_len(),
+
             | NewNsId(ref msg)
             | DelNsId(ref msg)
             | GetNsId(ref msg)
@@ -598,6 +1076,54 @@ impl Emitable for RouteNetlinkMessage {
             | NewRule(ref msg)
             | DelRule(ref msg)
             | GetRule(ref msg)
+            => msg.buffer_len(),
+
+            | NewNexthop(ref msg)
+            | DelNexthop(ref msg)
+            | GetNexthop(ref msg)
+            | NewNexthopBucket(ref msg)
+            | DelNexthopBucket(ref msg)
+            | GetNexthopBucket(ref msg)
+            => msg.buffer_len(),
+
+            | NewMdb(ref msg)
+            | DelMdb(ref msg)
+            | GetMdb(ref msg)
+            => msg.buffer_len(),
+
+            | NewNetconf(ref msg)
+            | DelNetconf(ref msg)
+            | GetNetconf(ref msg)
+            => msg.buffer_len(),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
+            | SetStats(ref msg)
+            => msg.buffer_len(),
+
+            | NewBridgeVlan(ref msg)
+            | DelBridgeVlan(ref msg)
+            | GetBridgeVlan(ref msg)
+            => msg.buffer_len(),
+
+            | NewTunnel(ref msg)
+            | DelTunnel(ref msg)
+            | GetTunnel(ref msg)
+            => msg.buffer_len(),
+
+            | NewAddressLabel(ref msg)
+            | DelAddressLabel(ref msg)
+            | GetAddressLabel(ref msg)
+            => msg.buffer_len(),
+
+            | NewNdUserOption(ref msg)
+            => msg.buffer_len(),
+
+            | NewCacheReport(ref msg)
+            => msg.buffer_len(),
+
+            | GetDcb(ref msg)
+            | SetDcb(ref msg)
             => msg.buffer_len()
         }
     }
@@ -617,6 +1143,12 @@ impl Emitable for RouteNetlinkMessage {
             | NewAddress(ref msg)
             | DelAddress(ref msg)
             | GetAddress(ref msg)
+            | NewMulticastAddress(ref msg)
+            | DelMulticastAddress(ref msg)
+            | GetMulticastAddress(ref msg)
+            | NewAnycastAddress(ref msg)
+            | DelAnycastAddress(ref msg)
+            | GetAnycastAddress(ref msg)
             => msg.emit(buffer),
 
             | GetNeighbour(ref msg)
@@ -650,6 +1182,11 @@ impl Emitable for RouteNetlinkMessage {
             | GetTrafficChain(ref msg)
             => msg.emit(buffer),
 
+            | NewTrafficAction(ref msg)
+            | DelTrafficAction(ref msg)
+            | GetTrafficAction(ref msg)
+            => msg.emit(buffer),
+
             | NewNsId(ref msg)
             | DelNsId(ref msg)
             | GetNsId(ref msg)
@@ -658,6 +1195,54 @@ impl Emitable for RouteNetlinkMessage {
             | NewRule(ref msg)
             | DelRule(ref msg)
             | GetRule(ref msg)
+            => msg.emit(buffer),
+
+            | NewNexthop(ref msg)
+            | DelNexthop(ref msg)
+            | GetNexthop(ref msg)
+            | NewNexthopBucket(ref msg)
+            | DelNexthopBucket(ref msg)
+            | GetNexthopBucket(ref msg)
+            => msg.emit(buffer),
+
+            | NewMdb(ref msg)
+            | DelMdb(ref msg)
+            | GetMdb(ref msg)
+            => msg.emit(buffer),
+
+            | NewNetconf(ref msg)
+            | DelNetconf(ref msg)
+            | GetNetconf(ref msg)
+            => msg.emit(buffer),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
+            | SetStats(ref msg)
+            => msg.emit(buffer),
+
+            | NewBridgeVlan(ref msg)
+            | DelBridgeVlan(ref msg)
+            | GetBridgeVlan(ref msg)
+            => msg.emit(buffer),
+
+            | NewTunnel(ref msg)
+            | DelTunnel(ref msg)
+            | GetTunnel(ref msg)
+            => msg.emit(buffer),
+
+            | NewAddressLabel(ref msg)
+            | DelAddressLabel(ref msg)
+            | GetAddressLabel(ref msg)
+            => msg.emit(buffer),
+
+            | NewNdUserOption(ref msg)
+            => msg.emit(buffer),
+
+            | NewCacheReport(ref msg)
+            => msg.emit(buffer),
+
+            | GetDcb(ref msg)
+            | SetDcb(ref msg)
             => msg.emit(buffer)
         }
     }
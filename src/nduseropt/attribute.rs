@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    traits::Parseable,
+    DecodeError,
+};
+
+use crate::ip::{parse_ipv6_addr, IPV6_ADDR_LEN};
+
+const NDUSEROPT_SRCADDR: u16 = 1;
+
+/// Netlink attributes of `RTM_NEWNDUSEROPT` message
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NdUserOptionAttribute {
+    /// Source address of the ICMPv6 message, normally the link-local
+    /// address of the router
+    SourceAddress(Ipv6Addr),
+    Other(DefaultNla),
+}
+
+impl Nla for NdUserOptionAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::SourceAddress(_) => IPV6_ADDR_LEN,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::SourceAddress(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::SourceAddress(_) => NDUSEROPT_SRCADDR,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NdUserOptionAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NDUSEROPT_SRCADDR => {
+                Self::SourceAddress(parse_ipv6_addr(payload).context(
+                    format!("invalid NDUSEROPT_SRCADDR value {payload:?}"),
+                )?)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    option::VecNdUserOption, NdUserOption, NdUserOptionAttribute,
+    NdUserOptionHeader, NdUserOptionMessageBuffer,
+};
+
+/// The `RTM_NEWNDUSEROPT` message is multicasted by kernel to
+/// `RTNLGRP_ND_USEROPT` group holding ND options of received router
+/// advertisement which kernel does not handle, for example DNS servers.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct NdUserOptionMessage {
+    pub header: NdUserOptionHeader,
+    pub options: Vec<NdUserOption>,
+    pub attributes: Vec<NdUserOptionAttribute>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NdUserOptionMessageBuffer<&'a T>>
+    for NdUserOptionMessage
+{
+    fn parse(
+        buf: &NdUserOptionMessageBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: NdUserOptionHeader::parse(buf)
+                .context("failed to parse nduseropt message header")?,
+            options: VecNdUserOption::try_from(buf.options()?)
+                .context("failed to parse nduseropt message ND options")?
+                .0,
+            attributes: Vec::<NdUserOptionAttribute>::parse(buf)
+                .context("failed to parse nduseropt message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NdUserOptionMessageBuffer<&'a T>>
+    for Vec<NdUserOptionAttribute>
+{
+    fn parse(
+        buf: &NdUserOptionMessageBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        let mut attributes = vec![];
+        for nla_buf in buf.attributes() {
+            attributes.push(NdUserOptionAttribute::parse(&nla_buf?)?);
+        }
+        Ok(attributes)
+    }
+}
+
+impl NdUserOptionMessage {
+    fn options_len(&self) -> usize {
+        self.options.iter().map(|o| o.buffer_len()).sum()
+    }
+}
+
+impl Emitable for NdUserOptionMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len()
+            + self.options_len()
+            + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        NdUserOptionMessageBuffer::new(&mut *buffer)
+            .set_options_len(self.options_len() as u16);
+        let mut offset = self.header.buffer_len();
+        for option in self.options.iter() {
+            option.emit(&mut buffer[offset..]);
+            offset += option.buffer_len();
+        }
+        self.attributes.as_slice().emit(&mut buffer[offset..]);
+    }
+}
@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod header;
+mod message;
+mod option;
+#[cfg(test)]
+mod tests;
+
+pub use self::attribute::NdUserOptionAttribute;
+pub use self::header::{NdUserOptionHeader, NdUserOptionMessageBuffer};
+pub use self::message::NdUserOptionMessage;
+pub use self::option::{
+    NdUserOption, NdUserOptionDnssl, NdUserOptionPref64,
+    NdUserOptionPref64PrefixLen, NdUserOptionPrefixInfo,
+    NdUserOptionPrefixInfoFlags, NdUserOptionRdnss, NdUserOptionRouteInfo,
+};
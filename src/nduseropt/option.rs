@@ -0,0 +1,520 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use byteorder::{BigEndian, ByteOrder};
+use netlink_packet_utils::{traits::Emitable, DecodeError};
+
+use crate::{ip::IPV6_ADDR_LEN, route::RoutePreference};
+
+const ND_OPT_PREFIX_INFO: u8 = 3;
+const ND_OPT_ROUTE_INFO: u8 = 24;
+const ND_OPT_RDNSS: u8 = 25;
+const ND_OPT_DNSSL: u8 = 31;
+const ND_OPT_CAPTIVE_PORTAL: u8 = 37;
+const ND_OPT_PREF64: u8 = 38;
+
+// The length of ND option is in units of 8 octets
+const ND_OPT_LEN_UNIT: usize = 8;
+const ND_OPT_MAX_LEN: usize = u8::MAX as usize * ND_OPT_LEN_UNIT;
+const ND_OPT_HEADER_LEN: usize = 2;
+// Type, length, reserved(or prefix length and flags) and lifetime
+const ND_OPT_LIFETIME_HEADER_LEN: usize = 8;
+const ND_OPT_PREFIX_INFO_LEN: usize = 32;
+const ND_OPT_PREF64_LEN: usize = 16;
+const ND_OPT_PREF64_PREFIX_LEN: usize = 12;
+const DNS_LABEL_MAX_LEN: usize = 63;
+
+const ND_OPT_PI_FLAG_ONLINK: u8 = 0x80;
+const ND_OPT_PI_FLAG_AUTO: u8 = 0x40;
+const ND_OPT_PI_FLAG_ROUTER: u8 = 0x20;
+const ND_OPT_PI_FLAG_PREFER_PD: u8 = 0x10;
+
+/// The ND option copied from ICMPv6 router advertisement by kernel. Kernel
+/// only forwards prefix information, RDNSS, DNSSL, captive portal and PREF64
+/// options(`ndisc_is_useropt()`).
+///
+/// The wire length of option is a single octet in units of 8 octets, hence
+/// emitting an option longer than 2040 bytes panics. Emitting a DNSSL option
+/// also panics on a domain name without any label or on a label longer than
+/// 63 bytes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NdUserOption {
+    /// Prefix Information option defined in RFC 4861
+    PrefixInfo(NdUserOptionPrefixInfo),
+    /// Route Information option defined in RFC 4191. Kernel handles it by
+    /// itself and never forwards it.
+    RouteInfo(NdUserOptionRouteInfo),
+    /// Recursive DNS Server option defined in RFC 8106
+    Rdnss(NdUserOptionRdnss),
+    /// DNS Search List option defined in RFC 8106
+    Dnssl(NdUserOptionDnssl),
+    /// URI of captive portal API defined in RFC 8910
+    CaptivePortal(String),
+    /// NAT64 prefix option defined in RFC 8781
+    Pref64(NdUserOptionPref64),
+    Other {
+        kind: u8,
+        /// Option data following the type and length octets, including
+        /// padding
+        payload: Vec<u8>,
+    },
+}
+
+bitflags! {
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[non_exhaustive]
+    pub struct NdUserOptionPrefixInfoFlags: u8 {
+        /// On-link flag(L)
+        const OnLink = ND_OPT_PI_FLAG_ONLINK;
+        /// Autonomous address-configuration flag(A)
+        const Autonomous = ND_OPT_PI_FLAG_AUTO;
+        /// Router address flag(R) defined in RFC 6275
+        const RouterAddress = ND_OPT_PI_FLAG_ROUTER;
+        /// DHCPv6-PD preferred flag(P) defined in RFC 9762
+        const PreferPd = ND_OPT_PI_FLAG_PREFER_PD;
+        const _ = !0;
+    }
+}
+
+/// Lifetimes are in seconds, `u32::MAX` means infinity.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct NdUserOptionPrefixInfo {
+    pub prefix_len: u8,
+    pub flags: NdUserOptionPrefixInfoFlags,
+    pub valid_lifetime: u32,
+    pub preferred_lifetime: u32,
+    pub prefix: Ipv6Addr,
+}
+
+impl Default for NdUserOptionPrefixInfo {
+    fn default() -> Self {
+        Self {
+            prefix_len: 0,
+            flags: NdUserOptionPrefixInfoFlags::empty(),
+            valid_lifetime: 0,
+            preferred_lifetime: 0,
+            prefix: Ipv6Addr::UNSPECIFIED,
+        }
+    }
+}
+
+/// Lifetime is in seconds, `u32::MAX` means infinity.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct NdUserOptionRouteInfo {
+    pub prefix_len: u8,
+    pub preference: RoutePreference,
+    pub lifetime: u32,
+    pub prefix: Ipv6Addr,
+}
+
+impl Default for NdUserOptionRouteInfo {
+    fn default() -> Self {
+        Self {
+            prefix_len: 0,
+            preference: RoutePreference::Medium,
+            lifetime: 0,
+            prefix: Ipv6Addr::UNSPECIFIED,
+        }
+    }
+}
+
+/// Lifetimes are in seconds, `u32::MAX` means infinity.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct NdUserOptionRdnss {
+    pub lifetime: u32,
+    pub servers: Vec<Ipv6Addr>,
+}
+
+/// Lifetimes are in seconds, `u32::MAX` means infinity.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct NdUserOptionDnssl {
+    pub lifetime: u32,
+    /// Domain names without the trailing dot
+    pub domains: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct NdUserOptionPref64 {
+    /// Lifetime in seconds, stored on wire in units of 8 seconds
+    pub lifetime: u16,
+    pub prefix_len: NdUserOptionPref64PrefixLen,
+    pub prefix: Ipv6Addr,
+}
+
+impl Default for NdUserOptionPref64 {
+    fn default() -> Self {
+        Self {
+            lifetime: 0,
+            prefix_len: NdUserOptionPref64PrefixLen::default(),
+            prefix: Ipv6Addr::UNSPECIFIED,
+        }
+    }
+}
+
+/// The NAT64 prefix length, stored on wire as Prefix Length Code(PLC)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum NdUserOptionPref64PrefixLen {
+    #[default]
+    Len96,
+    Len64,
+    Len56,
+    Len48,
+    Len40,
+    Len32,
+}
+
+impl NdUserOptionPref64PrefixLen {
+    fn from_plc(plc: u16) -> Option<Self> {
+        Some(match plc {
+            0 => Self::Len96,
+            1 => Self::Len64,
+            2 => Self::Len56,
+            3 => Self::Len48,
+            4 => Self::Len40,
+            5 => Self::Len32,
+            _ => return None,
+        })
+    }
+
+    fn plc(&self) -> u16 {
+        match self {
+            Self::Len96 => 0,
+            Self::Len64 => 1,
+            Self::Len56 => 2,
+            Self::Len48 => 3,
+            Self::Len40 => 4,
+            Self::Len32 => 5,
+        }
+    }
+}
+
+impl From<NdUserOptionPref64PrefixLen> for u8 {
+    fn from(v: NdUserOptionPref64PrefixLen) -> u8 {
+        match v {
+            NdUserOptionPref64PrefixLen::Len96 => 96,
+            NdUserOptionPref64PrefixLen::Len64 => 64,
+            NdUserOptionPref64PrefixLen::Len56 => 56,
+            NdUserOptionPref64PrefixLen::Len48 => 48,
+            NdUserOptionPref64PrefixLen::Len40 => 40,
+            NdUserOptionPref64PrefixLen::Len32 => 32,
+        }
+    }
+}
+
+fn round_up_nd_opt_len(len: usize) -> usize {
+    len.div_ceil(ND_OPT_LEN_UNIT) * ND_OPT_LEN_UNIT
+}
+
+// The prefix of Route Information option is truncated to 0, 8 or 16 bytes
+// depending on the prefix length.
+fn route_info_prefix_bytes(prefix_len: u8) -> usize {
+    match prefix_len {
+        0 => 0,
+        1..=64 => 8,
+        _ => IPV6_ADDR_LEN,
+    }
+}
+
+fn dnssl_labels(domain: &str) -> impl Iterator<Item = &str> {
+    domain.split('.').filter(|l| !l.is_empty())
+}
+
+fn dnssl_domain_len(domain: &str) -> usize {
+    dnssl_labels(domain).map(|l| l.len() + 1).sum::<usize>() + 1
+}
+
+impl NdUserOption {
+    fn kind(&self) -> u8 {
+        match self {
+            Self::PrefixInfo(_) => ND_OPT_PREFIX_INFO,
+            Self::RouteInfo(_) => ND_OPT_ROUTE_INFO,
+            Self::Rdnss(_) => ND_OPT_RDNSS,
+            Self::Dnssl(_) => ND_OPT_DNSSL,
+            Self::CaptivePortal(_) => ND_OPT_CAPTIVE_PORTAL,
+            Self::Pref64(_) => ND_OPT_PREF64,
+            Self::Other { kind, .. } => *kind,
+        }
+    }
+
+    fn parse(kind: u8, raw: &[u8]) -> Result<Self, DecodeError> {
+        let min_len = match kind {
+            ND_OPT_ROUTE_INFO | ND_OPT_RDNSS | ND_OPT_DNSSL => {
+                ND_OPT_LIFETIME_HEADER_LEN
+            }
+            ND_OPT_PREFIX_INFO => ND_OPT_PREFIX_INFO_LEN,
+            ND_OPT_PREF64 => ND_OPT_PREF64_LEN,
+            _ => ND_OPT_HEADER_LEN,
+        };
+        if raw.len() < min_len {
+            return Err(DecodeError::from(format!(
+                "invalid ND option {kind}, expecting at least {min_len} \
+                bytes, but got {raw:?}"
+            )));
+        }
+        Ok(match kind {
+            ND_OPT_PREFIX_INFO => {
+                let mut prefix = [0u8; IPV6_ADDR_LEN];
+                prefix.copy_from_slice(&raw[16..ND_OPT_PREFIX_INFO_LEN]);
+                Self::PrefixInfo(NdUserOptionPrefixInfo {
+                    prefix_len: raw[2],
+                    flags: NdUserOptionPrefixInfoFlags::from_bits_retain(
+                        raw[3],
+                    ),
+                    valid_lifetime: BigEndian::read_u32(&raw[4..8]),
+                    preferred_lifetime: BigEndian::read_u32(&raw[8..12]),
+                    prefix: Ipv6Addr::from(prefix),
+                })
+            }
+            ND_OPT_ROUTE_INFO => {
+                let mut prefix = [0u8; IPV6_ADDR_LEN];
+                let data = &raw[ND_OPT_LIFETIME_HEADER_LEN..];
+                let len = data.len().min(IPV6_ADDR_LEN);
+                prefix[..len].copy_from_slice(&data[..len]);
+                Self::RouteInfo(NdUserOptionRouteInfo {
+                    prefix_len: raw[2],
+                    preference: ((raw[3] >> 3) & 0x3).into(),
+                    lifetime: BigEndian::read_u32(&raw[4..8]),
+                    prefix: Ipv6Addr::from(prefix),
+                })
+            }
+            ND_OPT_RDNSS => Self::Rdnss(NdUserOptionRdnss {
+                lifetime: BigEndian::read_u32(&raw[4..8]),
+                servers: raw[ND_OPT_LIFETIME_HEADER_LEN..]
+                    .chunks_exact(IPV6_ADDR_LEN)
+                    .map(|d| {
+                        let mut addr = [0u8; IPV6_ADDR_LEN];
+                        addr.copy_from_slice(d);
+                        Ipv6Addr::from(addr)
+                    })
+                    .collect(),
+            }),
+            ND_OPT_DNSSL => Self::Dnssl(NdUserOptionDnssl {
+                lifetime: BigEndian::read_u32(&raw[4..8]),
+                domains: parse_dnssl_domains(
+                    &raw[ND_OPT_LIFETIME_HEADER_LEN..],
+                )?,
+            }),
+            ND_OPT_CAPTIVE_PORTAL => {
+                let data = &raw[ND_OPT_HEADER_LEN..];
+                let end = data
+                    .iter()
+                    .rposition(|c| *c != 0)
+                    .map(|i| i + 1)
+                    .unwrap_or_default();
+                Self::CaptivePortal(
+                    String::from_utf8(data[..end].to_vec()).map_err(|e| {
+                        DecodeError::from(format!(
+                            "invalid captive portal ND option {e}"
+                        ))
+                    })?,
+                )
+            }
+            ND_OPT_PREF64 => {
+                let v = BigEndian::read_u16(&raw[2..4]);
+                match NdUserOptionPref64PrefixLen::from_plc(v & 0x7) {
+                    Some(prefix_len) => {
+                        let mut prefix = [0u8; IPV6_ADDR_LEN];
+                        prefix[..ND_OPT_PREF64_PREFIX_LEN]
+                            .copy_from_slice(&raw[4..ND_OPT_PREF64_LEN]);
+                        Self::Pref64(NdUserOptionPref64 {
+                            lifetime: v & !0x7,
+                            prefix_len,
+                            prefix: Ipv6Addr::from(prefix),
+                        })
+                    }
+                    None => Self::Other {
+                        kind,
+                        payload: raw[ND_OPT_HEADER_LEN..].to_vec(),
+                    },
+                }
+            }
+            _ => Self::Other {
+                kind,
+                payload: raw[ND_OPT_HEADER_LEN..].to_vec(),
+            },
+        })
+    }
+}
+
+fn parse_dnssl_domains(raw: &[u8]) -> Result<Vec<String>, DecodeError> {
+    let mut domains = Vec::new();
+    let mut labels: Vec<String> = Vec::new();
+    let mut i = 0;
+    while i < raw.len() {
+        let len = raw[i] as usize;
+        i += 1;
+        if len > DNS_LABEL_MAX_LEN {
+            return Err(DecodeError::from(format!(
+                "invalid DNSSL ND option, label length {len} exceeded \
+                {DNS_LABEL_MAX_LEN}: {raw:?}"
+            )));
+        }
+        if len == 0 {
+            // Empty domain name means padding
+            if labels.is_empty() {
+                break;
+            }
+            domains.push(labels.join("."));
+            labels.clear();
+            continue;
+        }
+        if i + len > raw.len() {
+            return Err(DecodeError::from(format!(
+                "invalid DNSSL ND option, label exceeded the option: {raw:?}"
+            )));
+        }
+        labels.push(String::from_utf8(raw[i..i + len].to_vec()).map_err(
+            |e| DecodeError::from(format!("invalid DNSSL ND option {e}")),
+        )?);
+        i += len;
+    }
+    if !labels.is_empty() {
+        return Err(DecodeError::from(format!(
+            "invalid DNSSL ND option, domain name not terminated: {raw:?}"
+        )));
+    }
+    Ok(domains)
+}
+
+impl Emitable for NdUserOption {
+    fn buffer_len(&self) -> usize {
+        match self {
+            Self::PrefixInfo(_) => ND_OPT_PREFIX_INFO_LEN,
+            Self::RouteInfo(v) => {
+                ND_OPT_LIFETIME_HEADER_LEN
+                    + route_info_prefix_bytes(v.prefix_len)
+            }
+            Self::Rdnss(v) => {
+                ND_OPT_LIFETIME_HEADER_LEN + v.servers.len() * IPV6_ADDR_LEN
+            }
+            Self::Dnssl(v) => round_up_nd_opt_len(
+                ND_OPT_LIFETIME_HEADER_LEN
+                    + v.domains
+                        .iter()
+                        .map(|d| dnssl_domain_len(d))
+                        .sum::<usize>(),
+            ),
+            Self::CaptivePortal(v) => {
+                round_up_nd_opt_len(ND_OPT_HEADER_LEN + v.len())
+            }
+            Self::Pref64(_) => ND_OPT_PREF64_LEN,
+            Self::Other { payload, .. } => {
+                round_up_nd_opt_len(ND_OPT_HEADER_LEN + payload.len())
+            }
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let len = self.buffer_len();
+        assert!(
+            len <= ND_OPT_MAX_LEN,
+            "ND option {} of {len} bytes exceeded {ND_OPT_MAX_LEN} bytes",
+            self.kind()
+        );
+        let buffer = &mut buffer[..len];
+        buffer.fill(0);
+        buffer[0] = self.kind();
+        buffer[1] = (len / ND_OPT_LEN_UNIT) as u8;
+        match self {
+            Self::PrefixInfo(v) => {
+                buffer[2] = v.prefix_len;
+                buffer[3] = v.flags.bits();
+                BigEndian::write_u32(&mut buffer[4..8], v.valid_lifetime);
+                BigEndian::write_u32(&mut buffer[8..12], v.preferred_lifetime);
+                buffer[16..ND_OPT_PREFIX_INFO_LEN]
+                    .copy_from_slice(&v.prefix.octets());
+            }
+            Self::RouteInfo(v) => {
+                buffer[2] = v.prefix_len;
+                buffer[3] = (u8::from(v.preference) & 0x3) << 3;
+                BigEndian::write_u32(&mut buffer[4..8], v.lifetime);
+                let prefix_len = route_info_prefix_bytes(v.prefix_len);
+                buffer[ND_OPT_LIFETIME_HEADER_LEN..]
+                    .copy_from_slice(&v.prefix.octets()[..prefix_len]);
+            }
+            Self::Rdnss(v) => {
+                BigEndian::write_u32(&mut buffer[4..8], v.lifetime);
+                for (i, addr) in v.servers.iter().enumerate() {
+                    let offset = ND_OPT_LIFETIME_HEADER_LEN + i * IPV6_ADDR_LEN;
+                    buffer[offset..offset + IPV6_ADDR_LEN]
+                        .copy_from_slice(&addr.octets());
+                }
+            }
+            Self::Dnssl(v) => {
+                BigEndian::write_u32(&mut buffer[4..8], v.lifetime);
+                let mut offset = ND_OPT_LIFETIME_HEADER_LEN;
+                for domain in v.domains.iter() {
+                    // Empty domain name would be parsed as padding
+                    assert!(
+                        dnssl_labels(domain).next().is_some(),
+                        "empty domain name in DNSSL ND option"
+                    );
+                    for label in dnssl_labels(domain) {
+                        assert!(
+                            label.len() <= DNS_LABEL_MAX_LEN,
+                            "DNS label {label} of DNSSL ND option exceeded \
+                            {DNS_LABEL_MAX_LEN} bytes"
+                        );
+                        buffer[offset] = label.len() as u8;
+                        offset += 1;
+                        buffer[offset..offset + label.len()]
+                            .copy_from_slice(label.as_bytes());
+                        offset += label.len();
+                    }
+                    // Terminating empty label was zeroed already
+                    offset += 1;
+                }
+            }
+            Self::CaptivePortal(v) => {
+                buffer[ND_OPT_HEADER_LEN..ND_OPT_HEADER_LEN + v.len()]
+                    .copy_from_slice(v.as_bytes());
+            }
+            Self::Pref64(v) => {
+                BigEndian::write_u16(
+                    &mut buffer[2..4],
+                    (v.lifetime & !0x7) | v.prefix_len.plc(),
+                );
+                buffer[4..ND_OPT_PREF64_LEN].copy_from_slice(
+                    &v.prefix.octets()[..ND_OPT_PREF64_PREFIX_LEN],
+                );
+            }
+            Self::Other { payload, .. } => {
+                buffer[ND_OPT_HEADER_LEN..ND_OPT_HEADER_LEN + payload.len()]
+                    .copy_from_slice(payload);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct VecNdUserOption(pub(crate) Vec<NdUserOption>);
+
+impl TryFrom<&[u8]> for VecNdUserOption {
+    type Error = DecodeError;
+
+    fn try_from(raw: &[u8]) -> Result<Self, DecodeError> {
+        let mut options = Vec::new();
+        let mut offset = 0;
+        while offset + ND_OPT_HEADER_LEN <= raw.len() {
+            let kind = raw[offset];
+            let len = raw[offset + 1] as usize * ND_OPT_LEN_UNIT;
+            if len == 0 || offset + len > raw.len() {
+                return Err(DecodeError::from(format!(
+                    "invalid ND option {kind} with length {len}: {raw:?}"
+                )));
+            }
+            options
+                .push(NdUserOption::parse(kind, &raw[offset..offset + len])?);
+            offset += len;
+        }
+        Ok(Self(options))
+    }
+}
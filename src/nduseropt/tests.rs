@@ -0,0 +1,377 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::{
+    nduseropt::{
+        NdUserOption, NdUserOptionAttribute, NdUserOptionDnssl,
+        NdUserOptionHeader, NdUserOptionMessage, NdUserOptionMessageBuffer,
+        NdUserOptionPref64, NdUserOptionPref64PrefixLen,
+        NdUserOptionPrefixInfo, NdUserOptionPrefixInfoFlags, NdUserOptionRdnss,
+        NdUserOptionRouteInfo,
+    },
+    route::RoutePreference,
+    AddressFamily,
+};
+
+// Setup:
+//      ip link add d0 type veth peer name d1
+//      sysctl -w net.ipv6.conf.d0.accept_ra=2
+//      # Move d1 to another network namespace, bring both up, then send
+//      # router advertisement via d1 holding RDNSS option of
+//      # 2001:4860::8888 and 2001:4860::8844 with lifetime 4660.
+// wireshark capture(netlink message header removed) of nlmon against
+// RTNLGRP_ND_USEROPT multicast group.
+#[test]
+fn test_nduseropt_rdnss() {
+    let raw = vec![
+        0x0a, 0x00, 0x28, 0x00, 0x03, 0x00, 0x00, 0x00, 0x86, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x19, 0x05, 0x00, 0x00, 0x00, 0x00, 0x12, 0x34,
+        0x20, 0x01, 0x48, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x88, 0x88, 0x20, 0x01, 0x48, 0x60, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x88, 0x44, 0x14, 0x00, 0x01, 0x00,
+        0xfe, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc8, 0x35, 0x79, 0xff,
+        0xfe, 0xc5, 0xca, 0x1a,
+    ];
+
+    let expected = NdUserOptionMessage {
+        header: NdUserOptionHeader {
+            family: AddressFamily::Inet6,
+            ifindex: 3,
+            icmp_type: 134,
+            icmp_code: 0,
+        },
+        options: vec![NdUserOption::Rdnss(NdUserOptionRdnss {
+            lifetime: 4660,
+            servers: vec![
+                Ipv6Addr::from_str("2001:4860::8888").unwrap(),
+                Ipv6Addr::from_str("2001:4860::8844").unwrap(),
+            ],
+        })],
+        attributes: vec![NdUserOptionAttribute::SourceAddress(
+            Ipv6Addr::from_str("fe80::c835:79ff:fec5:ca1a").unwrap(),
+        )],
+    };
+
+    assert_eq!(
+        expected,
+        NdUserOptionMessage::parse(&NdUserOptionMessageBuffer::new(&raw))
+            .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup is the same as `test_nduseropt_rdnss` except router advertisement is
+// holding DNSSL option of `example.com` with lifetime 3600.
+// wireshark capture(netlink message header removed) of nlmon against
+// RTNLGRP_ND_USEROPT multicast group.
+#[test]
+fn test_nduseropt_dnssl() {
+    let raw = vec![
+        0x0a, 0x00, 0x18, 0x00, 0x03, 0x00, 0x00, 0x00, 0x86, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x1f, 0x03, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x10,
+        0x07, 0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x03, 0x63, 0x6f, 0x6d,
+        0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x01, 0x00, 0xfe, 0x80, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x98, 0x22, 0xf0, 0xff, 0xfe, 0x1b, 0x90, 0x7c,
+    ];
+
+    let expected = NdUserOptionMessage {
+        header: NdUserOptionHeader {
+            family: AddressFamily::Inet6,
+            ifindex: 3,
+            icmp_type: 134,
+            icmp_code: 0,
+        },
+        options: vec![NdUserOption::Dnssl(NdUserOptionDnssl {
+            lifetime: 3600,
+            domains: vec!["example.com".to_string()],
+        })],
+        attributes: vec![NdUserOptionAttribute::SourceAddress(
+            Ipv6Addr::from_str("fe80::9822:f0ff:fe1b:907c").unwrap(),
+        )],
+    };
+
+    assert_eq!(
+        expected,
+        NdUserOptionMessage::parse(&NdUserOptionMessageBuffer::new(&raw))
+            .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup is the same as `test_nduseropt_rdnss` except router advertisement is
+// holding captive portal option of `https://cp.example/`.
+// wireshark capture(netlink message header removed) of nlmon against
+// RTNLGRP_ND_USEROPT multicast group.
+#[test]
+fn test_nduseropt_captive_portal() {
+    let raw = vec![
+        0x0a, 0x00, 0x18, 0x00, 0x03, 0x00, 0x00, 0x00, 0x86, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x25, 0x03, 0x68, 0x74, 0x74, 0x70, 0x73, 0x3a,
+        0x2f, 0x2f, 0x63, 0x70, 0x2e, 0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65,
+        0x2f, 0x00, 0x00, 0x00, 0x14, 0x00, 0x01, 0x00, 0xfe, 0x80, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x98, 0x22, 0xf0, 0xff, 0xfe, 0x1b, 0x90, 0x7c,
+    ];
+
+    let expected = NdUserOptionMessage {
+        header: NdUserOptionHeader {
+            family: AddressFamily::Inet6,
+            ifindex: 3,
+            icmp_type: 134,
+            icmp_code: 0,
+        },
+        options: vec![NdUserOption::CaptivePortal(
+            "https://cp.example/".to_string(),
+        )],
+        attributes: vec![NdUserOptionAttribute::SourceAddress(
+            Ipv6Addr::from_str("fe80::9822:f0ff:fe1b:907c").unwrap(),
+        )],
+    };
+
+    assert_eq!(
+        expected,
+        NdUserOptionMessage::parse(&NdUserOptionMessageBuffer::new(&raw))
+            .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup is the same as `test_nduseropt_rdnss` except router advertisement is
+// holding PREF64 option of `64:ff9b::/96` with lifetime 600.
+// wireshark capture(netlink message header removed) of nlmon against
+// RTNLGRP_ND_USEROPT multicast group.
+#[test]
+fn test_nduseropt_pref64() {
+    let raw = vec![
+        0x0a, 0x00, 0x10, 0x00, 0x03, 0x00, 0x00, 0x00, 0x86, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x26, 0x02, 0x02, 0x58, 0x00, 0x64, 0xff, 0x9b,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x01, 0x00,
+        0xfe, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x98, 0x22, 0xf0, 0xff,
+        0xfe, 0x1b, 0x90, 0x7c,
+    ];
+
+    let expected = NdUserOptionMessage {
+        header: NdUserOptionHeader {
+            family: AddressFamily::Inet6,
+            ifindex: 3,
+            icmp_type: 134,
+            icmp_code: 0,
+        },
+        options: vec![NdUserOption::Pref64(NdUserOptionPref64 {
+            lifetime: 600,
+            prefix_len: NdUserOptionPref64PrefixLen::Len96,
+            prefix: Ipv6Addr::from_str("64:ff9b::").unwrap(),
+        })],
+        attributes: vec![NdUserOptionAttribute::SourceAddress(
+            Ipv6Addr::from_str("fe80::9822:f0ff:fe1b:907c").unwrap(),
+        )],
+    };
+
+    assert_eq!(
+        expected,
+        NdUserOptionMessage::parse(&NdUserOptionMessageBuffer::new(&raw))
+            .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup is the same as `test_nduseropt_rdnss` except router advertisement is
+// holding prefix information option of `2001:db8:1::/64` with on-link and
+// autonomous flags, valid lifetime 86400 and preferred lifetime 14400.
+// wireshark capture(netlink message header removed) of nlmon against
+// RTNLGRP_ND_USEROPT multicast group.
+#[test]
+fn test_nduseropt_prefix_info() {
+    let raw = vec![
+        0x0a, 0x00, 0x20, 0x00, 0x03, 0x00, 0x00, 0x00, 0x86, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x03, 0x04, 0x40, 0xc0, 0x00, 0x01, 0x51, 0x80,
+        0x00, 0x00, 0x38, 0x40, 0x00, 0x00, 0x00, 0x00, 0x20, 0x01, 0x0d, 0xb8,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x01, 0x00, 0xfe, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x80, 0x5f, 0x40, 0xff, 0xfe, 0x41, 0xd6, 0x3f,
+    ];
+
+    let expected = NdUserOptionMessage {
+        header: NdUserOptionHeader {
+            family: AddressFamily::Inet6,
+            ifindex: 3,
+            icmp_type: 134,
+            icmp_code: 0,
+        },
+        options: vec![NdUserOption::PrefixInfo(NdUserOptionPrefixInfo {
+            prefix_len: 64,
+            flags: NdUserOptionPrefixInfoFlags::OnLink
+                | NdUserOptionPrefixInfoFlags::Autonomous,
+            valid_lifetime: 86400,
+            preferred_lifetime: 14400,
+            prefix: Ipv6Addr::from_str("2001:db8:1::").unwrap(),
+        })],
+        attributes: vec![NdUserOptionAttribute::SourceAddress(
+            Ipv6Addr::from_str("fe80::805f:40ff:fe41:d63f").unwrap(),
+        )],
+    };
+
+    assert_eq!(
+        expected,
+        NdUserOptionMessage::parse(&NdUserOptionMessageBuffer::new(&raw))
+            .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Kernel handles route information option by itself instead of forwarding it
+// (`ndisc_is_useropt()`), this raw message(header removed) is crafted to hold
+// route information option of `2001:db8:1::/48` with high preference and
+// lifetime 1800.
+#[test]
+fn test_nduseropt_route_info() {
+    let raw = vec![
+        0x0a, 0x00, 0x10, 0x00, 0x03, 0x00, 0x00, 0x00, 0x86, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x18, 0x02, 0x30, 0x08, 0x00, 0x00, 0x07, 0x08,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x01, 0x00, 0x00,
+    ];
+
+    let expected = NdUserOptionMessage {
+        header: NdUserOptionHeader {
+            family: AddressFamily::Inet6,
+            ifindex: 3,
+            icmp_type: 134,
+            icmp_code: 0,
+        },
+        options: vec![NdUserOption::RouteInfo(NdUserOptionRouteInfo {
+            prefix_len: 48,
+            preference: RoutePreference::High,
+            lifetime: 1800,
+            prefix: Ipv6Addr::from_str("2001:db8:1::").unwrap(),
+        })],
+        attributes: vec![],
+    };
+
+    assert_eq!(
+        expected,
+        NdUserOptionMessage::parse(&NdUserOptionMessageBuffer::new(&raw))
+            .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_nduseropt_max_len_option() {
+    let expected = NdUserOptionMessage {
+        header: NdUserOptionHeader {
+            family: AddressFamily::Inet6,
+            ifindex: 3,
+            icmp_type: 134,
+            icmp_code: 0,
+        },
+        options: vec![NdUserOption::Other {
+            kind: 253,
+            payload: vec![0xff; 2038],
+        }],
+        attributes: vec![],
+    };
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    // nduseropt_opts_len, then type and length(in units of 8 octets) of
+    // the ND option
+    assert_eq!(&buf[2..4], 2040u16.to_ne_bytes().as_slice());
+    assert_eq!(&buf[16..18], &[253, 255]);
+    assert_eq!(
+        expected,
+        NdUserOptionMessage::parse(&NdUserOptionMessageBuffer::new(&buf))
+            .unwrap()
+    );
+}
+
+#[test]
+#[should_panic(expected = "ND option 253 of 2048 bytes exceeded 2040 bytes")]
+fn test_nduseropt_emit_oversized_option() {
+    let option = NdUserOption::Other {
+        kind: 253,
+        payload: vec![0xff; 2039],
+    };
+
+    let mut buf = vec![0; option.buffer_len()];
+
+    option.emit(&mut buf);
+}
+
+#[test]
+#[should_panic(expected = "of DNSSL ND option exceeded 63 bytes")]
+fn test_nduseropt_emit_dnssl_oversized_label() {
+    let option = NdUserOption::Dnssl(NdUserOptionDnssl {
+        lifetime: 3600,
+        domains: vec![format!("{}.example", "a".repeat(64))],
+    });
+
+    let mut buf = vec![0; option.buffer_len()];
+
+    option.emit(&mut buf);
+}
+
+#[test]
+#[should_panic(expected = "empty domain name in DNSSL ND option")]
+fn test_nduseropt_emit_dnssl_empty_domain() {
+    let option = NdUserOption::Dnssl(NdUserOptionDnssl {
+        lifetime: 3600,
+        domains: vec!["example.com".to_string(), String::new()],
+    });
+
+    let mut buf = vec![0; option.buffer_len()];
+
+    option.emit(&mut buf);
+}
+
+// DNSSL option holding a label of 64 bytes, which exceeds the 63 bytes limit
+// of DNS label.
+#[test]
+fn test_nduseropt_parse_dnssl_oversized_label() {
+    let mut raw = vec![
+        0x0a, 0x00, 0x50, 0x00, 0x03, 0x00, 0x00, 0x00, 0x86, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x1f, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x10,
+        0x40,
+    ];
+    raw.extend_from_slice(&[0x61; 64]);
+    raw.resize(16 + 80, 0);
+
+    assert!(
+        NdUserOptionMessage::parse(&NdUserOptionMessageBuffer::new(&raw))
+            .is_err()
+    );
+}
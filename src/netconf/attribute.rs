@@ -0,0 +1,170 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_i32,
+    traits::Parseable,
+    DecodeError,
+};
+
+const NETCONFA_IFINDEX: u16 = 1;
+const NETCONFA_FORWARDING: u16 = 2;
+const NETCONFA_RP_FILTER: u16 = 3;
+const NETCONFA_MC_FORWARDING: u16 = 4;
+const NETCONFA_PROXY_NEIGH: u16 = 5;
+const NETCONFA_IGNORE_ROUTES_WITH_LINKDOWN: u16 = 6;
+const NETCONFA_INPUT: u16 = 7;
+const NETCONFA_BC_FORWARDING: u16 = 8;
+
+/// Netlink attributes of `RTM_NEWNETCONF`, `RTM_DELNETCONF` and
+/// `RTM_GETNETCONF` messages. Besides [NetconfAttribute::Ifindex], the
+/// attributes hold the value of the corresponding sysctl, e.g.
+/// `net.ipv4.conf.<dev>.rp_filter`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NetconfAttribute {
+    Ifindex(NetconfIndex),
+    Forwarding(i32),
+    /// IPv4 only
+    RpFilter(i32),
+    McForwarding(i32),
+    ProxyNeigh(i32),
+    IgnoreRoutesWithLinkdown(i32),
+    /// MPLS only
+    Input(i32),
+    /// IPv4 only
+    BcForwarding(i32),
+    Other(DefaultNla),
+}
+
+impl Nla for NetconfAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ifindex(_)
+            | Self::Forwarding(_)
+            | Self::RpFilter(_)
+            | Self::McForwarding(_)
+            | Self::ProxyNeigh(_)
+            | Self::IgnoreRoutesWithLinkdown(_)
+            | Self::Input(_)
+            | Self::BcForwarding(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ifindex(v) => NativeEndian::write_i32(buffer, (*v).into()),
+            Self::Forwarding(v)
+            | Self::RpFilter(v)
+            | Self::McForwarding(v)
+            | Self::ProxyNeigh(v)
+            | Self::IgnoreRoutesWithLinkdown(v)
+            | Self::Input(v)
+            | Self::BcForwarding(v) => NativeEndian::write_i32(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ifindex(_) => NETCONFA_IFINDEX,
+            Self::Forwarding(_) => NETCONFA_FORWARDING,
+            Self::RpFilter(_) => NETCONFA_RP_FILTER,
+            Self::McForwarding(_) => NETCONFA_MC_FORWARDING,
+            Self::ProxyNeigh(_) => NETCONFA_PROXY_NEIGH,
+            Self::IgnoreRoutesWithLinkdown(_) => {
+                NETCONFA_IGNORE_ROUTES_WITH_LINKDOWN
+            }
+            Self::Input(_) => NETCONFA_INPUT,
+            Self::BcForwarding(_) => NETCONFA_BC_FORWARDING,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NetconfAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NETCONFA_IFINDEX => Self::Ifindex(
+                parse_i32(payload)
+                    .context("invalid NETCONFA_IFINDEX value")?
+                    .into(),
+            ),
+            NETCONFA_FORWARDING => Self::Forwarding(
+                parse_i32(payload)
+                    .context("invalid NETCONFA_FORWARDING value")?,
+            ),
+            NETCONFA_RP_FILTER => Self::RpFilter(
+                parse_i32(payload)
+                    .context("invalid NETCONFA_RP_FILTER value")?,
+            ),
+            NETCONFA_MC_FORWARDING => Self::McForwarding(
+                parse_i32(payload)
+                    .context("invalid NETCONFA_MC_FORWARDING value")?,
+            ),
+            NETCONFA_PROXY_NEIGH => Self::ProxyNeigh(
+                parse_i32(payload)
+                    .context("invalid NETCONFA_PROXY_NEIGH value")?,
+            ),
+            NETCONFA_IGNORE_ROUTES_WITH_LINKDOWN => {
+                Self::IgnoreRoutesWithLinkdown(parse_i32(payload).context(
+                    "invalid NETCONFA_IGNORE_ROUTES_WITH_LINKDOWN value",
+                )?)
+            }
+            NETCONFA_INPUT => Self::Input(
+                parse_i32(payload).context("invalid NETCONFA_INPUT value")?,
+            ),
+            NETCONFA_BC_FORWARDING => Self::BcForwarding(
+                parse_i32(payload)
+                    .context("invalid NETCONFA_BC_FORWARDING value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+const NETCONFA_IFINDEX_ALL: i32 = -1;
+const NETCONFA_IFINDEX_DEFAULT: i32 = -2;
+
+/// Interface index of `NETCONFA_IFINDEX`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum NetconfIndex {
+    /// The `all` configuration, e.g. `net.ipv4.conf.all`
+    All,
+    /// The `default` configuration, e.g. `net.ipv4.conf.default`
+    Default,
+    Index(u32),
+    Other(i32),
+}
+
+impl From<i32> for NetconfIndex {
+    fn from(d: i32) -> Self {
+        match d {
+            NETCONFA_IFINDEX_ALL => Self::All,
+            NETCONFA_IFINDEX_DEFAULT => Self::Default,
+            d if d >= 0 => Self::Index(d as u32),
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<NetconfIndex> for i32 {
+    fn from(v: NetconfIndex) -> i32 {
+        match v {
+            NetconfIndex::All => NETCONFA_IFINDEX_ALL,
+            NetconfIndex::Default => NETCONFA_IFINDEX_DEFAULT,
+            NetconfIndex::Index(d) => d as i32,
+            NetconfIndex::Other(d) => d,
+        }
+    }
+}
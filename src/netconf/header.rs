@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::AddressFamily;
+
+// The `struct netconfmsg` only holds a u8 family, but the attributes are
+// placed at `NLMSG_ALIGN(sizeof(struct netconfmsg))`.
+const NETCONF_HEADER_LEN: usize = 4;
+// Userspace may send the unpadded `struct netconfmsg` when no attribute
+// included.
+const NETCONF_HEADER_MIN_LEN: usize = 1;
+
+buffer!(NetconfMessageBuffer { family: (u8, 0) });
+
+impl<T: AsRef<[u8]>> NetconfMessageBuffer<T> {
+    pub fn new_checked(buffer: T) -> Result<Self, DecodeError> {
+        let packet = Self::new(buffer);
+        packet.check_buffer_length()?;
+        Ok(packet)
+    }
+
+    fn check_buffer_length(&self) -> Result<(), DecodeError> {
+        let len = self.buffer.as_ref().len();
+        if len < NETCONF_HEADER_MIN_LEN {
+            Err(format!(
+                "invalid NetconfMessageBuffer: length {len} < \
+                {NETCONF_HEADER_MIN_LEN}"
+            )
+            .into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> NetconfMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        let data: &'a [u8] = self.buffer.as_ref();
+        NlasIterator::new(data.get(NETCONF_HEADER_LEN..).unwrap_or_default())
+    }
+}
+
+/// High level representation of `RTM_NEWNETCONF`, `RTM_DELNETCONF` and
+/// `RTM_GETNETCONF` messages headers(`struct netconfmsg`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NetconfHeader {
+    /// [AddressFamily::Inet], [AddressFamily::Inet6] or
+    /// [AddressFamily::Mpls]
+    pub family: AddressFamily,
+}
+
+impl Emitable for NetconfHeader {
+    fn buffer_len(&self) -> usize {
+        NETCONF_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[..NETCONF_HEADER_LEN].fill(0);
+        let mut packet = NetconfMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<NetconfMessageBuffer<T>> for NetconfHeader {
+    fn parse(buf: &NetconfMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family().into(),
+        })
+    }
+}
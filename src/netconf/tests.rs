@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::{
+    netconf::{
+        NetconfAttribute, NetconfHeader, NetconfIndex, NetconfMessage,
+        NetconfMessageBuffer,
+    },
+    AddressFamily,
+};
+
+// Setup:
+//      ip link add veth0 type veth peer name veth1
+//      sysctl -w net.ipv4.conf.veth0.rp_filter=2
+//      sysctl -w net.ipv4.conf.veth0.forwarding=1
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      ip -4 netconf show dev veth0
+#[test]
+fn test_ipv4_netconf_of_interface() {
+    let raw = vec![
+        0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = NetconfMessage {
+        header: NetconfHeader {
+            family: AddressFamily::Inet,
+        },
+        attributes: vec![
+            NetconfAttribute::Ifindex(NetconfIndex::Index(3)),
+            NetconfAttribute::Forwarding(1),
+            NetconfAttribute::RpFilter(2),
+            NetconfAttribute::McForwarding(0),
+            NetconfAttribute::BcForwarding(0),
+            NetconfAttribute::ProxyNeigh(0),
+            NetconfAttribute::IgnoreRoutesWithLinkdown(0),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NetconfMessage::parse(&NetconfMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      sysctl -w net.ipv6.conf.default.forwarding=1
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      ip -6 netconf show dev default
+#[test]
+fn test_ipv6_netconf_of_default() {
+    let raw = vec![
+        0x0a, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0xfe, 0xff, 0xff, 0xff,
+        0x08, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = NetconfMessage {
+        header: NetconfHeader {
+            family: AddressFamily::Inet6,
+        },
+        attributes: vec![
+            NetconfAttribute::Ifindex(NetconfIndex::Default),
+            NetconfAttribute::Forwarding(1),
+            NetconfAttribute::McForwarding(0),
+            NetconfAttribute::ProxyNeigh(0),
+            NetconfAttribute::IgnoreRoutesWithLinkdown(0),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NetconfMessage::parse(&NetconfMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Userspace may send `RTM_GETNETCONF` dump request with unpadded
+// `struct netconfmsg`.
+#[test]
+fn test_netconf_dump_request_without_padding() {
+    let raw = vec![0x0a];
+
+    let expected = NetconfMessage {
+        header: NetconfHeader {
+            family: AddressFamily::Inet6,
+        },
+        attributes: vec![],
+    };
+
+    assert_eq!(
+        expected,
+        NetconfMessage::parse(
+            &NetconfMessageBuffer::new_checked(&raw).unwrap()
+        )
+        .unwrap()
+    );
+}
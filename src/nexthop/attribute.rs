@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use super::{
+    group::VecNexthopGroup, NexthopBucket, NexthopGroup, NexthopGroupType,
+    NexthopResilientGroup,
+};
+use crate::{
+    route::{
+        RouteAddress, RouteLwEnCapType, RouteLwTunnelEncap,
+        VecRouteLwTunnelEncap,
+    },
+    AddressFamily,
+};
+
+const NHA_ID: u16 = 1;
+const NHA_GROUP: u16 = 2;
+const NHA_GROUP_TYPE: u16 = 3;
+const NHA_BLACKHOLE: u16 = 4;
+const NHA_OIF: u16 = 5;
+const NHA_GATEWAY: u16 = 6;
+pub(crate) const NHA_ENCAP_TYPE: u16 = 7;
+const NHA_ENCAP: u16 = 8;
+const NHA_GROUPS: u16 = 9;
+const NHA_MASTER: u16 = 10;
+const NHA_FDB: u16 = 11;
+const NHA_RES_GROUP: u16 = 12;
+const NHA_RES_BUCKET: u16 = 13;
+
+/// Netlink attributes for `RTM_NEWNEXTHOP`, `RTM_DELNEXTHOP`,
+/// `RTM_GETNEXTHOP` and nexthop bucket netlink messages.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NexthopAttribute {
+    Id(u32),
+    Group(Vec<NexthopGroup>),
+    GroupType(NexthopGroupType),
+    Blackhole,
+    Oif(u32),
+    Gateway(RouteAddress),
+    EncapType(RouteLwEnCapType),
+    Encap(Vec<RouteLwTunnelEncap>),
+    /// Only dump nexthop groups, only valid in `RTM_GETNEXTHOP` dump request.
+    Groups,
+    /// Only dump nexthops using devices enslaved to specified master device
+    /// index, only valid in `RTM_GETNEXTHOP` dump request.
+    Master(u32),
+    /// Nexthop belongs to a bridge FDB. When used in `RTM_GETNEXTHOP` dump
+    /// request, only dump FDB nexthops.
+    Fdb,
+    /// Attributes of resilient nexthop group
+    ResilientGroup(Vec<NexthopResilientGroup>),
+    /// Nexthop bucket of resilient nexthop group, only valid in
+    /// `RTM_NEWNEXTHOPBUCKET`, `RTM_DELNEXTHOPBUCKET` and
+    /// `RTM_GETNEXTHOPBUCKET` messages.
+    Bucket(Vec<NexthopBucket>),
+    Other(DefaultNla),
+}
+
+impl Nla for NexthopAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Blackhole | Self::Groups | Self::Fdb => 0,
+            Self::GroupType(_) => 2,
+            Self::Id(_) | Self::Oif(_) | Self::Master(_) => 4,
+            Self::Group(entries) => {
+                entries.iter().map(|entry| entry.buffer_len()).sum()
+            }
+            Self::Gateway(v) => v.buffer_len(),
+            Self::EncapType(v) => v.buffer_len(),
+            Self::Encap(v) => v.as_slice().buffer_len(),
+            Self::ResilientGroup(v) => v.as_slice().buffer_len(),
+            Self::Bucket(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Blackhole | Self::Groups | Self::Fdb => (),
+            Self::GroupType(v) => {
+                NativeEndian::write_u16(buffer, u16::from(*v))
+            }
+            Self::Id(value) | Self::Oif(value) | Self::Master(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Group(entries) => {
+                let mut offset = 0;
+                for entry in entries {
+                    let len = entry.buffer_len();
+                    entry.emit(&mut buffer[offset..offset + len]);
+                    offset += len
+                }
+            }
+            Self::Gateway(v) => v.emit(buffer),
+            Self::EncapType(v) => v.emit(buffer),
+            Self::Encap(v) => v.as_slice().emit(buffer),
+            Self::ResilientGroup(v) => v.as_slice().emit(buffer),
+            Self::Bucket(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => NHA_ID,
+            Self::Group(_) => NHA_GROUP,
+            Self::GroupType(_) => NHA_GROUP_TYPE,
+            Self::Blackhole => NHA_BLACKHOLE,
+            Self::Oif(_) => NHA_OIF,
+            Self::Gateway(_) => NHA_GATEWAY,
+            Self::EncapType(_) => NHA_ENCAP_TYPE,
+            Self::Encap(_) => NHA_ENCAP,
+            Self::Groups => NHA_GROUPS,
+            Self::Master(_) => NHA_MASTER,
+            Self::Fdb => NHA_FDB,
+            Self::ResilientGroup(_) => NHA_RES_GROUP | NLA_F_NESTED,
+            Self::Bucket(_) => NHA_RES_BUCKET | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NlaBuffer<&'a T>, (AddressFamily, RouteLwEnCapType)>
+    for NexthopAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        (address_family, encap_type): (AddressFamily, RouteLwEnCapType),
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_ID => {
+                Self::Id(parse_u32(payload).context("invalid NHA_ID value")?)
+            }
+            NHA_GROUP => Self::Group(
+                VecNexthopGroup::parse(payload)
+                    .context(format!("invalid NHA_GROUP value {payload:?}"))?
+                    .0,
+            ),
+            NHA_GROUP_TYPE => Self::GroupType(
+                parse_u16(payload)
+                    .context("invalid NHA_GROUP_TYPE value")?
+                    .into(),
+            ),
+            NHA_BLACKHOLE => Self::Blackhole,
+            NHA_OIF => {
+                Self::Oif(parse_u32(payload).context("invalid NHA_OIF value")?)
+            }
+            NHA_GATEWAY => Self::Gateway(
+                RouteAddress::parse(address_family, payload).context(
+                    format!("invalid NHA_GATEWAY value {payload:?}"),
+                )?,
+            ),
+            NHA_ENCAP_TYPE => Self::EncapType(
+                parse_u16(payload)
+                    .context("invalid NHA_ENCAP_TYPE value")?
+                    .into(),
+            ),
+            NHA_ENCAP => Self::Encap(
+                VecRouteLwTunnelEncap::parse_with_param(buf, encap_type)
+                    .context("invalid NHA_ENCAP value")?
+                    .0,
+            ),
+            NHA_GROUPS => Self::Groups,
+            NHA_MASTER => Self::Master(
+                parse_u32(payload).context("invalid NHA_MASTER value")?,
+            ),
+            NHA_FDB => Self::Fdb,
+            NHA_RES_GROUP => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid NHA_RES_GROUP value")?;
+                    nlas.push(NexthopResilientGroup::parse(&nla)?);
+                }
+                Self::ResilientGroup(nlas)
+            }
+            NHA_RES_BUCKET => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid NHA_RES_BUCKET value")?;
+                    nlas.push(NexthopBucket::parse(&nla)?);
+                }
+                Self::Bucket(nlas)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const NEXTHOP_GRP_TYPE_MPATH: u16 = 0;
+const NEXTHOP_GRP_TYPE_RES: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum NexthopGroupType {
+    /// Hash-threshold multipath group
+    #[default]
+    Multipath,
+    /// Resilient hashing group
+    Resilient,
+    Other(u16),
+}
+
+impl From<u16> for NexthopGroupType {
+    fn from(d: u16) -> Self {
+        match d {
+            NEXTHOP_GRP_TYPE_MPATH => Self::Multipath,
+            NEXTHOP_GRP_TYPE_RES => Self::Resilient,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<NexthopGroupType> for u16 {
+    fn from(v: NexthopGroupType) -> u16 {
+        match v {
+            NexthopGroupType::Multipath => NEXTHOP_GRP_TYPE_MPATH,
+            NexthopGroupType::Resilient => NEXTHOP_GRP_TYPE_RES,
+            NexthopGroupType::Other(d) => d,
+        }
+    }
+}
+
+const NEXTHOP_GROUP_LEN: usize = 8;
+
+buffer!(NexthopGroupBuffer(NEXTHOP_GROUP_LEN) {
+    id: (u32, 0..4),
+    weight: (u8, 4),
+    weight_high: (u8, 5),
+    reserved: (u16, 6..8),
+});
+
+/// Member of a nexthop group(`struct nexthop_grp`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct NexthopGroup {
+    /// Nexthop ID of the group member
+    pub id: u32,
+    /// Weight of the group member minus one, e.g. `ip nexthop add id 10
+    /// group 1/2,3` is stored as weight 0 for ID 1 and weight 2 for ID 3.
+    pub weight: u8,
+    /// Upper 8 bits of the weight minus one. Linux kernel before 6.12 treat
+    /// this as reserved field.
+    pub weight_high: u8,
+}
+
+impl<T: AsRef<[u8]>> Parseable<NexthopGroupBuffer<T>> for NexthopGroup {
+    fn parse(buf: &NexthopGroupBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            id: buf.id(),
+            weight: buf.weight(),
+            weight_high: buf.weight_high(),
+        })
+    }
+}
+
+impl Emitable for NexthopGroup {
+    fn buffer_len(&self) -> usize {
+        NEXTHOP_GROUP_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = NexthopGroupBuffer::new(buffer);
+        buffer.set_id(self.id);
+        buffer.set_weight(self.weight);
+        buffer.set_weight_high(self.weight_high);
+        buffer.set_reserved(0);
+    }
+}
+
+// This is a helper struct for parsing the array of
+// `struct nexthop_grp` in `NHA_GROUP`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub(crate) struct VecNexthopGroup(pub(crate) Vec<NexthopGroup>);
+
+impl Parseable<[u8]> for VecNexthopGroup {
+    fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        let mut groups = Vec::new();
+        for raw in payload.chunks(NEXTHOP_GROUP_LEN) {
+            groups.push(NexthopGroup::parse(
+                &NexthopGroupBuffer::new_checked(raw)?,
+            )?);
+        }
+        Ok(Self(groups))
+    }
+}
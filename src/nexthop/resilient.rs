@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u32, parse_u64},
+    traits::Parseable,
+    DecodeError,
+};
+
+const NHA_RES_GROUP_BUCKETS: u16 = 1;
+const NHA_RES_GROUP_IDLE_TIMER: u16 = 2;
+const NHA_RES_GROUP_UNBALANCED_TIMER: u16 = 3;
+const NHA_RES_GROUP_UNBALANCED_TIME: u16 = 4;
+
+/// Nested attributes of `NHA_RES_GROUP` for resilient nexthop group.
+/// All timers are in `clock_t`(USER_HZ) unit.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NexthopResilientGroup {
+    /// Number of nexthop buckets in the resilient nexthop group
+    Buckets(u16),
+    /// Idle timer of nexthop buckets, i.e. a bucket is considered idle after
+    /// not being used by any flow for this amount of time
+    IdleTimer(u32),
+    /// Maximum amount of time the group is allowed to stay unbalanced
+    UnbalancedTimer(u32),
+    /// Amount of time the group has been unbalanced
+    UnbalancedTime(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for NexthopResilientGroup {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Buckets(_) => 2,
+            Self::IdleTimer(_) | Self::UnbalancedTimer(_) => 4,
+            Self::UnbalancedTime(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Buckets(v) => NativeEndian::write_u16(buffer, *v),
+            Self::IdleTimer(v) | Self::UnbalancedTimer(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::UnbalancedTime(v) => NativeEndian::write_u64(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Buckets(_) => NHA_RES_GROUP_BUCKETS,
+            Self::IdleTimer(_) => NHA_RES_GROUP_IDLE_TIMER,
+            Self::UnbalancedTimer(_) => NHA_RES_GROUP_UNBALANCED_TIMER,
+            Self::UnbalancedTime(_) => NHA_RES_GROUP_UNBALANCED_TIME,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NexthopResilientGroup
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_RES_GROUP_BUCKETS => Self::Buckets(
+                parse_u16(payload)
+                    .context("invalid NHA_RES_GROUP_BUCKETS value")?,
+            ),
+            NHA_RES_GROUP_IDLE_TIMER => Self::IdleTimer(
+                parse_u32(payload)
+                    .context("invalid NHA_RES_GROUP_IDLE_TIMER value")?,
+            ),
+            NHA_RES_GROUP_UNBALANCED_TIMER => Self::UnbalancedTimer(
+                parse_u32(payload)
+                    .context("invalid NHA_RES_GROUP_UNBALANCED_TIMER value")?,
+            ),
+            NHA_RES_GROUP_UNBALANCED_TIME => Self::UnbalancedTime(
+                parse_u64(payload)
+                    .context("invalid NHA_RES_GROUP_UNBALANCED_TIME value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+const NHA_RES_BUCKET_INDEX: u16 = 1;
+const NHA_RES_BUCKET_IDLE_TIME: u16 = 2;
+const NHA_RES_BUCKET_NH_ID: u16 = 3;
+
+/// Nested attributes of `NHA_RES_BUCKET` used by `RTM_NEWNEXTHOPBUCKET`,
+/// `RTM_DELNEXTHOPBUCKET` and `RTM_GETNEXTHOPBUCKET` messages.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NexthopBucket {
+    /// Index of the nexthop bucket
+    Index(u16),
+    /// Amount of time(`clock_t`) the bucket has been idle
+    IdleTime(u64),
+    /// Nexthop ID assigned to the nexthop bucket
+    NhId(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for NexthopBucket {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Index(_) => 2,
+            Self::NhId(_) => 4,
+            Self::IdleTime(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Index(v) => NativeEndian::write_u16(buffer, *v),
+            Self::NhId(v) => NativeEndian::write_u32(buffer, *v),
+            Self::IdleTime(v) => NativeEndian::write_u64(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Index(_) => NHA_RES_BUCKET_INDEX,
+            Self::IdleTime(_) => NHA_RES_BUCKET_IDLE_TIME,
+            Self::NhId(_) => NHA_RES_BUCKET_NH_ID,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NexthopBucket
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_RES_BUCKET_INDEX => Self::Index(
+                parse_u16(payload)
+                    .context("invalid NHA_RES_BUCKET_INDEX value")?,
+            ),
+            NHA_RES_BUCKET_IDLE_TIME => Self::IdleTime(
+                parse_u64(payload)
+                    .context("invalid NHA_RES_BUCKET_IDLE_TIME value")?,
+            ),
+            NHA_RES_BUCKET_NH_ID => Self::NhId(
+                parse_u32(payload)
+                    .context("invalid NHA_RES_BUCKET_NH_ID value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
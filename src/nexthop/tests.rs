@@ -0,0 +1,318 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use netlink_packet_utils::{
+    nla::DefaultNla,
+    traits::{Emitable, Parseable},
+};
+
+use crate::{
+    nexthop::{
+        NexthopAttribute, NexthopBucket, NexthopFlags, NexthopGroup,
+        NexthopGroupType, NexthopHeader, NexthopMessage, NexthopMessageBuffer,
+        NexthopResilientGroup,
+    },
+    route::{RouteProtocol, RouteScope},
+    AddressFamily,
+};
+
+// Setup:
+//      ip link add veth0 type veth peer name veth1
+//      ip link set veth0 up
+//      ip addr add 192.0.2.1/24 dev veth0
+//      ip nexthop add id 1 via 192.0.2.2 dev veth0
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      ip nexthop show id 1
+#[test]
+fn test_ipv4_nexthop_gateway() {
+    let raw = vec![
+        0x02, 0xfd, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x06, 0x00, 0xc0, 0x00, 0x02, 0x02,
+    ];
+
+    let expected = NexthopMessage {
+        header: NexthopHeader {
+            address_family: AddressFamily::Inet,
+            scope: RouteScope::Link,
+            protocol: RouteProtocol::Unspec,
+            flags: NexthopFlags::empty(),
+        },
+        attributes: vec![
+            NexthopAttribute::Id(1),
+            NexthopAttribute::Oif(3),
+            NexthopAttribute::Gateway(
+                Ipv4Addr::from_str("192.0.2.2").unwrap().into(),
+            ),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NexthopMessage::parse(&NexthopMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add veth0 type veth peer name veth1
+//      ip link set veth0 up
+//      ip -6 addr add 2001:db8::1/64 dev veth0 nodad
+//      ip nexthop add id 6 via 2001:db8::2 dev veth0
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      ip nexthop show id 6
+#[test]
+fn test_ipv6_nexthop_gateway() {
+    let raw = vec![
+        0x0a, 0xfd, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x06, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+    ];
+
+    let expected = NexthopMessage {
+        header: NexthopHeader {
+            address_family: AddressFamily::Inet6,
+            scope: RouteScope::Link,
+            protocol: RouteProtocol::Unspec,
+            flags: NexthopFlags::empty(),
+        },
+        attributes: vec![
+            NexthopAttribute::Id(6),
+            NexthopAttribute::Oif(3),
+            NexthopAttribute::Gateway(
+                Ipv6Addr::from_str("2001:db8::2").unwrap().into(),
+            ),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NexthopMessage::parse(&NexthopMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link set lo up
+//      ip nexthop add id 2 blackhole
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      ip nexthop show id 2
+#[test]
+fn test_nexthop_blackhole() {
+    let raw = vec![
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x04, 0x00, 0x04, 0x00,
+    ];
+
+    let expected = NexthopMessage {
+        header: NexthopHeader {
+            address_family: AddressFamily::Inet,
+            scope: RouteScope::Universe,
+            protocol: RouteProtocol::Unspec,
+            flags: NexthopFlags::empty(),
+        },
+        attributes: vec![NexthopAttribute::Id(2), NexthopAttribute::Blackhole],
+    };
+
+    assert_eq!(
+        expected,
+        NexthopMessage::parse(&NexthopMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add veth0 type veth peer name veth1
+//      ip link set veth0 up
+//      ip addr add 192.0.2.1/24 dev veth0
+//      ip nexthop add id 1 via 192.0.2.2 dev veth0
+//      ip nexthop add id 3 via 192.0.2.3 dev veth0
+//      ip nexthop add id 10 group 1/3,3
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      ip nexthop show id 10
+#[test]
+fn test_nexthop_group() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x00, 0x80,
+    ];
+
+    let expected = NexthopMessage {
+        header: NexthopHeader {
+            address_family: AddressFamily::Unspec,
+            scope: RouteScope::Universe,
+            protocol: RouteProtocol::Unspec,
+            flags: NexthopFlags::empty(),
+        },
+        attributes: vec![
+            NexthopAttribute::Id(10),
+            NexthopAttribute::GroupType(NexthopGroupType::Multipath),
+            NexthopAttribute::Group(vec![
+                NexthopGroup {
+                    id: 1,
+                    weight: 0,
+                    weight_high: 0,
+                },
+                NexthopGroup {
+                    id: 3,
+                    weight: 2,
+                    weight_high: 0,
+                },
+            ]),
+            NexthopAttribute::Other(DefaultNla::new(
+                14,
+                vec![0x00, 0x00, 0x00, 0x80],
+            )),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NexthopMessage::parse(&NexthopMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add veth0 type veth peer name veth1
+//      ip link set veth0 up
+//      ip addr add 192.0.2.1/24 dev veth0
+//      ip nexthop add id 1 via 192.0.2.2 dev veth0
+//      ip nexthop add id 3 via 192.0.2.3 dev veth0
+//      ip nexthop add id 10 group 1/3 type resilient buckets 4 \
+//          idle_timer 60 unbalanced_timer 120
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      ip nexthop show id 10
+#[test]
+fn test_nexthop_resilient_group() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x0c, 0x80,
+        0x06, 0x00, 0x01, 0x00, 0x04, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0x70, 0x17, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0xe0, 0x2e, 0x00, 0x00,
+        0x0c, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0x80,
+    ];
+
+    let expected = NexthopMessage {
+        header: NexthopHeader {
+            address_family: AddressFamily::Unspec,
+            scope: RouteScope::Universe,
+            protocol: RouteProtocol::Unspec,
+            flags: NexthopFlags::empty(),
+        },
+        attributes: vec![
+            NexthopAttribute::Id(10),
+            NexthopAttribute::GroupType(NexthopGroupType::Resilient),
+            NexthopAttribute::Group(vec![
+                NexthopGroup {
+                    id: 1,
+                    weight: 0,
+                    weight_high: 0,
+                },
+                NexthopGroup {
+                    id: 3,
+                    weight: 0,
+                    weight_high: 0,
+                },
+            ]),
+            NexthopAttribute::ResilientGroup(vec![
+                NexthopResilientGroup::Buckets(4),
+                NexthopResilientGroup::IdleTimer(6000),
+                NexthopResilientGroup::UnbalancedTimer(12000),
+                NexthopResilientGroup::UnbalancedTime(0),
+            ]),
+            NexthopAttribute::Other(DefaultNla::new(
+                14,
+                vec![0x00, 0x00, 0x00, 0x80],
+            )),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NexthopMessage::parse(&NexthopMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add veth0 type veth peer name veth1
+//      ip link set veth0 up
+//      ip addr add 192.0.2.1/24 dev veth0
+//      ip nexthop add id 1 via 192.0.2.2 dev veth0
+//      ip nexthop add id 3 via 192.0.2.3 dev veth0
+//      ip nexthop add id 10 group 1/3 type resilient buckets 4 \
+//          idle_timer 60 unbalanced_timer 120
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      ip nexthop bucket show id 10 index 2
+#[test]
+fn test_nexthop_bucket() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x20, 0x00, 0x0d, 0x80, 0x06, 0x00, 0x01, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x02, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = NexthopMessage {
+        header: NexthopHeader {
+            address_family: AddressFamily::Unspec,
+            scope: RouteScope::Universe,
+            protocol: RouteProtocol::Unspec,
+            flags: NexthopFlags::empty(),
+        },
+        attributes: vec![
+            NexthopAttribute::Id(10),
+            NexthopAttribute::Bucket(vec![
+                NexthopBucket::Index(2),
+                NexthopBucket::NhId(1),
+                NexthopBucket::IdleTime(28),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NexthopMessage::parse(&NexthopMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
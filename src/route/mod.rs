@@ -36,3 +36,5 @@ pub use self::preference::RoutePreference;
 pub use self::realm::RouteRealm;
 pub use self::via::{RouteVia, RouteViaBuffer};
 pub use flags::RouteFlags;
+
+pub(crate) use self::lwtunnel::VecRouteLwTunnelEncap;
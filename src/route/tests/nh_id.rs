@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::route::flags::RouteFlags;
+use crate::route::{
+    RouteAttribute, RouteHeader, RouteMessage, RouteMessageBuffer,
+    RouteNextHop, RouteNextHopFlags, RouteProtocol, RouteScope, RouteType,
+};
+use crate::AddressFamily;
+
+// Setup:
+//      ip link add veth0 type veth peer name veth1
+//      ip link set veth0 up
+//      ip addr add 192.0.2.1/24 dev veth0
+//      ip nexthop add id 1 via 192.0.2.2 dev veth0
+//      ip nexthop add id 3 via 192.0.2.3 dev veth0
+//      ip nexthop add id 10 group 1/3,3
+//      ip route add 198.51.100.0/24 nhid 10
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      ip route show 198.51.100.0/24
+#[test]
+fn test_ipv4_route_nh_id() {
+    let raw = vec![
+        0x02, 0x18, 0x00, 0x00, 0xfe, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0f, 0x00, 0xfe, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0xc6, 0x33, 0x64, 0x00, 0x08, 0x00, 0x1e, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x24, 0x00, 0x09, 0x00, 0x10, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x05, 0x00, 0xc0, 0x00, 0x02, 0x02, 0x10, 0x00, 0x00, 0x02,
+        0x03, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00, 0xc0, 0x00, 0x02, 0x03,
+    ];
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet,
+            destination_prefix_length: 24,
+            source_prefix_length: 0,
+            tos: 0,
+            table: 254,
+            protocol: RouteProtocol::Boot,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unicast,
+            flags: RouteFlags::empty(),
+        },
+        attributes: vec![
+            RouteAttribute::Table(254),
+            RouteAttribute::Destination(
+                Ipv4Addr::from_str("198.51.100.0").unwrap().into(),
+            ),
+            RouteAttribute::NhId(10),
+            RouteAttribute::MultiPath(vec![
+                RouteNextHop {
+                    flags: RouteNextHopFlags::empty(),
+                    hops: 0,
+                    interface_index: 3,
+                    attributes: vec![RouteAttribute::Gateway(
+                        Ipv4Addr::from_str("192.0.2.2").unwrap().into(),
+                    )],
+                },
+                RouteNextHop {
+                    flags: RouteNextHopFlags::empty(),
+                    hops: 2,
+                    interface_index: 3,
+                    attributes: vec![RouteAttribute::Gateway(
+                        Ipv4Addr::from_str("192.0.2.3").unwrap().into(),
+                    )],
+                },
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
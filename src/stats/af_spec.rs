@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const AF_MPLS: u16 = 28;
+
+/// Per address family statistics of `IFLA_STATS_AF_SPEC`
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsAfSpec {
+    Mpls(Vec<StatsMpls>),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsAfSpec {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Mpls(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Mpls(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Mpls(_) => AF_MPLS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for StatsAfSpec {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            AF_MPLS => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(format!(
+                        "invalid AF_MPLS of IFLA_STATS_AF_SPEC {payload:?}"
+                    ))?;
+                    nlas.push(StatsMpls::parse(&nla)?);
+                }
+                Self::Mpls(nlas)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+const MPLS_STATS_LINK: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsMpls {
+    Link(StatsMplsLink),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsMpls {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Link(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Link(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Link(_) => MPLS_STATS_LINK,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for StatsMpls {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MPLS_STATS_LINK => Self::Link(
+                StatsMplsLink::parse(
+                    &StatsMplsLinkBuffer::new_checked(payload).context(
+                        format!("invalid MPLS_STATS_LINK value {payload:?}"),
+                    )?,
+                )
+                .context(format!(
+                    "invalid MPLS_STATS_LINK value {payload:?}"
+                ))?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+const MPLS_LINK_STATS_LEN: usize = 72;
+
+buffer!(StatsMplsLinkBuffer(MPLS_LINK_STATS_LEN) {
+    rx_packets: (u64, 0..8),
+    tx_packets: (u64, 8..16),
+    rx_bytes: (u64, 16..24),
+    tx_bytes: (u64, 24..32),
+    rx_errors: (u64, 32..40),
+    tx_errors: (u64, 40..48),
+    rx_dropped: (u64, 48..56),
+    tx_dropped: (u64, 56..64),
+    rx_noroute: (u64, 64..72),
+});
+
+/// MPLS statistics of interface(`struct mpls_link_stats`)
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub struct StatsMplsLink {
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+    pub rx_noroute: u64,
+}
+
+impl<T: AsRef<[u8]>> Parseable<StatsMplsLinkBuffer<T>> for StatsMplsLink {
+    fn parse(buf: &StatsMplsLinkBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rx_packets: buf.rx_packets(),
+            tx_packets: buf.tx_packets(),
+            rx_bytes: buf.rx_bytes(),
+            tx_bytes: buf.tx_bytes(),
+            rx_errors: buf.rx_errors(),
+            tx_errors: buf.tx_errors(),
+            rx_dropped: buf.rx_dropped(),
+            tx_dropped: buf.tx_dropped(),
+            rx_noroute: buf.rx_noroute(),
+        })
+    }
+}
+
+impl Emitable for StatsMplsLink {
+    fn buffer_len(&self) -> usize {
+        MPLS_LINK_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = StatsMplsLinkBuffer::new(buffer);
+        buffer.set_rx_packets(self.rx_packets);
+        buffer.set_tx_packets(self.tx_packets);
+        buffer.set_rx_bytes(self.rx_bytes);
+        buffer.set_tx_bytes(self.tx_bytes);
+        buffer.set_rx_errors(self.rx_errors);
+        buffer.set_tx_errors(self.tx_errors);
+        buffer.set_rx_dropped(self.rx_dropped);
+        buffer.set_tx_dropped(self.tx_dropped);
+        buffer.set_rx_noroute(self.rx_noroute);
+    }
+}
@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::parse_u8,
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use super::{StatsAfSpec, StatsGetFilter, StatsOffloadXstats, StatsXstats};
+use crate::{
+    link::{expand_buffer_if_small, Stats64, Stats64Buffer, LINK_STATS64_LEN},
+    message::{RTM_GETSTATS, RTM_SETSTATS},
+};
+
+const IFLA_STATS_LINK_64: u16 = 1;
+const IFLA_STATS_LINK_XSTATS: u16 = 2;
+const IFLA_STATS_LINK_XSTATS_SLAVE: u16 = 3;
+pub(super) const IFLA_STATS_LINK_OFFLOAD_XSTATS: u16 = 4;
+const IFLA_STATS_AF_SPEC: u16 = 5;
+
+const IFLA_STATS_GET_FILTERS: u16 = 1;
+const IFLA_STATS_SET_OFFLOAD_XSTATS_L3_STATS: u16 = 2;
+
+/// Netlink attributes of `RTM_NEWSTATS` message, selected by
+/// [super::StatsFilterMask] of `RTM_GETSTATS` request, and of `RTM_GETSTATS`
+/// and `RTM_SETSTATS` requests.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsAttribute {
+    Link64(Stats64),
+    LinkXstats(Vec<StatsXstats>),
+    /// Extended statistics of the port decided by its master device
+    LinkXstatsSlave(Vec<StatsXstats>),
+    LinkOffloadXstats(Vec<StatsOffloadXstats>),
+    AfSpec(Vec<StatsAfSpec>),
+    /// Filters of nested statistics, only valid in `RTM_GETSTATS` request
+    GetFilters(Vec<StatsGetFilter>),
+    /// Enable or disable hardware L3 statistics, only valid in
+    /// `RTM_SETSTATS` request
+    SetOffloadXstatsL3Stats(bool),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Link64(_) => LINK_STATS64_LEN,
+            Self::LinkXstats(v) | Self::LinkXstatsSlave(v) => {
+                v.as_slice().buffer_len()
+            }
+            Self::LinkOffloadXstats(v) => v.as_slice().buffer_len(),
+            Self::AfSpec(v) => v.as_slice().buffer_len(),
+            Self::GetFilters(v) => v.as_slice().buffer_len(),
+            Self::SetOffloadXstatsL3Stats(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Link64(v) => v.emit(buffer),
+            Self::LinkXstats(v) | Self::LinkXstatsSlave(v) => {
+                v.as_slice().emit(buffer)
+            }
+            Self::LinkOffloadXstats(v) => v.as_slice().emit(buffer),
+            Self::AfSpec(v) => v.as_slice().emit(buffer),
+            Self::GetFilters(v) => v.as_slice().emit(buffer),
+            Self::SetOffloadXstatsL3Stats(v) => buffer[0] = *v as u8,
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Link64(_) => IFLA_STATS_LINK_64,
+            Self::LinkXstats(_) => IFLA_STATS_LINK_XSTATS,
+            Self::LinkXstatsSlave(_) => IFLA_STATS_LINK_XSTATS_SLAVE,
+            Self::LinkOffloadXstats(_) => IFLA_STATS_LINK_OFFLOAD_XSTATS,
+            Self::AfSpec(_) => IFLA_STATS_AF_SPEC,
+            Self::GetFilters(_) => IFLA_STATS_GET_FILTERS | NLA_F_NESTED,
+            Self::SetOffloadXstatsL3Stats(_) => {
+                IFLA_STATS_SET_OFFLOAD_XSTATS_L3_STATS
+            }
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+/// The `message_type` is the netlink message type holding this attribute.
+/// The `RTM_GETSTATS` and `RTM_SETSTATS` requests use their own attribute
+/// types overlapping with the ones of `RTM_NEWSTATS`.
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16>
+    for StatsAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        message_type: u16,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        let is_request =
+            message_type == RTM_GETSTATS || message_type == RTM_SETSTATS;
+        Ok(match buf.kind() {
+            IFLA_STATS_GET_FILTERS if is_request => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        nla.context("invalid IFLA_STATS_GET_FILTERS value")?;
+                    nlas.push(StatsGetFilter::parse(&nla)?);
+                }
+                Self::GetFilters(nlas)
+            }
+            IFLA_STATS_LINK_64 => {
+                let payload = expand_buffer_if_small(
+                    payload,
+                    LINK_STATS64_LEN,
+                    "IFLA_STATS_LINK_64",
+                );
+                Self::Link64(
+                    Stats64::parse(&Stats64Buffer::new(payload.as_slice()))
+                        .context(format!(
+                            "invalid IFLA_STATS_LINK_64 value {payload:?}"
+                        ))?,
+                )
+            }
+            IFLA_STATS_SET_OFFLOAD_XSTATS_L3_STATS
+                if message_type == RTM_SETSTATS =>
+            {
+                Self::SetOffloadXstatsL3Stats(
+                    parse_u8(payload).context(
+                        "invalid IFLA_STATS_SET_OFFLOAD_XSTATS_L3_STATS value",
+                    )? > 0,
+                )
+            }
+            IFLA_STATS_LINK_XSTATS => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        nla.context("invalid IFLA_STATS_LINK_XSTATS value")?;
+                    nlas.push(StatsXstats::parse(&nla)?);
+                }
+                Self::LinkXstats(nlas)
+            }
+            IFLA_STATS_LINK_XSTATS_SLAVE => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(
+                        "invalid IFLA_STATS_LINK_XSTATS_SLAVE value",
+                    )?;
+                    nlas.push(StatsXstats::parse(&nla)?);
+                }
+                Self::LinkXstatsSlave(nlas)
+            }
+            IFLA_STATS_LINK_OFFLOAD_XSTATS => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(
+                        "invalid IFLA_STATS_LINK_OFFLOAD_XSTATS value",
+                    )?;
+                    nlas.push(StatsOffloadXstats::parse(&nla)?);
+                }
+                Self::LinkOffloadXstats(nlas)
+            }
+            IFLA_STATS_AF_SPEC => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        nla.context("invalid IFLA_STATS_AF_SPEC value")?;
+                    nlas.push(StatsAfSpec::parse(&nla)?);
+                }
+                Self::AfSpec(nlas)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u64,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const BOND_XSTATS_3AD: u16 = 1;
+
+/// Bond extended statistics of `LINK_XSTATS_TYPE_BOND`
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsBondXstats {
+    /// 802.3ad(LACP) statistics
+    Lacp(Vec<StatsBond3ad>),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsBondXstats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Lacp(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Lacp(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Lacp(_) => BOND_XSTATS_3AD,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for StatsBondXstats
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BOND_XSTATS_3AD => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid BOND_XSTATS_3AD value")?;
+                    nlas.push(StatsBond3ad::parse(&nla)?);
+                }
+                Self::Lacp(nlas)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+const BOND_3AD_STAT_LACPDU_RX: u16 = 0;
+const BOND_3AD_STAT_LACPDU_TX: u16 = 1;
+const BOND_3AD_STAT_LACPDU_UNKNOWN_RX: u16 = 2;
+const BOND_3AD_STAT_LACPDU_ILLEGAL_RX: u16 = 3;
+const BOND_3AD_STAT_MARKER_RX: u16 = 4;
+const BOND_3AD_STAT_MARKER_TX: u16 = 5;
+const BOND_3AD_STAT_MARKER_RESP_RX: u16 = 6;
+const BOND_3AD_STAT_MARKER_RESP_TX: u16 = 7;
+const BOND_3AD_STAT_MARKER_UNKNOWN_RX: u16 = 8;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsBond3ad {
+    LacpduRx(u64),
+    LacpduTx(u64),
+    LacpduUnknownRx(u64),
+    LacpduIllegalRx(u64),
+    MarkerRx(u64),
+    MarkerTx(u64),
+    MarkerResponseRx(u64),
+    MarkerResponseTx(u64),
+    MarkerUnknownRx(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsBond3ad {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::LacpduRx(_)
+            | Self::LacpduTx(_)
+            | Self::LacpduUnknownRx(_)
+            | Self::LacpduIllegalRx(_)
+            | Self::MarkerRx(_)
+            | Self::MarkerTx(_)
+            | Self::MarkerResponseRx(_)
+            | Self::MarkerResponseTx(_)
+            | Self::MarkerUnknownRx(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::LacpduRx(v)
+            | Self::LacpduTx(v)
+            | Self::LacpduUnknownRx(v)
+            | Self::LacpduIllegalRx(v)
+            | Self::MarkerRx(v)
+            | Self::MarkerTx(v)
+            | Self::MarkerResponseRx(v)
+            | Self::MarkerResponseTx(v)
+            | Self::MarkerUnknownRx(v) => NativeEndian::write_u64(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::LacpduRx(_) => BOND_3AD_STAT_LACPDU_RX,
+            Self::LacpduTx(_) => BOND_3AD_STAT_LACPDU_TX,
+            Self::LacpduUnknownRx(_) => BOND_3AD_STAT_LACPDU_UNKNOWN_RX,
+            Self::LacpduIllegalRx(_) => BOND_3AD_STAT_LACPDU_ILLEGAL_RX,
+            Self::MarkerRx(_) => BOND_3AD_STAT_MARKER_RX,
+            Self::MarkerTx(_) => BOND_3AD_STAT_MARKER_TX,
+            Self::MarkerResponseRx(_) => BOND_3AD_STAT_MARKER_RESP_RX,
+            Self::MarkerResponseTx(_) => BOND_3AD_STAT_MARKER_RESP_TX,
+            Self::MarkerUnknownRx(_) => BOND_3AD_STAT_MARKER_UNKNOWN_RX,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for StatsBond3ad {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BOND_3AD_STAT_LACPDU_RX => Self::LacpduRx(
+                parse_u64(payload)
+                    .context("invalid BOND_3AD_STAT_LACPDU_RX value")?,
+            ),
+            BOND_3AD_STAT_LACPDU_TX => Self::LacpduTx(
+                parse_u64(payload)
+                    .context("invalid BOND_3AD_STAT_LACPDU_TX value")?,
+            ),
+            BOND_3AD_STAT_LACPDU_UNKNOWN_RX => Self::LacpduUnknownRx(
+                parse_u64(payload)
+                    .context("invalid BOND_3AD_STAT_LACPDU_UNKNOWN_RX value")?,
+            ),
+            BOND_3AD_STAT_LACPDU_ILLEGAL_RX => Self::LacpduIllegalRx(
+                parse_u64(payload)
+                    .context("invalid BOND_3AD_STAT_LACPDU_ILLEGAL_RX value")?,
+            ),
+            BOND_3AD_STAT_MARKER_RX => Self::MarkerRx(
+                parse_u64(payload)
+                    .context("invalid BOND_3AD_STAT_MARKER_RX value")?,
+            ),
+            BOND_3AD_STAT_MARKER_TX => Self::MarkerTx(
+                parse_u64(payload)
+                    .context("invalid BOND_3AD_STAT_MARKER_TX value")?,
+            ),
+            BOND_3AD_STAT_MARKER_RESP_RX => Self::MarkerResponseRx(
+                parse_u64(payload)
+                    .context("invalid BOND_3AD_STAT_MARKER_RESP_RX value")?,
+            ),
+            BOND_3AD_STAT_MARKER_RESP_TX => Self::MarkerResponseTx(
+                parse_u64(payload)
+                    .context("invalid BOND_3AD_STAT_MARKER_RESP_TX value")?,
+            ),
+            BOND_3AD_STAT_MARKER_UNKNOWN_RX => Self::MarkerUnknownRx(
+                parse_u64(payload)
+                    .context("invalid BOND_3AD_STAT_MARKER_UNKNOWN_RX value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    traits::Parseable,
+    DecodeError,
+};
+
+use super::attribute::IFLA_STATS_LINK_OFFLOAD_XSTATS;
+
+// IFLA_STATS_FILTER_BIT(ATTR) is defined as `1 << (ATTR - 1)`
+const IFLA_STATS_FILTER_LINK_64: u32 = 1 << 0;
+const IFLA_STATS_FILTER_LINK_XSTATS: u32 = 1 << 1;
+const IFLA_STATS_FILTER_LINK_XSTATS_SLAVE: u32 = 1 << 2;
+const IFLA_STATS_FILTER_LINK_OFFLOAD_XSTATS: u32 = 1 << 3;
+const IFLA_STATS_FILTER_AF_SPEC: u32 = 1 << 4;
+
+bitflags! {
+    /// Statistics to include in `RTM_GETSTATS` reply
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[non_exhaustive]
+    pub struct StatsFilterMask: u32 {
+        const Link64 = IFLA_STATS_FILTER_LINK_64;
+        const LinkXstats = IFLA_STATS_FILTER_LINK_XSTATS;
+        const LinkXstatsSlave = IFLA_STATS_FILTER_LINK_XSTATS_SLAVE;
+        const LinkOffloadXstats = IFLA_STATS_FILTER_LINK_OFFLOAD_XSTATS;
+        const AfSpec = IFLA_STATS_FILTER_AF_SPEC;
+        const _ = !0;
+    }
+}
+
+// IFLA_STATS_FILTER_BIT() of IFLA_OFFLOAD_XSTATS_* attributes
+const IFLA_OFFLOAD_XSTATS_FILTER_CPU_HIT: u32 = 1 << 0;
+const IFLA_OFFLOAD_XSTATS_FILTER_HW_S_INFO: u32 = 1 << 1;
+const IFLA_OFFLOAD_XSTATS_FILTER_L3_STATS: u32 = 1 << 2;
+
+bitflags! {
+    /// Nested attributes of `IFLA_STATS_LINK_OFFLOAD_XSTATS` to include in
+    /// `RTM_GETSTATS` reply
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[non_exhaustive]
+    pub struct StatsOffloadXstatsFilterMask: u32 {
+        const CpuHit = IFLA_OFFLOAD_XSTATS_FILTER_CPU_HIT;
+        const HwSInfo = IFLA_OFFLOAD_XSTATS_FILTER_HW_S_INFO;
+        const L3Stats = IFLA_OFFLOAD_XSTATS_FILTER_L3_STATS;
+        const _ = !0;
+    }
+}
+
+/// Nested attributes of `IFLA_STATS_GET_FILTERS` in `RTM_GETSTATS` request,
+/// narrowing down the statistics selected by [StatsFilterMask].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsGetFilter {
+    LinkOffloadXstats(StatsOffloadXstatsFilterMask),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsGetFilter {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::LinkOffloadXstats(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::LinkOffloadXstats(v) => {
+                NativeEndian::write_u32(buffer, v.bits())
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::LinkOffloadXstats(_) => IFLA_STATS_LINK_OFFLOAD_XSTATS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for StatsGetFilter
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_STATS_LINK_OFFLOAD_XSTATS => Self::LinkOffloadXstats(
+                StatsOffloadXstatsFilterMask::from_bits_retain(
+                    parse_u32(payload).context(
+                        "invalid IFLA_STATS_LINK_OFFLOAD_XSTATS filter value",
+                    )?,
+                ),
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::StatsFilterMask;
+use crate::AddressFamily;
+
+const STATS_HEADER_LEN: usize = 12;
+
+buffer!(StatsMessageBuffer(STATS_HEADER_LEN) {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    pad2: (u16, 2..4),
+    ifindex: (u32, 4..8),
+    filter_mask: (u32, 8..STATS_HEADER_LEN),
+    payload: (slice, STATS_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> StatsMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+/// High level representation of `RTM_NEWSTATS` and `RTM_GETSTATS` messages
+/// headers(`struct if_stats_msg`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct StatsHeader {
+    pub family: AddressFamily,
+    /// Interface index, 0 for dumping all interfaces
+    pub ifindex: u32,
+    pub filter_mask: StatsFilterMask,
+}
+
+impl<T: AsRef<[u8]>> Parseable<StatsMessageBuffer<T>> for StatsHeader {
+    fn parse(buf: &StatsMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family().into(),
+            ifindex: buf.ifindex(),
+            filter_mask: StatsFilterMask::from_bits_retain(buf.filter_mask()),
+        })
+    }
+}
+
+impl Emitable for StatsHeader {
+    fn buffer_len(&self) -> usize {
+        STATS_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = StatsMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_pad1(0);
+        packet.set_pad2(0);
+        packet.set_ifindex(self.ifindex);
+        packet.set_filter_mask(self.filter_mask.bits());
+    }
+}
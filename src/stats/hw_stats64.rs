@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::link::Stats64;
+
+pub(crate) const HW_STATS64_LEN: usize = 72;
+
+buffer!(StatsHwStats64Buffer(HW_STATS64_LEN) {
+    rx_packets: (u64, 0..8),
+    tx_packets: (u64, 8..16),
+    rx_bytes: (u64, 16..24),
+    tx_bytes: (u64, 24..32),
+    rx_errors: (u64, 32..40),
+    tx_errors: (u64, 40..48),
+    rx_dropped: (u64, 48..56),
+    tx_dropped: (u64, 56..64),
+    multicast: (u64, 64..72),
+});
+
+/// Hardware statistics(`struct rtnl_hw_stats64`), a subset of [Stats64]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub struct StatsHwStats64 {
+    /// total packets received
+    pub rx_packets: u64,
+    /// total packets transmitted
+    pub tx_packets: u64,
+    /// total bytes received
+    pub rx_bytes: u64,
+    /// total bytes transmitted
+    pub tx_bytes: u64,
+    /// bad packets received
+    pub rx_errors: u64,
+    /// packet transmit problems
+    pub tx_errors: u64,
+    /// no space in linux buffers
+    pub rx_dropped: u64,
+    /// no space available in linux
+    pub tx_dropped: u64,
+    /// multicast packets received
+    pub multicast: u64,
+}
+
+impl<T: AsRef<[u8]>> Parseable<StatsHwStats64Buffer<T>> for StatsHwStats64 {
+    fn parse(buf: &StatsHwStats64Buffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rx_packets: buf.rx_packets(),
+            tx_packets: buf.tx_packets(),
+            rx_bytes: buf.rx_bytes(),
+            tx_bytes: buf.tx_bytes(),
+            rx_errors: buf.rx_errors(),
+            tx_errors: buf.tx_errors(),
+            rx_dropped: buf.rx_dropped(),
+            tx_dropped: buf.tx_dropped(),
+            multicast: buf.multicast(),
+        })
+    }
+}
+
+impl Emitable for StatsHwStats64 {
+    fn buffer_len(&self) -> usize {
+        HW_STATS64_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = StatsHwStats64Buffer::new(buffer);
+        buffer.set_rx_packets(self.rx_packets);
+        buffer.set_tx_packets(self.tx_packets);
+        buffer.set_rx_bytes(self.rx_bytes);
+        buffer.set_tx_bytes(self.tx_bytes);
+        buffer.set_rx_errors(self.rx_errors);
+        buffer.set_tx_errors(self.tx_errors);
+        buffer.set_rx_dropped(self.rx_dropped);
+        buffer.set_tx_dropped(self.tx_dropped);
+        buffer.set_multicast(self.multicast);
+    }
+}
+
+impl From<StatsHwStats64> for Stats64 {
+    fn from(v: StatsHwStats64) -> Self {
+        Self {
+            rx_packets: v.rx_packets,
+            tx_packets: v.tx_packets,
+            rx_bytes: v.rx_bytes,
+            tx_bytes: v.tx_bytes,
+            rx_errors: v.rx_errors,
+            tx_errors: v.tx_errors,
+            rx_dropped: v.rx_dropped,
+            tx_dropped: v.tx_dropped,
+            multicast: v.multicast,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::stats::{StatsAttribute, StatsHeader, StatsMessageBuffer};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct StatsMessage {
+    pub header: StatsHeader,
+    pub attributes: Vec<StatsAttribute>,
+}
+
+/// The `message_type` is the netlink message type(`RTM_NEWSTATS`,
+/// `RTM_GETSTATS` or `RTM_SETSTATS`) as the attribute types of requests
+/// overlap with the ones of `RTM_NEWSTATS`.
+impl<'a, T: AsRef<[u8]> + 'a>
+    ParseableParametrized<StatsMessageBuffer<&'a T>, u16> for StatsMessage
+{
+    fn parse_with_param(
+        buf: &StatsMessageBuffer<&'a T>,
+        message_type: u16,
+    ) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: StatsHeader::parse(buf)
+                .context("failed to parse stats message header")?,
+            attributes: Vec::<StatsAttribute>::parse_with_param(
+                buf,
+                message_type,
+            )
+            .context("failed to parse stats message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a>
+    ParseableParametrized<StatsMessageBuffer<&'a T>, u16>
+    for Vec<StatsAttribute>
+{
+    fn parse_with_param(
+        buf: &StatsMessageBuffer<&'a T>,
+        message_type: u16,
+    ) -> Result<Self, DecodeError> {
+        let mut attributes = vec![];
+        for nla_buf in buf.attributes() {
+            attributes.push(StatsAttribute::parse_with_param(
+                &nla_buf?,
+                message_type,
+            )?);
+        }
+        Ok(attributes)
+    }
+}
+
+impl Emitable for StatsMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT
+
+mod af_spec;
+mod attribute;
+mod bond;
+mod bridge;
+mod filter;
+mod header;
+mod hw_stats64;
+mod message;
+mod offload;
+#[cfg(test)]
+mod tests;
+mod xstats;
+
+pub use self::af_spec::{
+    StatsAfSpec, StatsMpls, StatsMplsLink, StatsMplsLinkBuffer,
+};
+pub use self::attribute::StatsAttribute;
+pub use self::bond::{StatsBond3ad, StatsBondXstats};
+pub use self::bridge::{
+    StatsBridgeMulticast, StatsBridgeMulticastBuffer, StatsBridgeStp,
+    StatsBridgeStpBuffer, StatsBridgeVlan, StatsBridgeVlanBuffer,
+    StatsBridgeXstats,
+};
+pub use self::filter::{
+    StatsFilterMask, StatsGetFilter, StatsOffloadXstatsFilterMask,
+};
+pub use self::header::{StatsHeader, StatsMessageBuffer};
+pub use self::hw_stats64::{StatsHwStats64, StatsHwStats64Buffer};
+pub use self::message::StatsMessage;
+pub use self::offload::{
+    StatsOffloadHwSInfo, StatsOffloadHwSInfoAttribute, StatsOffloadXstats,
+};
+pub use self::xstats::StatsXstats;
@@ -0,0 +1,232 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::parse_u8,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{hw_stats64::HW_STATS64_LEN, StatsHwStats64, StatsHwStats64Buffer};
+use crate::link::{
+    expand_buffer_if_small, Stats64, Stats64Buffer, LINK_STATS64_LEN,
+};
+
+const IFLA_OFFLOAD_XSTATS_CPU_HIT: u16 = 1;
+const IFLA_OFFLOAD_XSTATS_HW_S_INFO: u16 = 2;
+const IFLA_OFFLOAD_XSTATS_L3_STATS: u16 = 3;
+
+/// Nested attributes of `IFLA_STATS_LINK_OFFLOAD_XSTATS`
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsOffloadXstats {
+    /// Statistics of packets trapped to CPU by the hardware
+    CpuHit(Stats64),
+    /// Whether each type of hardware statistics is requested and used
+    HwSInfo(Vec<StatsOffloadHwSInfo>),
+    /// Statistics of L3 traffic counted by the hardware, enabled by
+    /// `RTM_SETSTATS` with [super::StatsAttribute::SetOffloadXstatsL3Stats]
+    L3Stats(StatsHwStats64),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsOffloadXstats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::CpuHit(v) => v.buffer_len(),
+            Self::L3Stats(v) => v.buffer_len(),
+            Self::HwSInfo(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::CpuHit(v) => v.emit(buffer),
+            Self::L3Stats(v) => v.emit(buffer),
+            Self::HwSInfo(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::CpuHit(_) => IFLA_OFFLOAD_XSTATS_CPU_HIT,
+            Self::HwSInfo(_) => IFLA_OFFLOAD_XSTATS_HW_S_INFO | NLA_F_NESTED,
+            Self::L3Stats(_) => IFLA_OFFLOAD_XSTATS_L3_STATS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for StatsOffloadXstats
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_CPU_HIT => {
+                let payload = expand_buffer_if_small(
+                    payload,
+                    LINK_STATS64_LEN,
+                    "IFLA_OFFLOAD_XSTATS_CPU_HIT",
+                );
+                Self::CpuHit(
+                    Stats64::parse(&Stats64Buffer::new(payload.as_slice()))
+                        .context(format!(
+                            "invalid IFLA_OFFLOAD_XSTATS_CPU_HIT value \
+                            {payload:?}"
+                        ))?,
+                )
+            }
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(
+                        "invalid IFLA_OFFLOAD_XSTATS_HW_S_INFO value",
+                    )?;
+                    nlas.push(StatsOffloadHwSInfo::parse(&nla)?);
+                }
+                Self::HwSInfo(nlas)
+            }
+            IFLA_OFFLOAD_XSTATS_L3_STATS => {
+                let payload = expand_buffer_if_small(
+                    payload,
+                    HW_STATS64_LEN,
+                    "IFLA_OFFLOAD_XSTATS_L3_STATS",
+                );
+                Self::L3Stats(
+                    StatsHwStats64::parse(&StatsHwStats64Buffer::new(
+                        payload.as_slice(),
+                    ))
+                    .context(format!(
+                        "invalid IFLA_OFFLOAD_XSTATS_L3_STATS value \
+                        {payload:?}"
+                    ))?,
+                )
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+/// Hardware statistics information of `IFLA_OFFLOAD_XSTATS_HW_S_INFO`,
+/// indexed by the type of offload statistics.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsOffloadHwSInfo {
+    L3Stats(Vec<StatsOffloadHwSInfoAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsOffloadHwSInfo {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::L3Stats(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::L3Stats(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::L3Stats(_) => IFLA_OFFLOAD_XSTATS_L3_STATS | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for StatsOffloadHwSInfo
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_L3_STATS => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(
+                        "invalid IFLA_OFFLOAD_XSTATS_L3_STATS value",
+                    )?;
+                    nlas.push(StatsOffloadHwSInfoAttribute::parse(&nla)?);
+                }
+                Self::L3Stats(nlas)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+const IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST: u16 = 1;
+const IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsOffloadHwSInfoAttribute {
+    /// Whether the statistics has been requested by user space
+    Request(bool),
+    /// Whether any device driver is collecting the statistics
+    Used(bool),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsOffloadHwSInfoAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Request(_) | Self::Used(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Request(v) | Self::Used(v) => buffer[0] = *v as u8,
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Request(_) => IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST,
+            Self::Used(_) => IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for StatsOffloadHwSInfoAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST => Self::Request(
+                parse_u8(payload).context(
+                    "invalid IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST value",
+                )? > 0,
+            ),
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED => Self::Used(
+                parse_u8(payload).context(
+                    "invalid IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED value",
+                )? > 0,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,414 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::traits::{Emitable, ParseableParametrized};
+
+use crate::{
+    link::Stats64,
+    message::{RTM_GETSTATS, RTM_NEWSTATS, RTM_SETSTATS},
+    stats::{
+        StatsAttribute, StatsBridgeMulticast, StatsBridgeStp,
+        StatsBridgeXstats, StatsFilterMask, StatsGetFilter, StatsHeader,
+        StatsHwStats64, StatsMessage, StatsMessageBuffer, StatsOffloadHwSInfo,
+        StatsOffloadHwSInfoAttribute, StatsOffloadXstats,
+        StatsOffloadXstatsFilterMask, StatsXstats,
+    },
+    AddressFamily,
+};
+
+// Setup:
+//      ip link add d0 type veth peer name d1
+//      ip link set d0 up
+//      ip link set d1 up
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      ip stats show dev d0 group link
+#[test]
+fn test_stats_link_64() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0xcc, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x02, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = StatsMessage {
+        header: StatsHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 3,
+            filter_mask: StatsFilterMask::Link64,
+        },
+        attributes: vec![StatsAttribute::Link64(Stats64 {
+            rx_packets: 6,
+            tx_packets: 6,
+            rx_bytes: 516,
+            tx_bytes: 516,
+            ..Default::default()
+        })],
+    };
+
+    assert_eq!(
+        expected,
+        StatsMessage::parse_with_param(
+            &StatsMessageBuffer::new(&raw),
+            RTM_NEWSTATS
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add br0 type bridge
+//      ip link set br0 type bridge mcast_stats_enabled 1
+//      ip link add d0 type veth peer name d1
+//      ip link set d0 master br0
+//      ip link set br0 up
+//      ip link set d0 up
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      ip stats show dev d0 group xstats_slave
+#[test]
+fn test_stats_bridge_port_xstats() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x30, 0x01, 0x03, 0x00, 0x2c, 0x01, 0x01, 0x00, 0xf4, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x34, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = StatsMessage {
+        header: StatsHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 4,
+            filter_mask: StatsFilterMask::LinkXstatsSlave,
+        },
+        attributes: vec![StatsAttribute::LinkXstatsSlave(vec![
+            StatsXstats::Bridge(vec![
+                StatsBridgeXstats::Multicast(StatsBridgeMulticast::default()),
+                StatsBridgeXstats::Stp(StatsBridgeStp::default()),
+            ]),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        StatsMessage::parse_with_param(
+            &StatsMessageBuffer::new(&raw),
+            RTM_NEWSTATS
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_GETSTATS` request equivalent to
+// command:
+//      ip stats show dev d0 group xstats_slave
+#[test]
+fn test_stats_get_request() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = StatsMessage {
+        header: StatsHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 4,
+            filter_mask: StatsFilterMask::LinkXstatsSlave,
+        },
+        attributes: vec![],
+    };
+
+    assert_eq!(
+        expected,
+        StatsMessage::parse_with_param(
+            &StatsMessageBuffer::new(&raw),
+            RTM_GETSTATS
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_GETSTATS` request sent by
+// iproute2 6.1, command:
+//      ip stats show dev d0 group offload subgroup l3_stats
+#[test]
+fn test_stats_get_request_with_filters() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x01, 0x80, 0x08, 0x00, 0x04, 0x00, 0x06, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = StatsMessage {
+        header: StatsHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 3,
+            filter_mask: StatsFilterMask::LinkOffloadXstats,
+        },
+        attributes: vec![StatsAttribute::GetFilters(vec![
+            StatsGetFilter::LinkOffloadXstats(
+                StatsOffloadXstatsFilterMask::HwSInfo
+                    | StatsOffloadXstatsFilterMask::L3Stats,
+            ),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        StatsMessage::parse_with_param(
+            &StatsMessageBuffer::new(&raw),
+            RTM_GETSTATS
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add d0 type veth peer name d1
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      ip stats show dev d0 group offload subgroup hw_stats_info
+#[test]
+fn test_stats_offload_hw_stats_info() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x1c, 0x00, 0x04, 0x00, 0x18, 0x00, 0x02, 0x80, 0x14, 0x00, 0x03, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = StatsMessage {
+        header: StatsHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 2,
+            filter_mask: StatsFilterMask::LinkOffloadXstats,
+        },
+        attributes: vec![StatsAttribute::LinkOffloadXstats(vec![
+            StatsOffloadXstats::HwSInfo(vec![StatsOffloadHwSInfo::L3Stats(
+                vec![
+                    StatsOffloadHwSInfoAttribute::Request(false),
+                    StatsOffloadHwSInfoAttribute::Used(false),
+                ],
+            )]),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        StatsMessage::parse_with_param(
+            &StatsMessageBuffer::new(&raw),
+            RTM_NEWSTATS
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add v0 type veth peer name v1
+//      ip stats set dev v0 l3_stats on
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      ip stats show dev v0 group offload
+#[test]
+fn test_stats_offload_l3_stats() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x68, 0x00, 0x04, 0x00, 0x18, 0x00, 0x02, 0x80, 0x14, 0x00, 0x03, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x4c, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = StatsMessage {
+        header: StatsHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 3,
+            filter_mask: StatsFilterMask::LinkOffloadXstats,
+        },
+        attributes: vec![StatsAttribute::LinkOffloadXstats(vec![
+            StatsOffloadXstats::HwSInfo(vec![StatsOffloadHwSInfo::L3Stats(
+                vec![
+                    StatsOffloadHwSInfoAttribute::Request(true),
+                    StatsOffloadHwSInfoAttribute::Used(false),
+                ],
+            )]),
+            StatsOffloadXstats::L3Stats(StatsHwStats64::default()),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        StatsMessage::parse_with_param(
+            &StatsMessageBuffer::new(&raw),
+            RTM_NEWSTATS
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// IFLA_OFFLOAD_XSTATS_CPU_HIT is only reported by drivers implementing
+// `ndo_has_offload_stats()`(e.g. mlxsw), the raw netlink message(header
+// removed) below is built from the kernel layout of `RTM_NEWSTATS` reply to
+// command:
+//      ip stats show dev v0 group offload subgroup cpu_hit
+#[test]
+fn test_stats_offload_cpu_hit() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0xd0, 0x00, 0x04, 0x00, 0xcc, 0x00, 0x01, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = StatsMessage {
+        header: StatsHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 3,
+            filter_mask: StatsFilterMask::LinkOffloadXstats,
+        },
+        attributes: vec![StatsAttribute::LinkOffloadXstats(vec![
+            StatsOffloadXstats::CpuHit(Stats64 {
+                rx_packets: 3,
+                rx_bytes: 258,
+                ..Default::default()
+            }),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        StatsMessage::parse_with_param(
+            &StatsMessageBuffer::new(&raw),
+            RTM_NEWSTATS
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_SETSTATS` request equivalent to
+// command:
+//      ip stats set dev d0 l3_stats on
+#[test]
+fn test_stats_set_l3_stats() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = StatsMessage {
+        header: StatsHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 2,
+            filter_mask: StatsFilterMask::empty(),
+        },
+        attributes: vec![StatsAttribute::SetOffloadXstatsL3Stats(true)],
+    };
+
+    assert_eq!(
+        expected,
+        StatsMessage::parse_with_param(
+            &StatsMessageBuffer::new(&raw),
+            RTM_SETSTATS
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
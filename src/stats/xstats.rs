@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{StatsBondXstats, StatsBridgeXstats};
+
+const LINK_XSTATS_TYPE_BRIDGE: u16 = 1;
+const LINK_XSTATS_TYPE_BOND: u16 = 2;
+
+/// Extended statistics of `IFLA_STATS_LINK_XSTATS` and
+/// `IFLA_STATS_LINK_XSTATS_SLAVE`. For the latter, the type is decided by
+/// the master device.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsXstats {
+    Bridge(Vec<StatsBridgeXstats>),
+    Bond(Vec<StatsBondXstats>),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsXstats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Bridge(v) => v.as_slice().buffer_len(),
+            Self::Bond(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Bridge(v) => v.as_slice().emit(buffer),
+            Self::Bond(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Bridge(_) => LINK_XSTATS_TYPE_BRIDGE,
+            Self::Bond(_) => LINK_XSTATS_TYPE_BOND,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for StatsXstats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LINK_XSTATS_TYPE_BRIDGE => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        nla.context("invalid LINK_XSTATS_TYPE_BRIDGE value")?;
+                    nlas.push(StatsBridgeXstats::parse(&nla)?);
+                }
+                Self::Bridge(nlas)
+            }
+            LINK_XSTATS_TYPE_BOND => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        nla.context("invalid LINK_XSTATS_TYPE_BOND value")?;
+                    nlas.push(StatsBondXstats::parse(&nla)?);
+                }
+                Self::Bond(nlas)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
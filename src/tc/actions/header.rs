@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::AddressFamily;
+
+const TCA_HEADER_LEN: usize = 4;
+
+buffer!(TcActionMessageBuffer(TCA_HEADER_LEN) {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    pad2: (u16, 2..TCA_HEADER_LEN),
+    payload: (slice, TCA_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> TcActionMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+/// Header of `RTM_NEWACTION`, `RTM_DELACTION`, `RTM_GETACTION` messages,
+/// kernel `struct tcamsg`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcActionMessageHeader {
+    pub family: AddressFamily,
+}
+
+impl Emitable for TcActionMessageHeader {
+    fn buffer_len(&self) -> usize {
+        TCA_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcActionMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcActionMessageBuffer<T>>
+    for TcActionMessageHeader
+{
+    fn parse(buf: &TcActionMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family().into(),
+        })
+    }
+}
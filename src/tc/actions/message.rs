@@ -0,0 +1,227 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_string, parse_u32},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{TcAction, TcActionMessageBuffer, TcActionMessageHeader};
+
+/// Standalone(shared) tc action message, used by `RTM_NEWACTION`,
+/// `RTM_DELACTION`, `RTM_GETACTION`, similar to `tc actions` command.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct TcActionMessage {
+    pub header: TcActionMessageHeader,
+    pub attributes: Vec<TcActionMessageAttribute>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<TcActionMessageBuffer<&'a T>>
+    for TcActionMessage
+{
+    fn parse(buf: &TcActionMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: TcActionMessageHeader::parse(buf)
+                .context("failed to parse tc action message header")?,
+            attributes: Vec::<TcActionMessageAttribute>::parse(buf)
+                .context("failed to parse tc action message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<TcActionMessageBuffer<&'a T>>
+    for Vec<TcActionMessageAttribute>
+{
+    fn parse(buf: &TcActionMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut attributes = vec![];
+        for nla_buf in buf.attributes() {
+            attributes.push(TcActionMessageAttribute::parse(&nla_buf?)?);
+        }
+        Ok(attributes)
+    }
+}
+
+impl Emitable for TcActionMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+const TCA_ROOT_TAB: u16 = 1;
+const TCA_ROOT_FLAGS: u16 = 2;
+const TCA_ROOT_COUNT: u16 = 3;
+const TCA_ROOT_TIME_DELTA: u16 = 4;
+const TCA_ROOT_EXT_WARN_MSG: u16 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcActionMessageAttribute {
+    /// List of actions
+    Actions(Vec<TcAction>),
+    /// Dump flags, only valid in `RTM_GETACTION` dump request
+    Flags(TcActionMessageFlagsWithSelector),
+    /// Number of actions included in this message
+    Count(u32),
+    /// Only dump actions used or modified in the last specified
+    /// milliseconds, only valid in `RTM_GETACTION` dump request
+    TimeDelta(u32),
+    /// Extended warning message from kernel
+    ExtWarnMsg(String),
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionMessageAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Actions(actions) => actions.as_slice().buffer_len(),
+            Self::Flags(_) => TcActionMessageFlagsWithSelector::BUF_LEN,
+            Self::Count(_) | Self::TimeDelta(_) => 4,
+            Self::ExtWarnMsg(msg) => msg.len() + 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Actions(actions) => actions.as_slice().emit(buffer),
+            Self::Flags(flags) => flags.emit(buffer),
+            Self::Count(value) | Self::TimeDelta(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::ExtWarnMsg(msg) => {
+                buffer[..msg.len()].copy_from_slice(msg.as_bytes());
+                buffer[msg.len()] = 0;
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Actions(_) => TCA_ROOT_TAB,
+            Self::Flags(_) => TCA_ROOT_FLAGS,
+            Self::Count(_) => TCA_ROOT_COUNT,
+            Self::TimeDelta(_) => TCA_ROOT_TIME_DELTA,
+            Self::ExtWarnMsg(_) => TCA_ROOT_EXT_WARN_MSG,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcActionMessageAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_ROOT_TAB => {
+                let mut actions = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid TCA_ROOT_TAB")?;
+                    actions.push(
+                        TcAction::parse(&nla)
+                            .context("failed to parse TCA_ROOT_TAB")?,
+                    );
+                }
+                Self::Actions(actions)
+            }
+            TCA_ROOT_FLAGS => Self::Flags(
+                TcActionMessageFlagsWithSelector::parse(payload)
+                    .context("failed to parse TCA_ROOT_FLAGS")?,
+            ),
+            TCA_ROOT_COUNT => Self::Count(
+                parse_u32(payload).context("failed to parse TCA_ROOT_COUNT")?,
+            ),
+            TCA_ROOT_TIME_DELTA => Self::TimeDelta(
+                parse_u32(payload)
+                    .context("failed to parse TCA_ROOT_TIME_DELTA")?,
+            ),
+            TCA_ROOT_EXT_WARN_MSG => Self::ExtWarnMsg(
+                parse_string(payload)
+                    .context("failed to parse TCA_ROOT_EXT_WARN_MSG")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("failed to parse tc action message nla")?,
+            ),
+        })
+    }
+}
+
+const TCA_ACT_FLAG_LARGE_DUMP_ON: u32 = 1 << 0;
+const TCA_ACT_FLAG_TERSE_DUMP: u32 = 1 << 1;
+
+bitflags! {
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[non_exhaustive]
+    pub struct TcActionMessageFlags: u32 {
+        /// Allow kernel to include more than 32 actions in single dump
+        /// reply message
+        const LargeDump = TCA_ACT_FLAG_LARGE_DUMP_ON;
+        /// Only include action kind, index and statistics in dump reply
+        const TerseDump = TCA_ACT_FLAG_TERSE_DUMP;
+        const _ = !0;
+    }
+}
+
+/// Kernel `struct nla_bitfield32` holding [TcActionMessageFlags], only bits
+/// set in `selector` are honored by kernel.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcActionMessageFlagsWithSelector {
+    pub flags: TcActionMessageFlags,
+    pub selector: TcActionMessageFlags,
+}
+
+impl TcActionMessageFlagsWithSelector {
+    const BUF_LEN: usize = 8;
+
+    pub fn new(flags: TcActionMessageFlags) -> Self {
+        Self {
+            flags,
+            selector: flags,
+        }
+    }
+}
+
+impl Parseable<[u8]> for TcActionMessageFlagsWithSelector {
+    fn parse(buf: &[u8]) -> Result<Self, DecodeError> {
+        if buf.len() < Self::BUF_LEN {
+            return Err(DecodeError::from(format!(
+                "Expecting {} bytes for TCA_ROOT_FLAGS, but got {:?}",
+                Self::BUF_LEN,
+                buf
+            )));
+        }
+        Ok(Self {
+            flags: TcActionMessageFlags::from_bits_retain(
+                NativeEndian::read_u32(&buf[0..4]),
+            ),
+            selector: TcActionMessageFlags::from_bits_retain(
+                NativeEndian::read_u32(&buf[4..8]),
+            ),
+        })
+    }
+}
+
+impl Emitable for TcActionMessageFlagsWithSelector {
+    fn buffer_len(&self) -> usize {
+        Self::BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u32(&mut buffer[0..4], self.flags.bits());
+        NativeEndian::write_u32(&mut buffer[4..8], self.selector.bits());
+    }
+}
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT
 
 mod action;
+mod header;
+mod message;
 mod mirror;
 mod nat;
 mod nat_flag;
@@ -9,6 +11,11 @@ pub use self::action::{
     TcAction, TcActionAttribute, TcActionGeneric, TcActionGenericBuffer,
     TcActionOption, TcActionType,
 };
+pub use self::header::{TcActionMessageBuffer, TcActionMessageHeader};
+pub use self::message::{
+    TcActionMessage, TcActionMessageAttribute, TcActionMessageFlags,
+    TcActionMessageFlagsWithSelector,
+};
 pub use self::mirror::{
     TcActionMirror, TcActionMirrorOption, TcMirror, TcMirrorActionType,
     TcMirrorBuffer,
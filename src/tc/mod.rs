@@ -11,9 +11,11 @@ mod stats;
 
 pub use self::actions::{
     TcAction, TcActionAttribute, TcActionGeneric, TcActionGenericBuffer,
-    TcActionMirror, TcActionMirrorOption, TcActionNat, TcActionNatOption,
-    TcActionOption, TcActionType, TcMirror, TcMirrorActionType, TcMirrorBuffer,
-    TcNat, TcNatBuffer, TcNatFlags,
+    TcActionMessage, TcActionMessageAttribute, TcActionMessageBuffer,
+    TcActionMessageFlags, TcActionMessageFlagsWithSelector,
+    TcActionMessageHeader, TcActionMirror, TcActionMirrorOption, TcActionNat,
+    TcActionNatOption, TcActionOption, TcActionType, TcMirror,
+    TcMirrorActionType, TcMirrorBuffer, TcNat, TcNatBuffer, TcNatFlags,
 };
 pub use self::attribute::TcAttribute;
 pub use self::filters::{
@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    tc::{
+        TcAction, TcActionAttribute, TcActionMessage, TcActionMessageAttribute,
+        TcActionMessageBuffer, TcActionMessageFlags,
+        TcActionMessageFlagsWithSelector, TcActionMessageHeader, TcStats2,
+        TcStatsBasic, TcStatsQueue,
+    },
+    AddressFamily,
+};
+
+// Capture nlmon of this command:
+//
+//      tc -brief actions ls action mirred
+//
+// Raw packet modification:
+//   * rtnetlink header removed.
+#[test]
+fn test_get_action_terse_dump_request() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x01, 0x00, 0x10, 0x00, 0x01, 0x00,
+        0x0b, 0x00, 0x01, 0x00, 0x6d, 0x69, 0x72, 0x72, 0x65, 0x64, 0x00, 0x00,
+        0x0c, 0x00, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = TcActionMessage {
+        header: TcActionMessageHeader {
+            family: AddressFamily::Unspec,
+        },
+        attributes: vec![
+            TcActionMessageAttribute::Actions(vec![TcAction {
+                tab: 1,
+                attributes: vec![TcActionAttribute::Kind("mirred".to_string())],
+            }]),
+            TcActionMessageAttribute::Flags(
+                TcActionMessageFlagsWithSelector::new(
+                    TcActionMessageFlags::LargeDump
+                        | TcActionMessageFlags::TerseDump,
+                ),
+            ),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcActionMessage::parse(&TcActionMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+fn terse_mirred_action(tab: u16, index: u32) -> TcAction {
+    TcAction {
+        tab,
+        attributes: vec![
+            TcActionAttribute::Kind("mirred".to_string()),
+            TcActionAttribute::Stats(vec![
+                TcStats2::Basic(TcStatsBasic {
+                    bytes: 0,
+                    packets: 0,
+                }),
+                TcStats2::BasicHw(TcStatsBasic {
+                    bytes: 0,
+                    packets: 0,
+                }),
+                TcStats2::Queue(TcStatsQueue {
+                    qlen: 0,
+                    backlog: 0,
+                    drops: 0,
+                    requeues: 0,
+                    overlimits: 0,
+                }),
+            ]),
+            TcActionAttribute::Index(index),
+        ],
+    }
+}
+
+// Setup:
+//      ip link add veth0 type veth peer name veth1
+//      tc actions add action mirred egress redirect dev veth1 index 7
+//      tc actions add action mirred ingress mirror dev veth1 index 8
+//
+// Capture nlmon of this command:
+//
+//      tc -brief actions ls action mirred
+//
+// Raw packet modification:
+//   * rtnetlink header removed.
+#[test]
+fn test_get_action_terse_dump_reply() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0xbc, 0x00, 0x01, 0x00, 0x5c, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x01, 0x00,
+        0x6d, 0x69, 0x72, 0x72, 0x65, 0x64, 0x00, 0x00, 0x44, 0x00, 0x04, 0x00,
+        0x14, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x5c, 0x00, 0x01, 0x00, 0x0b, 0x00, 0x01, 0x00, 0x6d, 0x69, 0x72, 0x72,
+        0x65, 0x64, 0x00, 0x00, 0x44, 0x00, 0x04, 0x00, 0x14, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x18, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x08, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = TcActionMessage {
+        header: TcActionMessageHeader {
+            family: AddressFamily::Unspec,
+        },
+        attributes: vec![
+            TcActionMessageAttribute::Count(2),
+            TcActionMessageAttribute::Actions(vec![
+                terse_mirred_action(0, 7),
+                terse_mirred_action(1, 8),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TcActionMessage::parse(&TcActionMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: MIT
 
+#[cfg(test)]
+mod action_message;
 #[cfg(test)]
 mod action_nat;
 #[cfg(test)]
@@ -3,12 +3,19 @@
 // This file only contains testing parsing RouteNetlinkMessage, not focusing on
 // detailed sub-component parsing. Each component has their own tests moduel.
 
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
 use netlink_packet_core::{NetlinkHeader, NetlinkMessage, NetlinkPayload};
 use netlink_packet_utils::Emitable;
 
 use crate::{
+    address::{
+        AddressAttribute, AddressHeader, AddressHeaderFlags, AddressMessage,
+        AddressScope, CacheInfo,
+    },
     link::{LinkAttribute, LinkExtentMask, LinkMessage},
-    RouteNetlinkMessage,
+    AddressFamily, RouteNetlinkMessage,
 };
 
 // wireshark capture of nlmon against command:
@@ -47,3 +54,158 @@ fn test_get_link() {
     expected.emit(&mut buffer);
     assert_eq!(buffer.as_slice(), raw);
 }
+
+// wireshark capture of nlmon against `RTM_GETMULTICAST` dump request of
+// `AF_INET` family.
+#[test]
+fn test_get_multicast_address() {
+    let raw: Vec<u8> = vec![
+        0x34, 0x00, 0x00, 0x00, 0x3a, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x79, 0x34, 0x00, 0x00, 0x02, 0x20, 0x80, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x07, 0x00, 0xe0, 0x00, 0x00, 0x01, 0x14, 0x00, 0x06, 0x00,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x60, 0xd3, 0x05, 0x00,
+        0x60, 0xd3, 0x05, 0x00,
+    ];
+
+    let mut header = NetlinkHeader::default();
+    header.length = 52;
+    header.message_type = 58;
+    header.flags = 2;
+    header.sequence_number = 1;
+    header.port_number = 13433;
+
+    let expected = NetlinkMessage::new(
+        header,
+        NetlinkPayload::from(RouteNetlinkMessage::GetMulticastAddress(
+            AddressMessage {
+                header: AddressHeader {
+                    family: AddressFamily::Inet,
+                    prefix_len: 32,
+                    flags: AddressHeaderFlags::Permanent,
+                    scope: AddressScope::Universe,
+                    index: 1,
+                },
+                attributes: vec![
+                    AddressAttribute::MulticastV4(Ipv4Addr::new(224, 0, 0, 1)),
+                    AddressAttribute::CacheInfo(CacheInfo {
+                        ifa_preferred: u32::MAX,
+                        ifa_valid: u32::MAX,
+                        cstamp: 381792,
+                        tstamp: 381792,
+                    }),
+                ],
+            },
+        )),
+    );
+
+    assert_eq!(NetlinkMessage::deserialize(&raw).unwrap(), expected);
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+    assert_eq!(buffer.as_slice(), raw);
+}
+
+// Setup:
+//      ip link add d0 type veth peer name d1
+//      ip link set d0 up
+// wireshark capture of nlmon against RTNLGRP_IPV6_MCADDR multicast group
+// when d0 joined ff02::1.
+#[test]
+fn test_new_multicast_address() {
+    let raw: Vec<u8> = vec![
+        0x40, 0x00, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0a, 0x80, 0x80, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x07, 0x00, 0xff, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x14, 0x00, 0x06, 0x00,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x9d, 0x05, 0x0e, 0x00,
+        0x9d, 0x05, 0x0e, 0x00,
+    ];
+
+    let mut header = NetlinkHeader::default();
+    header.length = 64;
+    header.message_type = 56;
+
+    let expected = NetlinkMessage::new(
+        header,
+        NetlinkPayload::from(RouteNetlinkMessage::NewMulticastAddress(
+            AddressMessage {
+                header: AddressHeader {
+                    family: AddressFamily::Inet6,
+                    prefix_len: 128,
+                    flags: AddressHeaderFlags::Permanent,
+                    scope: AddressScope::Universe,
+                    index: 3,
+                },
+                attributes: vec![
+                    AddressAttribute::Multicast(
+                        Ipv6Addr::from_str("ff02::1").unwrap(),
+                    ),
+                    AddressAttribute::CacheInfo(CacheInfo {
+                        ifa_preferred: u32::MAX,
+                        ifa_valid: u32::MAX,
+                        cstamp: 918941,
+                        tstamp: 918941,
+                    }),
+                ],
+            },
+        )),
+    );
+
+    assert_eq!(NetlinkMessage::deserialize(&raw).unwrap(), expected);
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+    assert_eq!(buffer.as_slice(), raw);
+}
+
+// Setup:
+//      ip link add d0 type veth peer name d1
+//      ip link set d0 up
+//      ip -6 addr add 2001:db8:1::1/64 dev d0 nodad
+// wireshark capture of nlmon against RTNLGRP_IPV6_ACADDR multicast group
+// of command:
+//      sysctl -w net.ipv6.conf.d0.forwarding=1
+#[test]
+fn test_new_anycast_address() {
+    let raw: Vec<u8> = vec![
+        0x40, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0a, 0x80, 0x80, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x05, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x06, 0x00,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xdb, 0x06, 0x0e, 0x00,
+        0xdb, 0x06, 0x0e, 0x00,
+    ];
+
+    let mut header = NetlinkHeader::default();
+    header.length = 64;
+    header.message_type = 60;
+
+    let expected = NetlinkMessage::new(
+        header,
+        NetlinkPayload::from(RouteNetlinkMessage::NewAnycastAddress(
+            AddressMessage {
+                header: AddressHeader {
+                    family: AddressFamily::Inet6,
+                    prefix_len: 128,
+                    flags: AddressHeaderFlags::Permanent,
+                    scope: AddressScope::Universe,
+                    index: 3,
+                },
+                attributes: vec![
+                    AddressAttribute::Anycast(
+                        Ipv6Addr::from_str("2001:db8:1::").unwrap(),
+                    ),
+                    AddressAttribute::CacheInfo(CacheInfo {
+                        ifa_preferred: u32::MAX,
+                        ifa_valid: u32::MAX,
+                        cstamp: 919259,
+                        tstamp: 919259,
+                    }),
+                ],
+            },
+        )),
+    );
+
+    assert_eq!(NetlinkMessage::deserialize(&raw).unwrap(), expected);
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+    assert_eq!(buffer.as_slice(), raw);
+}
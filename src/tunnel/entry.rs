@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u32, parse_u64},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::ip::{
+    parse_ipv4_addr, parse_ipv6_addr, IPV4_ADDR_LEN, IPV6_ADDR_LEN,
+};
+
+const VXLAN_VNIFILTER_ENTRY_START: u16 = 1;
+const VXLAN_VNIFILTER_ENTRY_END: u16 = 2;
+const VXLAN_VNIFILTER_ENTRY_GROUP: u16 = 3;
+const VXLAN_VNIFILTER_ENTRY_GROUP6: u16 = 4;
+const VXLAN_VNIFILTER_ENTRY_STATS: u16 = 5;
+
+/// Nested attributes of `VXLAN_VNIFILTER_ENTRY`
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum VxlanVniFilterEntry {
+    Start(u32),
+    /// The last VNI of the range starting from [VxlanVniFilterEntry::Start]
+    End(u32),
+    /// Multicast group or unicast remote address of the VNI
+    Group(Ipv4Addr),
+    /// Multicast group or unicast remote address of the VNI
+    Group6(Ipv6Addr),
+    Stats(Vec<VxlanVniFilterStats>),
+    Other(DefaultNla),
+}
+
+impl Nla for VxlanVniFilterEntry {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Start(_) | Self::End(_) => 4,
+            Self::Group(_) => IPV4_ADDR_LEN,
+            Self::Group6(_) => IPV6_ADDR_LEN,
+            Self::Stats(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Start(v) | Self::End(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Group(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Group6(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Stats(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Start(_) => VXLAN_VNIFILTER_ENTRY_START,
+            Self::End(_) => VXLAN_VNIFILTER_ENTRY_END,
+            Self::Group(_) => VXLAN_VNIFILTER_ENTRY_GROUP,
+            Self::Group6(_) => VXLAN_VNIFILTER_ENTRY_GROUP6,
+            Self::Stats(_) => VXLAN_VNIFILTER_ENTRY_STATS | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for VxlanVniFilterEntry
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            VXLAN_VNIFILTER_ENTRY_START => Self::Start(
+                parse_u32(payload)
+                    .context("invalid VXLAN_VNIFILTER_ENTRY_START value")?,
+            ),
+            VXLAN_VNIFILTER_ENTRY_END => Self::End(
+                parse_u32(payload)
+                    .context("invalid VXLAN_VNIFILTER_ENTRY_END value")?,
+            ),
+            VXLAN_VNIFILTER_ENTRY_GROUP => Self::Group(
+                parse_ipv4_addr(payload)
+                    .context("invalid VXLAN_VNIFILTER_ENTRY_GROUP value")?,
+            ),
+            VXLAN_VNIFILTER_ENTRY_GROUP6 => Self::Group6(
+                parse_ipv6_addr(payload)
+                    .context("invalid VXLAN_VNIFILTER_ENTRY_GROUP6 value")?,
+            ),
+            VXLAN_VNIFILTER_ENTRY_STATS => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla
+                        .context("invalid VXLAN_VNIFILTER_ENTRY_STATS value")?;
+                    nlas.push(VxlanVniFilterStats::parse(&nla)?);
+                }
+                Self::Stats(nlas)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
+
+const VNIFILTER_ENTRY_STATS_RX_BYTES: u16 = 1;
+const VNIFILTER_ENTRY_STATS_RX_PKTS: u16 = 2;
+const VNIFILTER_ENTRY_STATS_RX_DROPS: u16 = 3;
+const VNIFILTER_ENTRY_STATS_RX_ERRORS: u16 = 4;
+const VNIFILTER_ENTRY_STATS_TX_BYTES: u16 = 5;
+const VNIFILTER_ENTRY_STATS_TX_PKTS: u16 = 6;
+const VNIFILTER_ENTRY_STATS_TX_DROPS: u16 = 7;
+const VNIFILTER_ENTRY_STATS_TX_ERRORS: u16 = 8;
+// const VNIFILTER_ENTRY_STATS_PAD: u16 = 9;
+
+/// Per-VNI statistics of `VXLAN_VNIFILTER_ENTRY_STATS`
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum VxlanVniFilterStats {
+    RxBytes(u64),
+    RxPackets(u64),
+    RxDrops(u64),
+    RxErrors(u64),
+    TxBytes(u64),
+    TxPackets(u64),
+    TxDrops(u64),
+    TxErrors(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for VxlanVniFilterStats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Other(attr) => attr.value_len(),
+            _ => 8,
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RxBytes(v)
+            | Self::RxPackets(v)
+            | Self::RxDrops(v)
+            | Self::RxErrors(v)
+            | Self::TxBytes(v)
+            | Self::TxPackets(v)
+            | Self::TxDrops(v)
+            | Self::TxErrors(v) => NativeEndian::write_u64(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RxBytes(_) => VNIFILTER_ENTRY_STATS_RX_BYTES,
+            Self::RxPackets(_) => VNIFILTER_ENTRY_STATS_RX_PKTS,
+            Self::RxDrops(_) => VNIFILTER_ENTRY_STATS_RX_DROPS,
+            Self::RxErrors(_) => VNIFILTER_ENTRY_STATS_RX_ERRORS,
+            Self::TxBytes(_) => VNIFILTER_ENTRY_STATS_TX_BYTES,
+            Self::TxPackets(_) => VNIFILTER_ENTRY_STATS_TX_PKTS,
+            Self::TxDrops(_) => VNIFILTER_ENTRY_STATS_TX_DROPS,
+            Self::TxErrors(_) => VNIFILTER_ENTRY_STATS_TX_ERRORS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for VxlanVniFilterStats
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            VNIFILTER_ENTRY_STATS_RX_BYTES => Self::RxBytes(
+                parse_u64(payload)
+                    .context("invalid VNIFILTER_ENTRY_STATS_RX_BYTES value")?,
+            ),
+            VNIFILTER_ENTRY_STATS_RX_PKTS => Self::RxPackets(
+                parse_u64(payload)
+                    .context("invalid VNIFILTER_ENTRY_STATS_RX_PKTS value")?,
+            ),
+            VNIFILTER_ENTRY_STATS_RX_DROPS => Self::RxDrops(
+                parse_u64(payload)
+                    .context("invalid VNIFILTER_ENTRY_STATS_RX_DROPS value")?,
+            ),
+            VNIFILTER_ENTRY_STATS_RX_ERRORS => Self::RxErrors(
+                parse_u64(payload)
+                    .context("invalid VNIFILTER_ENTRY_STATS_RX_ERRORS value")?,
+            ),
+            VNIFILTER_ENTRY_STATS_TX_BYTES => Self::TxBytes(
+                parse_u64(payload)
+                    .context("invalid VNIFILTER_ENTRY_STATS_TX_BYTES value")?,
+            ),
+            VNIFILTER_ENTRY_STATS_TX_PKTS => Self::TxPackets(
+                parse_u64(payload)
+                    .context("invalid VNIFILTER_ENTRY_STATS_TX_PKTS value")?,
+            ),
+            VNIFILTER_ENTRY_STATS_TX_DROPS => Self::TxDrops(
+                parse_u64(payload)
+                    .context("invalid VNIFILTER_ENTRY_STATS_TX_DROPS value")?,
+            ),
+            VNIFILTER_ENTRY_STATS_TX_ERRORS => Self::TxErrors(
+                parse_u64(payload)
+                    .context("invalid VNIFILTER_ENTRY_STATS_TX_ERRORS value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("unknown NLA type {kind}"))?,
+            ),
+        })
+    }
+}
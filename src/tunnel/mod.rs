@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod entry;
+mod header;
+mod message;
+#[cfg(test)]
+mod tests;
+
+pub use self::attribute::TunnelAttribute;
+pub use self::entry::{VxlanVniFilterEntry, VxlanVniFilterStats};
+pub use self::header::{TunnelHeader, TunnelMessageBuffer, TunnelMessageFlags};
+pub use self::message::TunnelMessage;
@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::{
+    tunnel::{
+        TunnelAttribute, TunnelHeader, TunnelMessage, TunnelMessageBuffer,
+        TunnelMessageFlags, VxlanVniFilterEntry, VxlanVniFilterStats,
+    },
+    AddressFamily,
+};
+
+// Setup:
+//      ip link add d0 type veth peer name d1
+//      ip link set d0 up
+//      ip addr add 192.0.2.254/24 dev d0
+//      ip link add vx0 type vxlan dstport 4789 external vnifilter dev d0 \
+//          local 192.0.2.254
+//      ip link set vx0 up
+//      bridge vni add dev vx0 vni 100
+//      bridge vni add dev vx0 vni 200-202 group 239.1.1.1
+//      bridge vni add dev vx0 vni 300 remote 192.0.2.1
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      bridge vni show
+#[test]
+fn test_vxlan_vni_filter_dump() {
+    let raw = vec![
+        0x07, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x64, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0xc8, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0xca, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0xef, 0x01, 0x01, 0x01,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x2c, 0x01, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0xc0, 0x00, 0x02, 0x01,
+    ];
+
+    let expected = TunnelMessage {
+        header: TunnelHeader {
+            family: AddressFamily::Bridge,
+            flags: TunnelMessageFlags::empty(),
+            ifindex: 4,
+        },
+        attributes: vec![
+            TunnelAttribute::VxlanVniFilterEntry(vec![
+                VxlanVniFilterEntry::Start(100),
+            ]),
+            TunnelAttribute::VxlanVniFilterEntry(vec![
+                VxlanVniFilterEntry::Start(200),
+                VxlanVniFilterEntry::End(202),
+                VxlanVniFilterEntry::Group(
+                    Ipv4Addr::from_str("239.1.1.1").unwrap(),
+                ),
+            ]),
+            TunnelAttribute::VxlanVniFilterEntry(vec![
+                VxlanVniFilterEntry::Start(300),
+                VxlanVniFilterEntry::Group(
+                    Ipv4Addr::from_str("192.0.2.1").unwrap(),
+                ),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        TunnelMessage::parse(&TunnelMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add vx0 type vxlan dstport 4789 external vnifilter
+//      ip link set vx0 up
+//      bridge vni add dev vx0 vni 100
+// wireshark capture(netlink message header removed) of nlmon against command:
+//      bridge -s vni show
+#[test]
+fn test_vxlan_vni_filter_dump_with_stats() {
+    let raw = vec![
+        0x07, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x70, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x64, 0x00, 0x00, 0x00, 0x64, 0x00, 0x05, 0x80,
+        0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = TunnelMessage {
+        header: TunnelHeader {
+            family: AddressFamily::Bridge,
+            flags: TunnelMessageFlags::empty(),
+            ifindex: 2,
+        },
+        attributes: vec![TunnelAttribute::VxlanVniFilterEntry(vec![
+            VxlanVniFilterEntry::Start(100),
+            VxlanVniFilterEntry::Stats(vec![
+                VxlanVniFilterStats::RxBytes(0),
+                VxlanVniFilterStats::RxPackets(0),
+                VxlanVniFilterStats::RxDrops(0),
+                VxlanVniFilterStats::RxErrors(0),
+                VxlanVniFilterStats::TxBytes(0),
+                VxlanVniFilterStats::TxPackets(0),
+                VxlanVniFilterStats::TxDrops(0),
+                VxlanVniFilterStats::TxErrors(0),
+            ]),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        TunnelMessage::parse(&TunnelMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Raw netlink message(header removed) of `RTM_GETTUNNEL` dump request
+// equivalent to command:
+//      bridge -s vni show
+#[test]
+fn test_vxlan_vni_filter_dump_request() {
+    let raw = vec![0x07, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+    let expected = TunnelMessage {
+        header: TunnelHeader {
+            family: AddressFamily::Bridge,
+            flags: TunnelMessageFlags::Stats,
+            ifindex: 0,
+        },
+        attributes: vec![],
+    };
+
+    assert_eq!(
+        expected,
+        TunnelMessage::parse(&TunnelMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}